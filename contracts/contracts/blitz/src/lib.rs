@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, String,
-    Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, token,
+    Address, BytesN, Env, String, Symbol, Vec,
 };
 
 // Constants
@@ -9,8 +9,32 @@ const AUCTION_DURATION: u64 = 24 * 60 * 60; // 24 hours in seconds
 const URL_DISPLAY_DURATION: u64 = 24 * 60 * 60; // 24 hours in seconds
 const DEFAULT_MIN_BID_INCREMENT: i128 = 1000000; // 0.1 XLM (7 decimal places)
 const DEFAULT_MIN_STARTING_BID: i128 = 10000000; // 1 XLM
+const DEFAULT_MAX_BID_PARAM: i128 = 1_000_000_000_000; // 100,000 XLM
+const MAX_INCREMENT_FACTOR: i128 = 10; // increment may be at most 10x the starting bid
+const MAX_INCREMENT_BPS: u32 = 5000; // 50% of the current highest bid
+const BPS_DENOMINATOR: i128 = 10000;
+const MAX_INCREMENT_TIERS: u32 = 10;
+const MAX_BIDS_PER_AUCTION: u32 = 200; // oldest bids are dropped beyond this
+const MAX_BIDS_PAGE: u32 = 50;
+const LEADERBOARD_SIZE: u32 = 10;
+const MAX_HOUSE_ADS: u32 = 20;
+const HOUSE_AD_ROTATION_PERIOD: u64 = 60 * 60; // house ads rotate hourly
+const MAX_SLOTS: u32 = 100;
+const MAX_SLOT_HISTORY: u32 = 30; // most recent auctions kept per slot
+const MAX_TITLE_LEN: u32 = 64;
+const MAX_DESCRIPTION_LEN: u32 = 280;
+const MAX_ADVERTISER_LEN: u32 = 64;
+const MAX_CATEGORY_LEN: u32 = 32;
+const MAX_URL_LEN: u32 = 256;
+const SCAN_BUCKET_DURATION: u64 = 60 * 60; // scans are reported per hour of display
+const MAX_DOWNTIME_INTERVALS: u32 = 50;
+const MAX_ACCEPTED_TOKENS: u32 = 10;
+const DEFAULT_ORACLE_MAX_AGE: u64 = 5 * 60; // prices older than 5 minutes are stale
+const TAX_PERIOD: u64 = 24 * 60 * 60; // Harberger tax rates are per day
+const MAX_POOL_CONTRIBUTORS: u32 = 50;
 
 // Storage keys
+// Per-slot state is keyed by `(KEY, slot)`; the rest is contract-wide.
 const PLATFORM_WALLET: Symbol = symbol_short!("PLATFORM");
 const AUCTION_COUNTER: Symbol = symbol_short!("COUNTER");
 const CURRENT_AUCTION: Symbol = symbol_short!("CURRENT");
@@ -19,6 +43,43 @@ const OWNER: Symbol = symbol_short!("OWNER");
 const TOKEN: Symbol = symbol_short!("TOKEN");
 const MIN_BID_INCREMENT: Symbol = symbol_short!("MIN_INC");
 const MIN_STARTING_BID: Symbol = symbol_short!("MIN_BID");
+const MIN_INCREMENT_BPS: Symbol = symbol_short!("MIN_BPS");
+const MAX_BID_PARAM: Symbol = symbol_short!("MAX_PARAM");
+const INCREMENT_TIERS: Symbol = symbol_short!("INC_TIERS");
+const PROXY_BID: Symbol = symbol_short!("PROXY");
+const BIDS: Symbol = symbol_short!("BIDS"); // (BIDS, auction_id, n) -> BidRecord
+const BID_COUNT: Symbol = symbol_short!("BID_CNT"); // (BID_CNT, auction_id) -> u32
+const STATS: Symbol = symbol_short!("STATS"); // (STATS, address) -> BidderStats
+const LEADERBOARD: Symbol = symbol_short!("LEADERS");
+const HOUSE_ADS: Symbol = symbol_short!("HOUSE_ADS");
+const DISPLAY_QUEUE: Symbol = symbol_short!("DISPLAYS");
+const KEEPER_REWARD: Symbol = symbol_short!("KEEPER");
+const CONTINUOUS_MODE: Symbol = symbol_short!("CONTINUE");
+const SLOTS: Symbol = symbol_short!("SLOTS");
+const AUCTION: Symbol = symbol_short!("AUCTION"); // (AUCTION, auction_id) -> Auction
+const SLOT_HISTORY: Symbol = symbol_short!("HISTORY"); // (HISTORY, slot) -> Vec<u64>
+const UPGRADER: Symbol = symbol_short!("UPGRADER");
+const SCAN_REPORTER: Symbol = symbol_short!("SCAN_REP");
+const SCANS: Symbol = symbol_short!("SCANS"); // (SCANS, auction_id) -> Vec<ScanBucket>
+const DEVICE_REPORTER: Symbol = symbol_short!("DEV_REP");
+const DOWNTIME: Symbol = symbol_short!("DOWNTIME"); // (DOWNTIME, auction_id) -> Vec<DowntimeInterval>
+const ESCROW: Symbol = symbol_short!("ESCROW"); // (ESCROW, auction_id) -> ProceedsEscrow
+const LINEAR_VESTING: Symbol = symbol_short!("VESTING");
+const ACCEPTED_TOKENS: Symbol = symbol_short!("TOKENS");
+const PRICE_ORACLE: Symbol = symbol_short!("ORACLE");
+const ORACLE_MAX_AGE: Symbol = symbol_short!("ORACLE_MX");
+const FIAT_FLOORS: Symbol = symbol_short!("FIAT");
+const BALANCE: Symbol = symbol_short!("BALANCE"); // (BALANCE, address) -> i128
+const URL_MANAGER: Symbol = symbol_short!("MANAGER"); // (MANAGER, auction_id) -> UrlManager
+const DISPLAY_HOLDER: Symbol = symbol_short!("HOLDER"); // (HOLDER, auction_id) -> Address
+const LISTING: Symbol = symbol_short!("LISTING"); // (LISTING, auction_id) -> Listing
+const ROYALTY: Symbol = symbol_short!("ROYALTY");
+const HARBERGER: Symbol = symbol_short!("HARBERGER"); // (HARBERGER, slot) -> daily tax bps
+const HOLDING: Symbol = symbol_short!("HOLDING"); // (HOLDING, slot) -> HarbergerHolding
+const POOL_COUNTER: Symbol = symbol_short!("POOL_CNT");
+const POOL: Symbol = symbol_short!("POOL"); // (POOL, pool_id) -> Pool
+const POOL_SHARE: Symbol = symbol_short!("POOL_SHR"); // (POOL_SHR, pool_id, address) -> PoolShare
+const WINNING_POOL: Symbol = symbol_short!("POOL_WIN"); // (POOL_WIN, auction_id) -> pool_id
 
 // Error codes
 #[contracterror]
@@ -34,20 +95,271 @@ pub enum Error {
     AlreadyEnded = 7,
     Unauthorized = 8,
     AlreadyInitialized = 9,
+    InvalidAmount = 10,
+    AmountTooHigh = 11,
+    IncrementTooHigh = 12,
+    InvalidBasisPoints = 13,
+    InvalidTiers = 14,
+    InvalidHouseAds = 15,
+    InvalidKeeperReward = 16,
+    SlotNotFound = 17,
+    SlotExists = 18,
+    TooManySlots = 19,
+    InvalidMetadata = 20,
+    AuctionNotFound = 21,
+    DisplayEnded = 22,
+    InvalidScanBucket = 23,
+    InvalidDowntime = 24,
+    DisplayNotEnded = 25,
+    NothingEscrowed = 26,
+    TokenNotAccepted = 27,
+    PriceUnavailable = 28,
+    InvalidTokens = 29,
+    OracleStale = 30,
+    InsufficientBalance = 31,
+    ListingNotFound = 32,
+    NotHarbergerSlot = 33,
+    HarbergerSlot = 34,
+    AuctionInProgress = 35,
+    SlotVacant = 36,
+    PoolNotFound = 37,
+    TooManyContributors = 38,
+    NothingToWithdraw = 39,
+    UrlTooLong = 40,
+    DisplayScheduled = 41,
+    BeneficiaryLeading = 42,
+    PoolAuction = 43,
 }
 
 // Data structures
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
+/// `highest_bid` is in units of the contract token; the leading bid was
+/// actually paid by `bid_payer` as `bid_amount` of `bid_token`, reserved
+/// from the payer's deposit balance if `bid_from_balance`. Refunds go to
+/// `bid_payer`; `highest_bidder` controls the URL until the display rights
+/// of a settled auction are transferred.
 pub struct Auction {
     pub auction_id: u64,
+    pub slot: Symbol,
     pub starting_time: u64,
     pub ending_time: u64,
     pub highest_bid: i128,
     pub highest_bidder: Address,
+    pub bid_payer: Address,
+    pub bid_token: Address,
+    pub bid_amount: i128,
+    pub bid_from_balance: bool,
     pub preferred_url: String,
     pub is_ended: bool,
     pub url_expiry_time: u64,
+    pub metadata: QRMetadata,
+}
+
+/// Ad card details a winner attaches to their URL; empty until set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QRMetadata {
+    pub title: String,
+    pub description: String,
+    pub advertiser: String,
+    pub image_hash: Option<BytesN<32>>,
+    pub category: String,
+}
+
+/// Increment applied once the highest bid reaches `min_bid`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IncrementTier {
+    pub min_bid: i128,
+    pub increment: i128,
+}
+
+/// Escrowed proxy bid; the ceiling is kept out of `Auction` on purpose
+///
+/// Pools bid through a proxy whose ceiling is the pooled balance; their
+/// escrow stays with the pool instead of being refunded to `bidder`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProxyBid {
+    pub auction_id: u64,
+    pub bidder: Address,
+    pub max_amount: i128,
+    pub pool_id: Option<u64>,
+}
+
+/// Crowdfunded bid on one auction, placed by the contract on the pool's behalf
+///
+/// The contract pays as `bid_payer` while `creator` is the bidder who
+/// controls the URL, metadata and display rights if the pool wins.
+///
+/// `spent` is the winning bid paid from the pool and `refunded` any
+/// downtime refund credited back to it; contributors withdraw the rest pro
+/// rata once the auction is settled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pool {
+    pub pool_id: u64,
+    pub slot: Symbol,
+    pub auction_id: u64,
+    pub creator: Address,
+    pub url: String,
+    pub balance: i128,
+    pub spent: i128,
+    pub refunded: i128,
+    pub contributors: Vec<Address>,
+}
+
+/// A contributor's stake in a pool
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolShare {
+    pub amount: i128,
+    pub withdrawn: i128,
+}
+
+/// Single entry in an auction's on-chain bid ladder
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidRecord {
+    pub bidder: Address,
+    pub amount: i128,
+    pub preferred_url: String,
+    pub timestamp: u64,
+}
+
+/// Lifetime aggregates for a single bidder address
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidderStats {
+    pub bids_placed: u32,
+    pub auctions_won: u32,
+    pub total_paid: i128,
+    pub total_refunded: i128,
+    pub last_win_id: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub bidder: Address,
+    pub total_paid: i128,
+}
+
+/// Owner-managed fallback URL shown while no winner is displaying
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HouseAd {
+    pub id: u32,
+    pub url: String,
+    pub weight: u32,
+}
+
+/// Paid display slot; windows are queued back-to-back and never overlap
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisplayWindow {
+    pub auction_id: u64,
+    pub holder: Address,
+    pub url: String,
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+/// Reward paid from proceeds to whoever settles an expired auction
+///
+/// The reward is `fixed_amount` plus `bps` of the winning bid, capped at
+/// `max_amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperReward {
+    pub fixed_amount: i128,
+    pub bps: u32,
+    pub max_amount: i128,
+}
+
+/// Address a URL holder has delegated URL and metadata edits to
+///
+/// Only honoured while `holder` still controls the auction's URL.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UrlManager {
+    pub holder: Address,
+    pub manager: Address,
+}
+
+/// Holder of a slot in Harberger mode
+///
+/// Tax accrues every second on the self-assessed `price` since
+/// `assessed_at`, at the slot's daily rate. `tax_paid` is what has already
+/// been collected from `deposit` since then; the holding lapses once the
+/// accrued tax exhausts the deposit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HarbergerHolding {
+    pub holder: Address,
+    pub price: i128,
+    pub url: String,
+    pub deposit: i128,
+    pub assessed_at: u64,
+    pub tax_paid: i128,
+}
+
+/// Remaining display rights of a settled auction offered for sale
+///
+/// `price` is in units of the contract token. The listing lapses when the
+/// window ends or the rights change hands.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    pub auction_id: u64,
+    pub seller: Address,
+    pub price: i128,
+    pub listed_at: u64,
+}
+
+/// Scans attested by the reporter for one hour of a display window
+///
+/// `bucket` counts hours from the start of the window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScanBucket {
+    pub bucket: u32,
+    pub scans: u32,
+    pub reported_at: u64,
+}
+
+/// Period during which a slot's physical display was offline
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DowntimeInterval {
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+/// Winning proceeds held until the auction's display window ends
+///
+/// With `linear_vesting` the platform may claim proceeds as display time
+/// is delivered; otherwise everything is released when the window closes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProceedsEscrow {
+    pub token: Address,
+    pub total: i128,
+    pub released: i128,
+    pub refunded: i128,
+    pub linear_vesting: bool,
+}
+
+/// Locked vs released accounting for an auction's proceeds
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProceedsStatus {
+    pub total: i128,
+    pub released: i128,
+    pub refunded: i128,
+    pub claimable: i128,
+    pub locked: i128,
 }
 
 #[contracttype]
@@ -55,6 +367,8 @@ pub struct Auction {
 pub struct QRStatus {
     pub status: String,
     pub source: String,
+    pub fallback_id: Option<u32>,
+    pub metadata: QRMetadata,
 }
 
 #[contracttype]
@@ -64,8 +378,20 @@ pub struct ContractInfo {
     pub platform_wallet: Address,
     pub token_address: Address,
     pub auction_counter: u64,
+    pub keeper_reward: KeeperReward,
+    pub slots: Vec<Symbol>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlotConfig {
     pub min_bid_increment: i128,
     pub min_starting_bid: i128,
+    pub min_bid_increment_bps: u32,
+    pub max_bid_param: i128,
+    pub increment_tiers: Vec<IncrementTier>,
+    pub continuous_mode: bool,
+    pub harberger_tax_bps: Option<u32>,
 }
 
 #[contracttype]
@@ -79,6 +405,39 @@ pub struct AuctionSummary {
     pub qr_url: String,
 }
 
+/// Asset identifier in the SEP-40 price feed interface
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// Price record in the SEP-40 price feed interface
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// Starting bid and flat increment in the price oracle's base asset
+///
+/// Amounts are scaled by the oracle's decimals and converted to the
+/// contract token at the latest price whenever a minimum bid is computed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FiatFloors {
+    pub min_starting_bid: i128,
+    pub min_bid_increment: i128,
+}
+
+/// Subset of the SEP-40 price feed interface used to value bids
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+}
+
 #[contract]
 pub struct Blitz;
 
@@ -109,576 +468,3583 @@ impl Blitz {
         // Set token address
         env.storage().persistent().set(&TOKEN, &token_address);
 
-        // Initialize auction counter
+        // Initialize auction counter (shared by all slots)
         env.storage().persistent().set(&AUCTION_COUNTER, &0u64);
 
+        // Initialize empty slot registry
+        env.storage()
+            .persistent()
+            .set(&SLOTS, &Vec::<Symbol>::new(&env));
+
+        // Emit initialization event
+        env.events().publish(
+            (symbol_short!("init"),),
+            (&owner, &platform_wallet, &token_address),
+        );
+
+        Ok(())
+    }
+
+    /// Register a new QR slot, one per physical placement (only owner)
+    pub fn create_slot(env: Env, slot: Symbol) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        let mut slots = Self::list_slots(env.clone());
+        if slots.contains(&slot) {
+            return Err(Error::SlotExists);
+        }
+        if slots.len() >= MAX_SLOTS {
+            return Err(Error::TooManySlots);
+        }
+
+        slots.push_back(slot.clone());
+        env.storage().persistent().set(&SLOTS, &slots);
+
         // Set default bid parameters
+        env.storage().persistent().set(
+            &(MIN_BID_INCREMENT, slot.clone()),
+            &DEFAULT_MIN_BID_INCREMENT,
+        );
+        env.storage()
+            .persistent()
+            .set(&(MIN_STARTING_BID, slot.clone()), &DEFAULT_MIN_STARTING_BID);
         env.storage()
             .persistent()
-            .set(&MIN_BID_INCREMENT, &DEFAULT_MIN_BID_INCREMENT);
+            .set(&(MIN_INCREMENT_BPS, slot.clone()), &0u32);
         env.storage()
             .persistent()
-            .set(&MIN_STARTING_BID, &DEFAULT_MIN_STARTING_BID);
+            .set(&(MAX_BID_PARAM, slot.clone()), &DEFAULT_MAX_BID_PARAM);
 
         // Create empty initial auction
-        let empty_auction = Auction {
-            auction_id: 0,
-            starting_time: 0,
-            ending_time: 0,
-            highest_bid: 0,
-            highest_bidder: owner.clone(),
-            preferred_url: String::from_str(&env, ""),
-            is_ended: true,
-            url_expiry_time: 0,
-        };
+        let empty_auction = Self::get_empty_auction(&env, &slot);
 
         env.storage()
             .persistent()
-            .set(&CURRENT_AUCTION, &empty_auction);
+            .set(&(CURRENT_AUCTION, slot.clone()), &empty_auction);
         env.storage()
             .persistent()
-            .set(&LAST_AUCTION, &empty_auction);
+            .set(&(LAST_AUCTION, slot.clone()), &empty_auction);
 
-        // Emit initialization event
-        env.events().publish(
-            (symbol_short!("init"),),
-            (&owner, &platform_wallet, &token_address),
-        );
+        env.events().publish((symbol_short!("slot_new"), slot), ());
 
         Ok(())
     }
 
+    /// List all registered slots
+    pub fn list_slots(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&SLOTS)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
     /// Place a bid on the current auction
+    ///
+    /// If another bidder holds a proxy bid with a ceiling at or above
+    /// `amount`, the proxy counters automatically: the visible price rises
+    /// but the proxy bidder stays in the lead and nothing is taken from
+    /// `bidder`, who must still hold `amount` for the counter to happen.
     pub fn place_bid(
         env: Env,
+        slot: Symbol,
         bidder: Address,
         amount: i128,
         preferred_url: String,
     ) -> Result<(), Error> {
         bidder.require_auth();
 
-        let mut current_auction: Auction = env
-            .storage()
-            .persistent()
-            .get(&CURRENT_AUCTION)
-            .ok_or(Error::NoActiveAuction)?;
+        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        Self::submit_bid(
+            &env,
+            &slot,
+            &bidder,
+            &bidder,
+            None,
+            &token_address,
+            amount,
+            &preferred_url,
+        )
+    }
 
-        let current_time = env.ledger().timestamp();
+    /// Place a bid paid by `payer` on behalf of `beneficiary`
+    ///
+    /// Lets a company buy a slot for someone else: `beneficiary` controls
+    /// the URL and its metadata, while refunds go back to `payer`.
+    /// Gifting to the current leader is refused.
+    pub fn place_gift_bid(
+        env: Env,
+        slot: Symbol,
+        payer: Address,
+        beneficiary: Address,
+        amount: i128,
+        preferred_url: String,
+    ) -> Result<(), Error> {
+        payer.require_auth();
 
-        // Check if auction is active
-        if current_time >= current_auction.ending_time {
-            return Err(Error::AuctionEnded);
-        }
+        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        Self::submit_bid(
+            &env,
+            &slot,
+            &beneficiary,
+            &payer,
+            None,
+            &token_address,
+            amount,
+            &preferred_url,
+        )
+    }
 
-        if current_auction.starting_time == 0 {
-            return Err(Error::NoActiveAuction);
-        }
+    /// Place a bid funded from `payer`'s token allowance to `spender`
+    ///
+    /// Lets an agency or relayer bid on a client's behalf: `beneficiary`
+    /// controls the URL if the bid wins, and `payer` is refunded if it is
+    /// outbid. Bidding for the current leader with someone else's
+    /// allowance is refused.
+    pub fn place_bid_from(
+        env: Env,
+        slot: Symbol,
+        spender: Address,
+        payer: Address,
+        beneficiary: Address,
+        amount: i128,
+        preferred_url: String,
+    ) -> Result<(), Error> {
+        spender.require_auth();
 
-        // Check URL is not empty
-        if preferred_url.len() == 0 {
-            return Err(Error::EmptyUrl);
+        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        Self::submit_bid(
+            &env,
+            &slot,
+            &beneficiary,
+            &payer,
+            Some(&spender),
+            &token_address,
+            amount,
+            &preferred_url,
+        )
+    }
+
+    /// Place a bid paid in one of the accepted tokens
+    ///
+    /// `amount` is in units of `token`; it is converted to the contract
+    /// token's units through the price oracle to rank against other bids,
+    /// and refunded in `token` if outbid.
+    pub fn place_token_bid(
+        env: Env,
+        slot: Symbol,
+        bidder: Address,
+        token: Address,
+        amount: i128,
+        preferred_url: String,
+    ) -> Result<(), Error> {
+        bidder.require_auth();
+
+        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        if token != token_address && !Self::get_accepted_tokens(env.clone()).contains(&token) {
+            return Err(Error::TokenNotAccepted);
         }
 
-        // Calculate minimum bid using stored values
-        let min_starting_bid = Self::get_min_starting_bid(&env);
-        let min_bid_increment = Self::get_min_bid_increment(&env);
+        Self::submit_bid(
+            &env,
+            &slot,
+            &bidder,
+            &bidder,
+            None,
+            &token,
+            amount,
+            &preferred_url,
+        )
+    }
 
-        let minimum_bid = if current_auction.highest_bid == 0 {
-            min_starting_bid
-        } else {
-            current_auction.highest_bid + min_bid_increment
-        };
+    /// Internal function to place a plain bid for `bidder` paid by `payer`
+    /// in `token_address`
+    ///
+    /// With a `spender` the bid is pulled from the payer's allowance;
+    /// otherwise it comes from the payer's deposit balance or wallet.
+    #[allow(clippy::too_many_arguments)]
+    fn submit_bid(
+        env: &Env,
+        slot: &Symbol,
+        bidder: &Address,
+        payer: &Address,
+        spender: Option<&Address>,
+        token_address: &Address,
+        amount: i128,
+        preferred_url: &String,
+    ) -> Result<(), Error> {
+        let mut current_auction = Self::get_open_auction(env, slot)?;
+        let current_time = env.ledger().timestamp();
+
+        Self::validate_url(preferred_url)?;
 
-        if amount < minimum_bid {
+        // Bids are ranked by their value in the contract token
+        let value = Self::get_token_value(env, token_address, amount)?;
+
+        // Calculate minimum bid using stored values
+        let minimum_bid = Self::calculate_minimum_bid(env, slot, current_auction.highest_bid)?;
+
+        if value < minimum_bid {
             return Err(Error::BidTooLow);
         }
 
-        // Get token client
-        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
-        let token = token::Client::new(&env, &token_address);
+        // Nobody else may bid for the current leader: the bid would replace
+        // their URL, or cancel their proxy, with someone else's money
+        if payer != bidder
+            && current_auction.highest_bid > 0
+            && current_auction.highest_bidder == *bidder
+        {
+            return Err(Error::BeneficiaryLeading);
+        }
 
-        // Refund previous highest bidder if exists and different from current bidder
-        if current_auction.highest_bidder != bidder && current_auction.highest_bid > 0 {
-            token.transfer(
-                &env.current_contract_address(),
-                &current_auction.highest_bidder,
-                &current_auction.highest_bid,
-            );
+        if let Some(proxy) = Self::get_active_proxy(env, slot, current_auction.auction_id) {
+            // Only the proxy's own payer can replace it without a counter
+            if proxy.bidder != *payer && proxy.max_amount >= value {
+                Self::require_funded(env, payer, spender, token_address, amount)?;
+
+                // Proxy defends its lead, paying one increment over this bid
+                let visible_bid = proxy
+                    .max_amount
+                    .min(Self::calculate_minimum_bid(env, slot, value)?);
+                current_auction.highest_bid = visible_bid;
+                current_auction.bid_amount = visible_bid;
+
+                env.storage()
+                    .persistent()
+                    .set(&(CURRENT_AUCTION, slot.clone()), &current_auction);
+
+                // The challenger escrowed nothing, so only the counter is recorded
+                Self::record_bid(
+                    env,
+                    current_auction.auction_id,
+                    &current_auction.highest_bidder,
+                    visible_bid,
+                    &current_auction.preferred_url,
+                );
+
+                env.events().publish(
+                    (
+                        symbol_short!("px_count"),
+                        slot.clone(),
+                        current_auction.auction_id,
+                    ),
+                    (bidder.clone(), value, visible_bid, current_time),
+                );
+
+                return Ok(());
+            }
+
+            // Proxy is outbid (or replaced by its own bidder) - release its escrow
+            Self::release_proxy_escrow(env, &proxy, proxy.max_amount);
+            env.storage()
+                .persistent()
+                .remove(&(PROXY_BID, slot.clone()));
+        } else if current_auction.highest_bid > 0 {
+            Self::refund_leading_bid(env, &current_auction);
         }
 
-        // Transfer new bid from bidder to contract
-        token.transfer(&bidder, &env.current_contract_address(), &amount);
+        // Reserve the bid from the payer's deposit balance if it covers
+        // it, otherwise transfer it in
+        let base_token: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        let balance = Self::get_balance(env.clone(), payer.clone());
+        let from_balance = spender.is_none() && *token_address == base_token && balance >= amount;
+        let token = token::Client::new(env, token_address);
+        if from_balance {
+            Self::set_balance(env, payer, balance - amount);
+        } else if let Some(spender) = spender {
+            token.transfer_from(spender, payer, &env.current_contract_address(), &amount);
+        } else {
+            token.transfer(payer, &env.current_contract_address(), &amount);
+        }
 
-        // Update auction data
-        current_auction.highest_bid = amount;
+        // Update auction data; metadata and manager belong to the previous leader
+        if current_auction.highest_bidder != *bidder {
+            Self::reset_url_controls(env, &mut current_auction);
+        }
+        current_auction.highest_bid = value;
+        current_auction.bid_token = token_address.clone();
+        current_auction.bid_amount = amount;
+        current_auction.bid_from_balance = from_balance;
         current_auction.highest_bidder = bidder.clone();
+        current_auction.bid_payer = payer.clone();
         current_auction.preferred_url = preferred_url.clone();
 
         // Save updated auction
         env.storage()
             .persistent()
-            .set(&CURRENT_AUCTION, &current_auction);
+            .set(&(CURRENT_AUCTION, slot.clone()), &current_auction);
+
+        Self::record_bid_placed(env, bidder);
+        Self::record_bid(
+            env,
+            current_auction.auction_id,
+            bidder,
+            value,
+            preferred_url,
+        );
 
         // Emit event - Fixed: use symbol_short with max 9 characters and clone String
         env.events().publish(
-            (symbol_short!("bid_place"), current_auction.auction_id),
-            (bidder.clone(), amount, preferred_url.clone(), current_time),
+            (
+                symbol_short!("bid_place"),
+                slot.clone(),
+                current_auction.auction_id,
+            ),
+            (
+                bidder.clone(),
+                payer.clone(),
+                value,
+                preferred_url.clone(),
+                current_time,
+            ),
         );
 
         Ok(())
     }
 
-    /// Start a new auction (only owner)
-    pub fn start_auction(env: Env) -> Result<(), Error> {
-        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
-        owner.require_auth();
+    /// Place a proxy (maximum) bid on the current auction
+    ///
+    /// The full `max_amount` is escrowed and the contract bids on the
+    /// bidder's behalf up to that ceiling, only ever paying one increment
+    /// over the next best bid. The ceiling itself is never exposed through
+    /// `Auction`; unused escrow is refunded at settlement or when outbid.
+    pub fn place_proxy_bid(
+        env: Env,
+        slot: Symbol,
+        bidder: Address,
+        max_amount: i128,
+        preferred_url: String,
+    ) -> Result<(), Error> {
+        bidder.require_auth();
 
-        let mut counter: u64 = env
-            .storage()
-            .persistent()
-            .get(&AUCTION_COUNTER)
-            .unwrap_or(0);
+        let mut current_auction = Self::get_open_auction(&env, &slot)?;
         let current_time = env.ledger().timestamp();
 
-        // End current auction if it exists and hasn't been ended
-        let current_auction: Auction = env.storage().persistent().get(&CURRENT_AUCTION).unwrap();
+        Self::validate_url(&preferred_url)?;
+
+        let minimum_bid = Self::calculate_minimum_bid(&env, &slot, current_auction.highest_bid)?;
+        let token = Self::get_token_client(&env);
+        let existing_proxy = Self::get_active_proxy(&env, &slot, current_auction.auction_id);
+
+        let visible_bid = match existing_proxy {
+            // Raising our own ceiling - only the difference is escrowed
+            Some(proxy) if proxy.bidder == bidder => {
+                if max_amount <= proxy.max_amount {
+                    return Err(Error::BidTooLow);
+                }
+                token.transfer(
+                    &bidder,
+                    &env.current_contract_address(),
+                    &(max_amount - proxy.max_amount),
+                );
+                current_auction.highest_bid
+            }
+            Some(proxy) => {
+                if max_amount < minimum_bid {
+                    return Err(Error::BidTooLow);
+                }
+
+                // Existing proxy wins ties and defends up to its ceiling
+                if proxy.max_amount >= max_amount {
+                    Self::require_funded(&env, &bidder, None, &token.address, max_amount)?;
+
+                    let visible_bid = proxy
+                        .max_amount
+                        .min(Self::calculate_minimum_bid(&env, &slot, max_amount)?);
+                    current_auction.highest_bid = visible_bid;
+                    current_auction.bid_amount = visible_bid;
+
+                    env.storage()
+                        .persistent()
+                        .set(&(CURRENT_AUCTION, slot.clone()), &current_auction);
+
+                    // The challenger escrowed nothing, so only the counter
+                    // is recorded
+                    Self::record_bid(
+                        &env,
+                        current_auction.auction_id,
+                        &current_auction.highest_bidder,
+                        visible_bid,
+                        &current_auction.preferred_url,
+                    );
+
+                    env.events().publish(
+                        (
+                            symbol_short!("px_count"),
+                            slot.clone(),
+                            current_auction.auction_id,
+                        ),
+                        (bidder.clone(), max_amount, visible_bid, current_time),
+                    );
+
+                    return Ok(());
+                }
+
+                Self::release_proxy_escrow(&env, &proxy, proxy.max_amount);
+                token.transfer(&bidder, &env.current_contract_address(), &max_amount);
+
+                max_amount.min(Self::calculate_minimum_bid(&env, &slot, proxy.max_amount)?)
+            }
+            // Converting our own plain leading bid into a proxy bid
+            None if current_auction.highest_bidder == bidder
+                && current_auction.bid_payer == bidder
+                && current_auction.highest_bid > 0
+                && current_auction.bid_token == token.address
+                && !current_auction.bid_from_balance =>
+            {
+                if max_amount <= current_auction.highest_bid {
+                    return Err(Error::BidTooLow);
+                }
+                token.transfer(
+                    &bidder,
+                    &env.current_contract_address(),
+                    &(max_amount - current_auction.highest_bid),
+                );
+                current_auction.highest_bid
+            }
+            None => {
+                if max_amount < minimum_bid {
+                    return Err(Error::BidTooLow);
+                }
 
-        if current_auction.starting_time > 0 && !current_auction.is_ended {
-            Self::end_current_auction(&env)?;
+                if current_auction.highest_bid > 0 {
+                    Self::refund_leading_bid(&env, &current_auction);
+                }
+                token.transfer(&bidder, &env.current_contract_address(), &max_amount);
+
+                minimum_bid
+            }
+        };
+
+        env.storage().persistent().set(
+            &(PROXY_BID, slot.clone()),
+            &ProxyBid {
+                auction_id: current_auction.auction_id,
+                bidder: bidder.clone(),
+                max_amount,
+                pool_id: None,
+            },
+        );
+
+        if current_auction.highest_bidder != bidder {
+            Self::reset_url_controls(&env, &mut current_auction);
         }
+        current_auction.highest_bid = visible_bid;
+        current_auction.bid_token = token.address.clone();
+        current_auction.bid_amount = visible_bid;
+        current_auction.bid_from_balance = false;
+        current_auction.highest_bidder = bidder.clone();
+        current_auction.bid_payer = bidder.clone();
+        current_auction.preferred_url = preferred_url.clone();
 
-        // Increment counter
-        counter += 1;
-        env.storage().persistent().set(&AUCTION_COUNTER, &counter);
+        env.storage()
+            .persistent()
+            .set(&(CURRENT_AUCTION, slot.clone()), &current_auction);
+
+        Self::record_bid_placed(&env, &bidder);
+        Self::record_bid(
+            &env,
+            current_auction.auction_id,
+            &bidder,
+            visible_bid,
+            &preferred_url,
+        );
 
-        // Create new auction
-        let new_auction = Auction {
-            auction_id: counter,
-            starting_time: current_time,
-            ending_time: current_time + AUCTION_DURATION,
-            highest_bid: 0,
-            highest_bidder: owner.clone(),
-            preferred_url: String::from_str(&env, ""),
-            is_ended: false,
-            url_expiry_time: 0,
-        };
+        // Only the visible price is published, never the ceiling
+        env.events().publish(
+            (
+                symbol_short!("bid_place"),
+                slot.clone(),
+                current_auction.auction_id,
+            ),
+            (
+                bidder.clone(),
+                bidder,
+                visible_bid,
+                preferred_url,
+                current_time,
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Open a crowdfunded pool bidding for `url` on a slot's current auction
+    ///
+    /// The URL is fixed: while the pool leads and once it wins, its URL,
+    /// metadata and display rights cannot be changed, transferred or sold.
+    pub fn open_pool(env: Env, slot: Symbol, creator: Address, url: String) -> Result<u64, Error> {
+        creator.require_auth();
+
+        let current_auction = Self::get_open_auction(&env, &slot)?;
+        Self::validate_url(&url)?;
+
+        let pool_id: u64 = env.storage().persistent().get(&POOL_COUNTER).unwrap_or(0) + 1;
+        env.storage().persistent().set(&POOL_COUNTER, &pool_id);
+
+        env.storage().persistent().set(
+            &(POOL, pool_id),
+            &Pool {
+                pool_id,
+                slot: slot.clone(),
+                auction_id: current_auction.auction_id,
+                creator: creator.clone(),
+                url: url.clone(),
+                balance: 0,
+                spent: 0,
+                refunded: 0,
+                contributors: Vec::new(&env),
+            },
+        );
+
+        env.events().publish(
+            (symbol_short!("pool_open"), slot, current_auction.auction_id),
+            (pool_id, creator, url),
+        );
+
+        Ok(pool_id)
+    }
+
+    /// Contribute contract tokens to a pool
+    ///
+    /// The pool bids as a single bidder, defending its lead with the pooled
+    /// balance as its ceiling and only paying one increment over the next
+    /// best bid. Each contribution raises the ceiling and, if the pool is
+    /// not leading, bids again.
+    pub fn contribute(
+        env: Env,
+        pool_id: u64,
+        contributor: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        contributor.require_auth();
+
+        let mut pool = Self::get_pool(env.clone(), pool_id).ok_or(Error::PoolNotFound)?;
+        let current_auction = Self::get_open_auction(&env, &pool.slot)?;
+        if current_auction.auction_id != pool.auction_id {
+            return Err(Error::AuctionEnded);
+        }
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        if !pool.contributors.contains(&contributor) {
+            if pool.contributors.len() >= MAX_POOL_CONTRIBUTORS {
+                return Err(Error::TooManyContributors);
+            }
+            pool.contributors.push_back(contributor.clone());
+        }
+
+        let token = Self::get_token_client(&env);
+        token.transfer(&contributor, &env.current_contract_address(), &amount);
+
+        let mut share = Self::get_pool_share(&env, pool_id, &contributor);
+        share.amount += amount;
+        env.storage()
+            .persistent()
+            .set(&(POOL_SHARE, pool_id, contributor.clone()), &share);
+
+        pool.balance += amount;
+        env.storage().persistent().set(&(POOL, pool_id), &pool);
+
+        env.events().publish(
+            (symbol_short!("pool_add"), pool.slot.clone(), pool_id),
+            (contributor, amount, pool.balance),
+        );
+
+        Self::bid_from_pool(&env, &pool)
+    }
+
+    /// Withdraw a contributor's pro rata share of what a pool did not spend
+    ///
+    /// Available once the pool's auction is settled: the full contribution
+    /// if the pool lost, the unspent remainder if it won. Downtime refunds
+    /// on a winning pool's display can be withdrawn as they are credited.
+    pub fn withdraw_from_pool(env: Env, pool_id: u64, contributor: Address) -> Result<i128, Error> {
+        contributor.require_auth();
+
+        let pool = Self::get_pool(env.clone(), pool_id).ok_or(Error::PoolNotFound)?;
+        if Self::get_auction(env.clone(), pool.auction_id).is_none() {
+            return Err(Error::AuctionNotEnded);
+        }
+
+        let mut share = Self::get_pool_share(&env, pool_id, &contributor);
+        if share.amount == 0 {
+            return Err(Error::NothingToWithdraw);
+        }
+
+        let entitled = share.amount * (pool.balance - pool.spent + pool.refunded) / pool.balance;
+        let amount = entitled - share.withdrawn;
+        if amount <= 0 {
+            return Err(Error::NothingToWithdraw);
+        }
+
+        let token = Self::get_token_client(&env);
+        Self::refund_bid(&env, &token, &contributor, amount, amount);
+
+        share.withdrawn += amount;
+        env.storage()
+            .persistent()
+            .set(&(POOL_SHARE, pool_id, contributor.clone()), &share);
+
+        env.events().publish(
+            (symbol_short!("pool_out"), pool.slot, pool_id),
+            (contributor, amount),
+        );
+
+        Ok(amount)
+    }
+
+    /// Attach ad card metadata to an auction's URL
+    ///
+    /// Callable by the leading bidder while bidding is open and by the
+    /// winner until their display window ends, or by their URL manager.
+    /// Metadata is cleared whenever a different bidder takes the lead.
+    /// A pool's bid keeps the content it was opened with.
+    pub fn set_qr_metadata(
+        env: Env,
+        slot: Symbol,
+        auction_id: u64,
+        caller: Address,
+        metadata: QRMetadata,
+    ) -> Result<(), Error> {
+        let mut auction = Self::get_editable_auction(&env, &slot, auction_id)?;
+        Self::require_url_controller(&env, &auction, &caller)?;
+
+        Self::validate_metadata(&metadata)?;
+
+        auction.metadata = metadata.clone();
+        Self::save_auction(&env, &slot, &auction);
+
+        env.events().publish(
+            (symbol_short!("metadata"), slot, auction_id),
+            (caller, metadata),
+        );
+
+        Ok(())
+    }
+
+    /// Change the URL of a leading bid or of a winner's display window
+    ///
+    /// Same access rules as `set_qr_metadata`.
+    pub fn update_url(
+        env: Env,
+        slot: Symbol,
+        auction_id: u64,
+        caller: Address,
+        url: String,
+    ) -> Result<(), Error> {
+        let mut auction = Self::get_editable_auction(&env, &slot, auction_id)?;
+        Self::require_url_controller(&env, &auction, &caller)?;
+
+        Self::validate_url(&url)?;
+
+        auction.preferred_url = url.clone();
+        Self::save_auction(&env, &slot, &auction);
+
+        // Settled auctions are served from their queued display window
+        if auction.is_ended {
+            if let Some(mut window) = Self::get_queued_window(&env, &slot, auction_id) {
+                window.url = url.clone();
+                Self::save_queued_window(&env, &slot, window);
+            }
+        }
+
+        env.events()
+            .publish((symbol_short!("url_set"), slot, auction_id), (caller, url));
+
+        Ok(())
+    }
+
+    /// Let `manager` edit the URL and metadata of an auction the caller
+    /// leads or holds the display rights of, replacing any previous manager
+    pub fn appoint_manager(
+        env: Env,
+        slot: Symbol,
+        auction_id: u64,
+        manager: Address,
+    ) -> Result<(), Error> {
+        let auction = Self::get_editable_auction(&env, &slot, auction_id)?;
+        Self::require_no_pool(&env, &auction)?;
+        let holder = Self::get_url_holder(&env, &auction);
+        holder.require_auth();
+
+        env.storage().persistent().set(
+            &(URL_MANAGER, auction_id),
+            &UrlManager {
+                holder: holder.clone(),
+                manager: manager.clone(),
+            },
+        );
+
+        env.events().publish(
+            (symbol_short!("manager"), slot, auction_id),
+            (holder, manager),
+        );
+
+        Ok(())
+    }
+
+    /// Revoke the URL manager of an auction the caller leads or holds the
+    /// display rights of
+    pub fn revoke_manager(env: Env, slot: Symbol, auction_id: u64) -> Result<(), Error> {
+        let auction = Self::get_editable_auction(&env, &slot, auction_id)?;
+        let holder = Self::get_url_holder(&env, &auction);
+        holder.require_auth();
+
+        let manager = Self::get_active_manager(&env, &auction).ok_or(Error::Unauthorized)?;
+        env.storage()
+            .persistent()
+            .remove(&(URL_MANAGER, auction_id));
+
+        env.events().publish(
+            (symbol_short!("mgr_revok"), slot, auction_id),
+            (holder, manager),
+        );
+
+        Ok(())
+    }
+
+    /// Transfer the remaining display rights of a settled auction
+    ///
+    /// The new holder controls the URL and metadata until the window ends;
+    /// any manager appointed by the previous holder loses access. Refunds
+    /// and proceeds are unaffected. A pool's display rights stay with its
+    /// contributors.
+    pub fn transfer_display(env: Env, auction_id: u64, to: Address) -> Result<(), Error> {
+        let auction = Self::get_auction(env.clone(), auction_id).ok_or(Error::AuctionNotFound)?;
+        let slot = auction.slot.clone();
+        let auction = Self::get_editable_auction(&env, &slot, auction_id)?;
+        if !auction.is_ended {
+            return Err(Error::AuctionNotEnded);
+        }
+
+        Self::require_no_pool(&env, &auction)?;
+
+        let from = Self::get_url_holder(&env, &auction);
+        from.require_auth();
+
+        Self::set_url_holder(&env, &auction, &to);
+
+        env.events()
+            .publish((symbol_short!("transfer"), slot, auction_id), (from, to));
+
+        Ok(())
+    }
+
+    /// List the remaining display rights of a settled auction for sale at
+    /// `price` contract tokens, replacing any previous listing
+    ///
+    /// Not available for auctions won by a pool.
+    pub fn list_display(env: Env, auction_id: u64, price: i128) -> Result<(), Error> {
+        let auction = Self::get_auction(env.clone(), auction_id).ok_or(Error::AuctionNotFound)?;
+        let slot = auction.slot.clone();
+        let auction = Self::get_editable_auction(&env, &slot, auction_id)?;
+        if !auction.is_ended {
+            return Err(Error::AuctionNotEnded);
+        }
+
+        Self::require_no_pool(&env, &auction)?;
+
+        let seller = Self::get_url_holder(&env, &auction);
+        seller.require_auth();
+
+        if price <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage().persistent().set(
+            &(LISTING, auction_id),
+            &Listing {
+                auction_id,
+                seller: seller.clone(),
+                price,
+                listed_at: env.ledger().timestamp(),
+            },
+        );
+
+        env.events()
+            .publish((symbol_short!("listed"), slot, auction_id), (seller, price));
+
+        Ok(())
+    }
+
+    /// Withdraw a display rights listing (only seller)
+    pub fn cancel_listing(env: Env, auction_id: u64) -> Result<(), Error> {
+        let listing: Listing = env
+            .storage()
+            .persistent()
+            .get(&(LISTING, auction_id))
+            .ok_or(Error::ListingNotFound)?;
+        listing.seller.require_auth();
+
+        env.storage().persistent().remove(&(LISTING, auction_id));
+
+        let auction = Self::get_auction(env.clone(), auction_id).unwrap();
+        env.events().publish(
+            (symbol_short!("unlisted"), auction.slot, auction_id),
+            listing.seller,
+        );
+
+        Ok(())
+    }
+
+    /// Buy listed display rights at the ask price
+    ///
+    /// The seller receives the price minus the platform royalty and the
+    /// buyer becomes the display holder.
+    pub fn buy_listing(env: Env, auction_id: u64, buyer: Address) -> Result<(), Error> {
+        buyer.require_auth();
+
+        let listing = Self::get_listing(env.clone(), auction_id).ok_or(Error::ListingNotFound)?;
+        let auction = Self::get_auction(env.clone(), auction_id).unwrap();
+
+        let royalty =
+            listing.price * Self::get_listing_royalty(env.clone()) as i128 / BPS_DENOMINATOR;
+        let seller_amount = listing.price - royalty;
+
+        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        let token = token::Client::new(&env, &token_address);
+        if seller_amount > 0 {
+            token.transfer(&buyer, &listing.seller, &seller_amount);
+        }
+        if royalty > 0 {
+            let platform_wallet: Address =
+                env.storage().persistent().get(&PLATFORM_WALLET).unwrap();
+            token.transfer(&buyer, &platform_wallet, &royalty);
+        }
+
+        Self::set_url_holder(&env, &auction, &buyer);
+
+        env.events().publish(
+            (symbol_short!("sold"), auction.slot, auction_id),
+            (listing.seller, buyer, listing.price, royalty),
+        );
+
+        Ok(())
+    }
+
+    /// Take over a Harberger slot
+    ///
+    /// The buyer pays the current holder their self-assessed price, and the
+    /// holder's unused tax deposit is refunded to them. A vacant slot is
+    /// free to claim. The buyer sets their own price and prepays `deposit`
+    /// contract tokens of tax.
+    pub fn claim_slot(
+        env: Env,
+        slot: Symbol,
+        buyer: Address,
+        price: i128,
+        url: String,
+        deposit: i128,
+    ) -> Result<(), Error> {
+        Self::require_harberger(&env, &slot)?;
+        buyer.require_auth();
+
+        Self::validate_url(&url)?;
+        Self::validate_harberger_price(&env, &slot, price)?;
+        if deposit <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        let token = token::Client::new(&env, &token_address);
+
+        let previous = Self::settle_harberger_tax(&env, &slot);
+        let mut paid = 0;
+        if let Some(previous) = &previous {
+            token.transfer(&buyer, &previous.holder, &previous.price);
+            token.transfer(
+                &env.current_contract_address(),
+                &previous.holder,
+                &previous.deposit,
+            );
+            paid = previous.price;
+        }
+
+        token.transfer(&buyer, &env.current_contract_address(), &deposit);
+
+        env.storage().persistent().set(
+            &(HOLDING, slot.clone()),
+            &HarbergerHolding {
+                holder: buyer.clone(),
+                price,
+                url,
+                deposit,
+                assessed_at: env.ledger().timestamp(),
+                tax_paid: 0,
+            },
+        );
+
+        env.events().publish(
+            (symbol_short!("h_claim"), slot),
+            (previous.map(|previous| previous.holder), buyer, paid, price),
+        );
+
+        Ok(())
+    }
+
+    /// Self-assess a new price for a Harberger slot (only holder)
+    pub fn set_harberger_price(env: Env, slot: Symbol, price: i128) -> Result<(), Error> {
+        Self::require_harberger(&env, &slot)?;
+
+        let mut holding = Self::settle_harberger_tax(&env, &slot).ok_or(Error::SlotVacant)?;
+        holding.holder.require_auth();
+
+        Self::validate_harberger_price(&env, &slot, price)?;
+
+        holding.price = price;
+        holding.assessed_at = env.ledger().timestamp();
+        holding.tax_paid = 0;
+        env.storage()
+            .persistent()
+            .set(&(HOLDING, slot.clone()), &holding);
+
+        env.events()
+            .publish((symbol_short!("h_price"), slot), (holding.holder, price));
+
+        Ok(())
+    }
+
+    /// Add to the tax deposit of a Harberger slot (only holder)
+    pub fn top_up_tax(env: Env, slot: Symbol, amount: i128) -> Result<(), Error> {
+        Self::require_harberger(&env, &slot)?;
+
+        let mut holding = Self::settle_harberger_tax(&env, &slot).ok_or(Error::SlotVacant)?;
+        holding.holder.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        let token = token::Client::new(&env, &token_address);
+        token.transfer(&holding.holder, &env.current_contract_address(), &amount);
+
+        holding.deposit += amount;
+        env.storage()
+            .persistent()
+            .set(&(HOLDING, slot.clone()), &holding);
+
+        env.events().publish(
+            (symbol_short!("h_topup"), slot),
+            (holding.holder, amount, holding.deposit),
+        );
+
+        Ok(())
+    }
+
+    /// Pay the tax accrued on a Harberger slot to the platform wallet,
+    /// vacating the slot if the deposit has run out. Anyone may call this.
+    pub fn collect_tax(env: Env, slot: Symbol) -> Result<(), Error> {
+        Self::require_harberger(&env, &slot)?;
+        Self::settle_harberger_tax(&env, &slot);
+
+        Ok(())
+    }
+
+    /// Report the scans counted during one hour of a settled auction's
+    /// display window (only scan reporter)
+    ///
+    /// Buckets must be reported in increasing order and only once the hour
+    /// they cover has passed.
+    pub fn report_scans(env: Env, auction_id: u64, bucket: u32, scans: u32) -> Result<(), Error> {
+        let reporter: Address = env
+            .storage()
+            .persistent()
+            .get(&SCAN_REPORTER)
+            .ok_or(Error::Unauthorized)?;
+        reporter.require_auth();
+
+        let auction = Self::get_auction(env.clone(), auction_id).ok_or(Error::AuctionNotFound)?;
+        if auction.highest_bid == 0 {
+            return Err(Error::AuctionNotFound);
+        }
+
+        // Bucket must lie inside the window and be complete
+        let window_start = auction.url_expiry_time - URL_DISPLAY_DURATION;
+        let bucket_end = window_start + (bucket as u64 + 1) * SCAN_BUCKET_DURATION;
+        if bucket_end > auction.url_expiry_time || bucket_end > env.ledger().timestamp() {
+            return Err(Error::InvalidScanBucket);
+        }
+
+        let mut reports = Self::get_scan_reports(env.clone(), auction_id);
+        if let Some(last) = reports.last() {
+            if bucket <= last.bucket {
+                return Err(Error::InvalidScanBucket);
+            }
+        }
+
+        reports.push_back(ScanBucket {
+            bucket,
+            scans,
+            reported_at: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&(SCANS, auction_id), &reports);
+
+        env.events().publish(
+            (symbol_short!("scans"), auction.slot, auction_id),
+            (bucket, scans),
+        );
+
+        Ok(())
+    }
+
+    /// Record a period the slot's display was offline during a settled
+    /// auction's window (only device reporter)
+    ///
+    /// Intervals are clipped to the window and must be reported in order
+    /// without overlapping.
+    pub fn report_downtime(
+        env: Env,
+        auction_id: u64,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), Error> {
+        let reporter: Address = env
+            .storage()
+            .persistent()
+            .get(&DEVICE_REPORTER)
+            .ok_or(Error::Unauthorized)?;
+        reporter.require_auth();
+
+        let auction = Self::get_auction(env.clone(), auction_id).ok_or(Error::AuctionNotFound)?;
+        if auction.highest_bid == 0 {
+            return Err(Error::AuctionNotFound);
+        }
+
+        let window_start = auction.url_expiry_time - URL_DISPLAY_DURATION;
+        let start_time = start_time.max(window_start);
+        let end_time = end_time.min(auction.url_expiry_time);
+        if start_time >= end_time || end_time > env.ledger().timestamp() {
+            return Err(Error::InvalidDowntime);
+        }
+
+        let mut downtime = Self::get_downtime(env.clone(), auction_id);
+        if downtime.len() >= MAX_DOWNTIME_INTERVALS {
+            return Err(Error::InvalidDowntime);
+        }
+        if let Some(last) = downtime.last() {
+            if start_time < last.end_time {
+                return Err(Error::InvalidDowntime);
+            }
+        }
+
+        downtime.push_back(DowntimeInterval {
+            start_time,
+            end_time,
+        });
+        env.storage()
+            .persistent()
+            .set(&(DOWNTIME, auction_id), &downtime);
+
+        env.events().publish(
+            (symbol_short!("downtime"), auction.slot, auction_id),
+            (start_time, end_time),
+        );
+
+        Ok(())
+    }
+
+    /// Release an auction's escrowed proceeds once its display window ends
+    ///
+    /// The winner is refunded pro rata for reported downtime, less anything
+    /// already vested to the platform, and the rest goes to the platform
+    /// wallet. Anyone may call this.
+    pub fn close_display_window(env: Env, auction_id: u64) -> Result<(), Error> {
+        let auction = Self::get_auction(env.clone(), auction_id).ok_or(Error::AuctionNotFound)?;
+        if env.ledger().timestamp() < auction.url_expiry_time {
+            return Err(Error::DisplayNotEnded);
+        }
+
+        let mut escrow = Self::get_escrow(&env, auction_id).ok_or(Error::NothingEscrowed)?;
+        let escrowed = escrow.total - escrow.released - escrow.refunded;
+        if escrowed == 0 {
+            return Err(Error::NothingEscrowed);
+        }
+
+        let downtime = Self::get_total_downtime(env.clone(), auction_id);
+        let refund = (escrow.total * downtime as i128 / URL_DISPLAY_DURATION as i128).min(escrowed);
+        let platform_amount = escrowed - refund;
+
+        let token = token::Client::new(&env, &escrow.token);
+        if refund > 0 {
+            // A winning pool keeps its refund for contributors to withdraw
+            let winning_pool: Option<u64> =
+                env.storage().persistent().get(&(WINNING_POOL, auction_id));
+            match winning_pool {
+                Some(pool_id) => {
+                    let mut pool = Self::get_pool(env.clone(), pool_id).unwrap();
+                    pool.refunded += refund;
+                    env.storage().persistent().set(&(POOL, pool_id), &pool);
+                }
+                None => Self::refund_bid(
+                    &env,
+                    &token,
+                    &auction.bid_payer,
+                    refund,
+                    refund * auction.highest_bid / auction.bid_amount,
+                ),
+            }
+        }
+        if platform_amount > 0 {
+            Self::pay_platform(&env, &token, platform_amount);
+        }
+
+        escrow.refunded += refund;
+        escrow.released += platform_amount;
+        env.storage()
+            .persistent()
+            .set(&(ESCROW, auction_id), &escrow);
+
+        env.events().publish(
+            (
+                Symbol::new(&env, "display_closed"),
+                auction.slot,
+                auction_id,
+            ),
+            (refund, platform_amount, downtime),
+        );
+
+        Ok(())
+    }
+
+    /// Pay the platform whatever has vested of an auction's proceeds
+    ///
+    /// Only linearly vesting escrows release anything before the window
+    /// ends; after that this closes the window like `close_display_window`.
+    /// Anyone may call this. Returns the amount paid to the platform.
+    pub fn claim_proceeds(env: Env, auction_id: u64) -> Result<i128, Error> {
+        let auction = Self::get_auction(env.clone(), auction_id).ok_or(Error::AuctionNotFound)?;
+        let mut escrow = Self::get_escrow(&env, auction_id).ok_or(Error::NothingEscrowed)?;
+
+        if env.ledger().timestamp() >= auction.url_expiry_time {
+            Self::close_display_window(env.clone(), auction_id)?;
+            let closed = Self::get_escrow(&env, auction_id).unwrap();
+            return Ok(closed.released - escrow.released);
+        }
+
+        let claimable = Self::calculate_claimable(&env, &auction, &escrow);
+        if claimable == 0 {
+            return Err(Error::NothingEscrowed);
+        }
+
+        let token = token::Client::new(&env, &escrow.token);
+        Self::pay_platform(&env, &token, claimable);
+
+        escrow.released += claimable;
+        env.storage()
+            .persistent()
+            .set(&(ESCROW, auction_id), &escrow);
+
+        env.events().publish(
+            (symbol_short!("claimed"), auction.slot, auction_id),
+            (claimable, escrow.released),
+        );
+
+        Ok(claimable)
+    }
+
+    /// Deposit contract tokens into the caller's internal balance
+    ///
+    /// Bids covered by the balance are reserved from it instead of being
+    /// transferred, and refunded into it when outbid.
+    pub fn deposit(env: Env, from: Address, amount: i128) -> Result<(), Error> {
+        from.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token = Self::get_token_client(&env);
+        token.transfer(&from, &env.current_contract_address(), &amount);
+
+        let balance = Self::get_balance(env.clone(), from.clone()) + amount;
+        Self::set_balance(&env, &from, balance);
+
+        env.events()
+            .publish((symbol_short!("deposit"), from), (amount, balance));
+
+        Ok(())
+    }
+
+    /// Withdraw contract tokens from the caller's internal balance
+    pub fn withdraw(env: Env, to: Address, amount: i128) -> Result<(), Error> {
+        to.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let balance = Self::get_balance(env.clone(), to.clone());
+        if amount > balance {
+            return Err(Error::InsufficientBalance);
+        }
+
+        Self::set_balance(&env, &to, balance - amount);
+        let token = Self::get_token_client(&env);
+        token.transfer(&env.current_contract_address(), &to, &amount);
+
+        env.events()
+            .publish((symbol_short!("withdraw"), to), (amount, balance - amount));
+
+        Ok(())
+    }
+
+    /// Start a new auction (only owner)
+    pub fn start_auction(env: Env, slot: Symbol) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        if Self::get_harberger_tax_bps(&env, &slot).is_some() {
+            return Err(Error::HarbergerSlot);
+        }
+
+        // End current auction if it exists and hasn't been ended
+        let current_auction: Auction = env
+            .storage()
+            .persistent()
+            .get(&(CURRENT_AUCTION, slot.clone()))
+            .unwrap();
+
+        if current_auction.starting_time > 0 && !current_auction.is_ended {
+            Self::end_current_auction(&env, &slot, None)?;
+        }
+
+        Self::open_next_auction(&env, &slot, &owner);
+
+        Ok(())
+    }
+
+    /// End the current auction
+    ///
+    /// Anyone may settle an expired auction. The `keeper` receives the
+    /// configured keeper reward out of the winning bid and, in continuous
+    /// mode, the next auction is started in the same call.
+    pub fn end_auction(env: Env, slot: Symbol, keeper: Address) -> Result<(), Error> {
+        keeper.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        let current_auction: Auction = env
+            .storage()
+            .persistent()
+            .get(&(CURRENT_AUCTION, slot.clone()))
+            .unwrap();
+
+        if current_auction.starting_time == 0 {
+            return Err(Error::NoAuctionToEnd);
+        }
+
+        let current_time = env.ledger().timestamp();
+        if current_time <= current_auction.ending_time {
+            return Err(Error::AuctionNotEnded);
+        }
+
+        if current_auction.is_ended {
+            return Err(Error::AlreadyEnded);
+        }
+
+        Self::end_current_auction(&env, &slot, Some(&keeper))?;
+
+        if Self::is_continuous_mode(&env, &slot) {
+            let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+            Self::open_next_auction(&env, &slot, &owner);
+        }
+
+        Ok(())
+    }
+
+    /// Internal function to open the next auction
+    fn open_next_auction(env: &Env, slot: &Symbol, owner: &Address) {
+        let mut counter: u64 = env
+            .storage()
+            .persistent()
+            .get(&AUCTION_COUNTER)
+            .unwrap_or(0);
+        let current_time = env.ledger().timestamp();
+
+        // Increment counter
+        counter += 1;
+        env.storage().persistent().set(&AUCTION_COUNTER, &counter);
+
+        // Create new auction
+        let new_auction = Auction {
+            auction_id: counter,
+            slot: slot.clone(),
+            starting_time: current_time,
+            ending_time: current_time + AUCTION_DURATION,
+            highest_bid: 0,
+            highest_bidder: owner.clone(),
+            bid_payer: owner.clone(),
+            bid_token: env.storage().persistent().get(&TOKEN).unwrap(),
+            bid_amount: 0,
+            bid_from_balance: false,
+            preferred_url: String::from_str(env, ""),
+            is_ended: false,
+            url_expiry_time: 0,
+            metadata: Self::get_empty_metadata(env),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&(CURRENT_AUCTION, slot.clone()), &new_auction);
+
+        // Emit event
+        env.events().publish(
+            (symbol_short!("started"), slot.clone(), counter),
+            (current_time, new_auction.ending_time),
+        );
+    }
+
+    /// Internal function to end current auction
+    fn end_current_auction(
+        env: &Env,
+        slot: &Symbol,
+        keeper: Option<&Address>,
+    ) -> Result<(), Error> {
+        let mut current_auction: Auction = env
+            .storage()
+            .persistent()
+            .get(&(CURRENT_AUCTION, slot.clone()))
+            .unwrap();
+
+        let current_time = env.ledger().timestamp();
+
+        // Refund whatever a winning proxy bid escrowed above the final price
+        let mut winning_pool = None;
+        if let Some(proxy) = Self::get_active_proxy(env, slot, current_auction.auction_id) {
+            let unused_escrow = proxy.max_amount - current_auction.highest_bid;
+            if unused_escrow > 0 {
+                Self::release_proxy_escrow(env, &proxy, unused_escrow);
+            }
+            winning_pool = proxy.pool_id;
+            env.storage()
+                .persistent()
+                .remove(&(PROXY_BID, slot.clone()));
+        }
+
+        // Escrow the winning bid, in the token it was paid in, until the
+        // display window closes
+        if current_auction.highest_bid > 0 {
+            let token = token::Client::new(env, &current_auction.bid_token);
+
+            // Pay the keeper's bounty out of the proceeds
+            let mut proceeds = current_auction.bid_amount;
+            if let Some(keeper) = keeper {
+                let reward = Self::calculate_keeper_reward(env, current_auction.highest_bid)
+                    * current_auction.bid_amount
+                    / current_auction.highest_bid;
+                if reward > 0 {
+                    token.transfer(&env.current_contract_address(), keeper, &reward);
+                    proceeds -= reward;
+
+                    env.events().publish(
+                        (
+                            Symbol::new(env, "keeper_paid"),
+                            slot.clone(),
+                            current_auction.auction_id,
+                        ),
+                        (keeper.clone(), reward),
+                    );
+                }
+            }
+
+            env.storage().persistent().set(
+                &(ESCROW, current_auction.auction_id),
+                &ProceedsEscrow {
+                    token: current_auction.bid_token.clone(),
+                    total: proceeds,
+                    released: 0,
+                    refunded: 0,
+                    linear_vesting: Self::is_linear_vesting(env),
+                },
+            );
+
+            match winning_pool {
+                Some(pool_id) => Self::record_pool_win(env, pool_id, &current_auction),
+                None => Self::record_win(
+                    env,
+                    &current_auction.highest_bidder,
+                    &current_auction.bid_payer,
+                    current_auction.auction_id,
+                    current_auction.highest_bid,
+                ),
+            }
+        }
+
+        // Mark auction as ended and queue the winner's display window,
+        // anchored to the scheduled end rather than this settlement call
+        current_auction.is_ended = true;
+        let display_start =
+            Self::next_display_start(env, slot, current_time.min(current_auction.ending_time));
+        current_auction.url_expiry_time = display_start + URL_DISPLAY_DURATION;
+
+        if current_auction.highest_bid > 0 && !current_auction.preferred_url.is_empty() {
+            Self::queue_display_window(
+                env,
+                slot,
+                DisplayWindow {
+                    auction_id: current_auction.auction_id,
+                    holder: current_auction.highest_bidder.clone(),
+                    url: current_auction.preferred_url.clone(),
+                    start_time: display_start,
+                    end_time: current_auction.url_expiry_time,
+                },
+            );
+        }
+
+        // Store completed auction in history
+        env.storage()
+            .persistent()
+            .set(&(AUCTION, current_auction.auction_id), &current_auction);
+
+        let mut history = Self::get_slot_history(env, slot);
+        if history.len() >= MAX_SLOT_HISTORY {
+            history.pop_front();
+        }
+        history.push_back(current_auction.auction_id);
+        env.storage()
+            .persistent()
+            .set(&(SLOT_HISTORY, slot.clone()), &history);
+
+        // Update last completed auction
+        env.storage()
+            .persistent()
+            .set(&(LAST_AUCTION, slot.clone()), &current_auction);
+
+        // Update current auction
+        env.storage()
+            .persistent()
+            .set(&(CURRENT_AUCTION, slot.clone()), &current_auction);
+
+        // Emit event - Fixed: clone String values
+        env.events().publish(
+            (
+                symbol_short!("ended"),
+                slot.clone(),
+                current_auction.auction_id,
+            ),
+            (
+                current_auction.highest_bidder.clone(),
+                current_auction.bid_payer.clone(),
+                current_auction.highest_bid,
+                current_auction.preferred_url.clone(),
+                current_time,
+                current_auction.url_expiry_time,
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Get the QR URL to display
+    ///
+    /// Falls back to the Harberger holder's URL, then to the
+    /// owner-configured house ads while no winner is displaying.
+    pub fn get_qr_url(env: Env, slot: Symbol) -> String {
+        if let Some(url) = Self::get_winner_display_url(&env, &slot) {
+            return url;
+        }
+
+        if let Some(holding) = Self::get_active_holding(&env, &slot) {
+            return holding.url;
+        }
+
+        if let Some(house_ad) = Self::select_house_ad(&env, &slot) {
+            return house_ad.url;
+        }
+
+        String::from_str(&env, "")
+    }
+
+    /// Get current auction URL (during bidding)
+    pub fn get_current_auction_url(env: Env, slot: Symbol) -> String {
+        if Self::is_auction_active(env.clone(), slot.clone()) {
+            let current_auction: Auction = env
+                .storage()
+                .persistent()
+                .get(&(CURRENT_AUCTION, slot.clone()))
+                .unwrap();
+            if !current_auction.preferred_url.is_empty() {
+                return current_auction.preferred_url;
+            }
+        }
+        String::from_str(&env, "")
+    }
+
+    /// Check if there's an active winner or Harberger holder QR URL
+    pub fn has_active_qr_url(env: Env, slot: Symbol) -> bool {
+        Self::get_winner_display_url(&env, &slot).is_some()
+            || Self::get_active_holding(&env, &slot).is_some()
+    }
+
+    /// Get QR URL status and source
+    pub fn get_qr_url_status(env: Env, slot: Symbol) -> QRStatus {
+        // A paid display window always takes precedence over live bids
+        if let Some(window) = Self::get_current_display(env.clone(), slot.clone()) {
+            return QRStatus {
+                status: String::from_str(&env, "winner_display"),
+                source: String::from_str(&env, "Winner Display"),
+                fallback_id: None,
+                metadata: Self::get_auction_metadata(&env, &slot, window.auction_id),
+            };
+        }
+
+        if Self::get_active_holding(&env, &slot).is_some() {
+            return QRStatus {
+                status: String::from_str(&env, "harberger"),
+                source: String::from_str(&env, "Harberger Holder"),
+                fallback_id: None,
+                metadata: Self::get_empty_metadata(&env),
+            };
+        }
+
+        if let Some(house_ad) = Self::select_house_ad(&env, &slot) {
+            return QRStatus {
+                status: String::from_str(&env, "fallback"),
+                source: String::from_str(&env, "House Ad"),
+                fallback_id: Some(house_ad.id),
+                metadata: Self::get_empty_metadata(&env),
+            };
+        }
+
+        if Self::is_auction_active(env.clone(), slot.clone()) {
+            let current_auction: Auction = env
+                .storage()
+                .persistent()
+                .get(&(CURRENT_AUCTION, slot.clone()))
+                .unwrap();
+            if !current_auction.preferred_url.is_empty() {
+                return QRStatus {
+                    status: String::from_str(&env, "auction_active"),
+                    source: String::from_str(&env, "Current Auction"),
+                    fallback_id: None,
+                    metadata: current_auction.metadata,
+                };
+            }
+        }
+
+        QRStatus {
+            status: String::from_str(&env, "default"),
+            source: String::from_str(&env, "Default"),
+            fallback_id: None,
+            metadata: Self::get_empty_metadata(&env),
+        }
+    }
+
+    /// Get QR URL expiry time
+    pub fn get_qr_url_expiry_time(env: Env, slot: Symbol) -> u64 {
+        if let Some(window) = Self::get_current_display(env.clone(), slot.clone()) {
+            return window.end_time;
+        }
+
+        // A Harberger holder displays until their tax deposit runs out
+        if let Some(holding) = Self::get_active_holding(&env, &slot) {
+            let tax_bps = Self::get_harberger_tax_bps(&env, &slot).unwrap();
+            return Self::get_harberger_expiry(&holding, tax_bps);
+        }
+
+        // Otherwise project when the leading bid's window would end
+        if Self::is_auction_active(env.clone(), slot.clone()) {
+            let current_auction: Auction = env
+                .storage()
+                .persistent()
+                .get(&(CURRENT_AUCTION, slot.clone()))
+                .unwrap();
+            if !current_auction.preferred_url.is_empty() {
+                return Self::next_display_start(&env, &slot, current_auction.ending_time)
+                    + URL_DISPLAY_DURATION;
+            }
+        }
+
+        0
+    }
+
+    /// Get the display window currently being served, if any
+    ///
+    /// Includes the window of an expired auction that has not been settled yet.
+    pub fn get_current_display(env: Env, slot: Symbol) -> Option<DisplayWindow> {
+        let current_time = env.ledger().timestamp();
+        Self::get_effective_display_queue(&env, &slot)
+            .iter()
+            .find(|window| window.start_time <= current_time && current_time < window.end_time)
+    }
+
+    /// Get the current and upcoming display windows in order
+    pub fn get_display_schedule(env: Env, slot: Symbol) -> Vec<DisplayWindow> {
+        let current_time = env.ledger().timestamp();
+        let mut schedule = Vec::new(&env);
+        for window in Self::get_effective_display_queue(&env, &slot).iter() {
+            if window.end_time > current_time {
+                schedule.push_back(window);
+            }
+        }
+        schedule
+    }
+
+    /// Get time remaining in current auction
+    pub fn get_time_remaining(env: Env, slot: Symbol) -> u64 {
+        let current_auction: Auction = env
+            .storage()
+            .persistent()
+            .get(&(CURRENT_AUCTION, slot.clone()))
+            .unwrap_or_else(|| Self::get_empty_auction(&env, &slot));
+        let current_time = env.ledger().timestamp();
+
+        if current_time >= current_auction.ending_time {
+            return 0;
+        }
+
+        current_auction.ending_time - current_time
+    }
+
+    /// Check if auction is currently active
+    pub fn is_auction_active(env: Env, slot: Symbol) -> bool {
+        let current_auction: Auction = env
+            .storage()
+            .persistent()
+            .get(&(CURRENT_AUCTION, slot.clone()))
+            .unwrap_or_else(|| Self::get_empty_auction(&env, &slot));
+        let current_time = env.ledger().timestamp();
+
+        current_auction.starting_time > 0
+            && current_time < current_auction.ending_time
+            && !current_auction.is_ended
+    }
+
+    /// Get current auction details
+    pub fn get_current_auction(env: Env, slot: Symbol) -> Auction {
+        env.storage()
+            .persistent()
+            .get(&(CURRENT_AUCTION, slot.clone()))
+            .unwrap_or_else(|| Self::get_empty_auction(&env, &slot))
+    }
+
+    /// Get last completed auction
+    pub fn get_last_auction(env: Env, slot: Symbol) -> Auction {
+        env.storage()
+            .persistent()
+            .get(&(LAST_AUCTION, slot.clone()))
+            .unwrap_or_else(|| Self::get_empty_auction(&env, &slot))
+    }
+
+    /// Get a completed auction by ID
+    pub fn get_auction(env: Env, auction_id: u64) -> Option<Auction> {
+        env.storage().persistent().get(&(AUCTION, auction_id))
+    }
+
+    /// Get auction counter
+    pub fn get_auction_counter(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&AUCTION_COUNTER)
+            .unwrap_or(0)
+    }
+
+    /// Get contract information
+    pub fn get_contract_info(env: Env) -> ContractInfo {
+        ContractInfo {
+            owner: env.storage().persistent().get(&OWNER).unwrap(),
+            platform_wallet: env.storage().persistent().get(&PLATFORM_WALLET).unwrap(),
+            token_address: env.storage().persistent().get(&TOKEN).unwrap(),
+            auction_counter: Self::get_auction_counter(env.clone()),
+            keeper_reward: Self::get_keeper_reward(&env),
+            slots: Self::list_slots(env.clone()),
+        }
+    }
+
+    /// Get a slot's bid parameters and settings
+    pub fn get_slot_config(env: Env, slot: Symbol) -> SlotConfig {
+        SlotConfig {
+            min_bid_increment: Self::get_min_bid_increment(&env, &slot),
+            min_starting_bid: Self::get_min_starting_bid(&env, &slot),
+            min_bid_increment_bps: Self::get_min_increment_bps(&env, &slot),
+            max_bid_param: Self::get_max_bid_param(&env, &slot),
+            increment_tiers: Self::get_increment_tiers(env.clone(), slot.clone()),
+            continuous_mode: Self::is_continuous_mode(&env, &slot),
+            harberger_tax_bps: Self::get_harberger_tax_bps(&env, &slot),
+        }
+    }
+
+    /// Get auction summary (everything frontend needs)
+    pub fn get_auction_summary(env: Env, slot: Symbol) -> AuctionSummary {
+        let current_auction = Self::get_current_auction(env.clone(), slot.clone());
+        let last_auction = Self::get_effective_last_auction(&env, &slot);
+        let is_active = Self::is_auction_active(env.clone(), slot.clone());
+        let time_remaining = Self::get_time_remaining(env.clone(), slot.clone());
+        let has_active_qr = Self::has_active_qr_url(env.clone(), slot.clone());
+        let qr_url = Self::get_qr_url(env.clone(), slot.clone());
+
+        AuctionSummary {
+            current_auction,
+            last_auction,
+            is_active,
+            time_remaining,
+            has_active_qr,
+            qr_url,
+        }
+    }
+
+    /// Calculate minimum bid for current auction
+    ///
+    /// Fails with `OracleStale` if the slot's floors are set in fiat and
+    /// the oracle price is too old to convert them.
+    pub fn get_minimum_bid(env: Env, slot: Symbol) -> Result<i128, Error> {
+        let current_auction = Self::get_current_auction(env.clone(), slot.clone());
+        Self::calculate_minimum_bid(&env, &slot, current_auction.highest_bid)
+    }
+
+    /// Get the tiered minimum bid increment table
+    pub fn get_increment_tiers(env: Env, slot: Symbol) -> Vec<IncrementTier> {
+        env.storage()
+            .persistent()
+            .get(&(INCREMENT_TIERS, slot.clone()))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get a page of an auction's bid ladder, oldest first
+    ///
+    /// `cursor` is the index of the first bid to return among every bid
+    /// ever placed on the auction, so it stays valid as old bids are
+    /// dropped. A cursor before the oldest kept bid starts at that bid
+    /// (see `get_first_bid_index`).
+    pub fn get_bids(env: Env, auction_id: u64, cursor: u32, limit: u32) -> Vec<BidRecord> {
+        let total = Self::get_total_bids(&env, auction_id);
+        let first = Self::get_first_bid_index(env.clone(), auction_id);
+
+        let start = cursor.max(first).min(total);
+        let end = start.saturating_add(limit.min(MAX_BIDS_PAGE)).min(total);
+
+        let mut bids = Vec::new(&env);
+        for index in start..end {
+            bids.push_back(
+                env.storage()
+                    .persistent()
+                    .get(&(BIDS, auction_id, index))
+                    .unwrap(),
+            );
+        }
+        bids
+    }
+
+    /// Get the number of bids stored for an auction
+    pub fn get_bid_count(env: Env, auction_id: u64) -> u32 {
+        Self::get_total_bids(&env, auction_id).min(MAX_BIDS_PER_AUCTION)
+    }
+
+    /// Get the index of the oldest bid still stored for an auction, the
+    /// cursor to start paging `get_bids` from
+    pub fn get_first_bid_index(env: Env, auction_id: u64) -> u32 {
+        Self::get_total_bids(&env, auction_id).saturating_sub(MAX_BIDS_PER_AUCTION)
+    }
+
+    /// Get lifetime statistics for a bidder
+    pub fn get_bidder_stats(env: Env, bidder: Address) -> BidderStats {
+        env.storage()
+            .persistent()
+            .get(&(STATS, bidder))
+            .unwrap_or(BidderStats {
+                bids_placed: 0,
+                auctions_won: 0,
+                total_paid: 0,
+                total_refunded: 0,
+                last_win_id: 0,
+            })
+    }
+
+    /// Get the top bidders by total amount paid for won auctions
+    pub fn get_leaderboard(env: Env) -> Vec<LeaderboardEntry> {
+        env.storage()
+            .persistent()
+            .get(&LEADERBOARD)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the configured fallback house ads
+    pub fn get_house_ads(env: Env, slot: Symbol) -> Vec<HouseAd> {
+        env.storage()
+            .persistent()
+            .get(&(HOUSE_ADS, slot.clone()))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get a slot's most recent completed auctions, oldest first
+    pub fn get_auction_history(env: Env, slot: Symbol) -> Vec<Auction> {
+        let mut auctions = Vec::new(&env);
+
+        for auction_id in Self::get_slot_history(&env, &slot).iter() {
+            if let Some(auction) = Self::get_auction(env.clone(), auction_id) {
+                auctions.push_back(auction);
+            }
+        }
+
+        auctions
+    }
+
+    /// Get the scan reports submitted for an auction, in bucket order
+    pub fn get_scan_reports(env: Env, auction_id: u64) -> Vec<ScanBucket> {
+        env.storage()
+            .persistent()
+            .get(&(SCANS, auction_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the total attested scans for an auction
+    pub fn get_total_scans(env: Env, auction_id: u64) -> u64 {
+        Self::get_scan_reports(env, auction_id)
+            .iter()
+            .map(|report| report.scans as u64)
+            .sum()
+    }
+
+    /// Get the winning bid divided by attested scans (0 before any scans)
+    pub fn get_cost_per_scan(env: Env, auction_id: u64) -> i128 {
+        let total_scans = Self::get_total_scans(env.clone(), auction_id);
+        if total_scans == 0 {
+            return 0;
+        }
+
+        Self::get_auction(env, auction_id)
+            .map(|auction| auction.highest_bid / total_scans as i128)
+            .unwrap_or(0)
+    }
+
+    /// Get an address's internal deposit balance in contract tokens
+    pub fn get_balance(env: Env, address: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(BALANCE, address))
+            .unwrap_or(0)
+    }
+
+    /// Get the address currently allowed to manage an auction's URL, if any
+    pub fn get_url_manager(env: Env, slot: Symbol, auction_id: u64) -> Option<Address> {
+        let current_auction = Self::get_current_auction(env.clone(), slot.clone());
+        let auction = if current_auction.auction_id == auction_id {
+            current_auction
+        } else {
+            Self::get_auction(env.clone(), auction_id).filter(|auction| auction.slot == slot)?
+        };
+
+        Self::get_active_manager(&env, &auction)
+    }
+
+    /// Get the address holding a settled auction's display rights
+    pub fn get_display_holder(env: Env, auction_id: u64) -> Result<Address, Error> {
+        let auction = Self::get_auction(env.clone(), auction_id).ok_or(Error::AuctionNotFound)?;
+        Ok(Self::get_url_holder(&env, &auction))
+    }
+
+    /// Get a crowdfunded pool by id
+    pub fn get_pool(env: Env, pool_id: u64) -> Option<Pool> {
+        env.storage().persistent().get(&(POOL, pool_id))
+    }
+
+    /// Get how much an address has contributed to a pool
+    pub fn get_pool_contribution(env: Env, pool_id: u64, contributor: Address) -> i128 {
+        Self::get_pool_share(&env, pool_id, &contributor).amount
+    }
+
+    /// Get a Harberger slot's holder, price, URL and remaining deposit, if held
+    pub fn get_harberger_holding(env: Env, slot: Symbol) -> Option<HarbergerHolding> {
+        Self::get_active_holding(&env, &slot)
+    }
+
+    /// Get the seconds until a Harberger holder's tax deposit runs out
+    pub fn get_tax_runway(env: Env, slot: Symbol) -> u64 {
+        match Self::get_active_holding(&env, &slot) {
+            Some(holding) => {
+                let tax_bps = Self::get_harberger_tax_bps(&env, &slot).unwrap();
+                Self::get_harberger_expiry(&holding, tax_bps) - env.ledger().timestamp()
+            }
+            None => 0,
+        }
+    }
+
+    /// Get the open listing for an auction's display rights, if any
+    pub fn get_listing(env: Env, auction_id: u64) -> Option<Listing> {
+        let listing: Listing = env.storage().persistent().get(&(LISTING, auction_id))?;
+        let auction = Self::get_auction(env.clone(), auction_id)?;
+
+        if env.ledger().timestamp() >= auction.url_expiry_time
+            || listing.seller != Self::get_url_holder(&env, &auction)
+        {
+            return None;
+        }
+
+        Some(listing)
+    }
+
+    /// Get the platform royalty on display rights sales, in basis points
+    pub fn get_listing_royalty(env: Env) -> u32 {
+        env.storage().persistent().get(&ROYALTY).unwrap_or(0)
+    }
+
+    /// Get the tokens accepted for bids besides the contract token
+    pub fn get_accepted_tokens(env: Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&ACCEPTED_TOKENS)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the price oracle used to value bids in accepted tokens, if any
+    pub fn get_price_oracle(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&PRICE_ORACLE)
+    }
+
+    /// Get the minimum bid for the current auction in units of `token`
+    pub fn get_minimum_token_bid(env: Env, slot: Symbol, token: Address) -> Result<i128, Error> {
+        let minimum_bid = Self::get_minimum_bid(env.clone(), slot)?;
+        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        if token == token_address {
+            return Ok(minimum_bid);
+        }
+
+        // Round up so the converted amount is never below the minimum
+        let base_price = Self::get_price(&env, &token_address)?;
+        let token_price = Self::get_price(&env, &token)?;
+        Ok((minimum_bid * base_price + token_price - 1) / token_price)
+    }
+
+    /// Get a slot's fiat-denominated bid floors, if configured
+    pub fn get_fiat_floors(env: Env, slot: Symbol) -> Option<FiatFloors> {
+        env.storage().persistent().get(&(FIAT_FLOORS, slot))
+    }
+
+    /// Get the maximum age in seconds of an oracle price before it is stale
+    pub fn get_oracle_max_age(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&ORACLE_MAX_AGE)
+            .unwrap_or(DEFAULT_ORACLE_MAX_AGE)
+    }
+
+    /// Get the downtime intervals reported for an auction's display window
+    pub fn get_downtime(env: Env, auction_id: u64) -> Vec<DowntimeInterval> {
+        env.storage()
+            .persistent()
+            .get(&(DOWNTIME, auction_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the total seconds of reported downtime for an auction
+    pub fn get_total_downtime(env: Env, auction_id: u64) -> u64 {
+        Self::get_downtime(env, auction_id)
+            .iter()
+            .map(|interval| interval.end_time - interval.start_time)
+            .sum()
+    }
+
+    /// Get the proceeds still held for an auction's display window
+    pub fn get_escrowed_proceeds(env: Env, auction_id: u64) -> i128 {
+        Self::get_escrow(&env, auction_id)
+            .map(|escrow| escrow.total - escrow.released - escrow.refunded)
+            .unwrap_or(0)
+    }
+
+    /// Get locked, claimable, released and refunded proceeds for an auction
+    pub fn get_proceeds(env: Env, auction_id: u64) -> ProceedsStatus {
+        let escrow = Self::get_escrow(&env, auction_id);
+        let auction = Self::get_auction(env.clone(), auction_id);
+
+        match (escrow, auction) {
+            (Some(escrow), Some(auction)) => ProceedsStatus {
+                total: escrow.total,
+                released: escrow.released,
+                refunded: escrow.refunded,
+                claimable: Self::calculate_claimable(&env, &auction, &escrow),
+                locked: escrow.total - escrow.released - escrow.refunded,
+            },
+            _ => ProceedsStatus {
+                total: 0,
+                released: 0,
+                refunded: 0,
+                claimable: 0,
+                locked: 0,
+            },
+        }
+    }
+
+    /// Get the address allowed to report device downtime, if any
+    pub fn get_device_reporter(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DEVICE_REPORTER)
+    }
+
+    /// Get the address allowed to report scans, if any
+    pub fn get_scan_reporter(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&SCAN_REPORTER)
+    }
+
+    // === OWNER FUNCTIONS ===
+
+    /// Set minimum bid increment (only owner)
+    pub fn set_min_bid_increment(env: Env, slot: Symbol, new_increment: i128) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        Self::validate_bid_params(
+            &env,
+            &slot,
+            new_increment,
+            Self::get_min_starting_bid(&env, &slot),
+        )?;
+
+        env.storage()
+            .persistent()
+            .set(&(MIN_BID_INCREMENT, slot.clone()), &new_increment);
+
+        env.events()
+            .publish((symbol_short!("min_inc"), slot), new_increment);
+
+        Ok(())
+    }
+
+    /// Set minimum starting bid (only owner)
+    pub fn set_min_starting_bid(
+        env: Env,
+        slot: Symbol,
+        new_starting_bid: i128,
+    ) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        Self::validate_bid_params(
+            &env,
+            &slot,
+            Self::get_min_bid_increment(&env, &slot),
+            new_starting_bid,
+        )?;
+
+        env.storage()
+            .persistent()
+            .set(&(MIN_STARTING_BID, slot.clone()), &new_starting_bid);
+
+        env.events()
+            .publish((symbol_short!("min_bid"), slot), new_starting_bid);
+
+        Ok(())
+    }
+
+    /// Set percentage-based minimum bid increment in basis points (only owner)
+    ///
+    /// The effective increment is the larger of the flat increment and this
+    /// percentage of the current highest bid. Zero disables it.
+    pub fn set_min_bid_increment_bps(env: Env, slot: Symbol, new_bps: u32) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        if new_bps > MAX_INCREMENT_BPS {
+            return Err(Error::InvalidBasisPoints);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(MIN_INCREMENT_BPS, slot.clone()), &new_bps);
+
+        env.events()
+            .publish((symbol_short!("min_bps"), slot), new_bps);
+
+        Ok(())
+    }
+
+    /// Set the tiered minimum bid increment table (only owner)
+    ///
+    /// Tiers must be sorted by strictly ascending `min_bid`. While a tier
+    /// matches the current highest bid its increment replaces the flat one.
+    /// An empty table disables tiering.
+    pub fn set_increment_tiers(
+        env: Env,
+        slot: Symbol,
+        tiers: Vec<IncrementTier>,
+    ) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        if tiers.len() > MAX_INCREMENT_TIERS {
+            return Err(Error::InvalidTiers);
+        }
+
+        let max_bid_param = Self::get_max_bid_param(&env, &slot);
+        let mut previous_min_bid: i128 = -1;
+        for tier in tiers.iter() {
+            if tier.min_bid < 0 || tier.min_bid <= previous_min_bid {
+                return Err(Error::InvalidTiers);
+            }
+            if tier.increment <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            if tier.increment > max_bid_param {
+                return Err(Error::AmountTooHigh);
+            }
+            previous_min_bid = tier.min_bid;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(INCREMENT_TIERS, slot.clone()), &tiers);
+
+        env.events()
+            .publish((symbol_short!("inc_tiers"), slot), tiers.len());
+
+        Ok(())
+    }
+
+    /// Set a single fallback URL shown when no winner is displaying (only owner)
+    pub fn set_fallback_url(env: Env, slot: Symbol, url: String) -> Result<(), Error> {
+        let mut house_ads = Vec::new(&env);
+        house_ads.push_back(HouseAd {
+            id: 0,
+            url,
+            weight: 1,
+        });
+        Self::set_house_ads(env, slot.clone(), house_ads)
+    }
+
+    /// Set the weighted house ad rotation used as fallback (only owner)
+    ///
+    /// Entries rotate every `HOUSE_AD_ROTATION_PERIOD`, each receiving a
+    /// share of periods proportional to its weight. An empty list clears the
+    /// fallback.
+    pub fn set_house_ads(env: Env, slot: Symbol, house_ads: Vec<HouseAd>) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        if house_ads.len() > MAX_HOUSE_ADS {
+            return Err(Error::InvalidHouseAds);
+        }
+
+        for (index, house_ad) in house_ads.iter().enumerate() {
+            Self::validate_url(&house_ad.url)?;
+            if house_ad.weight == 0 {
+                return Err(Error::InvalidHouseAds);
+            }
+            // Ids must be unique so the reported fallback id is unambiguous
+            if house_ads
+                .iter()
+                .skip(index + 1)
+                .any(|other| other.id == house_ad.id)
+            {
+                return Err(Error::InvalidHouseAds);
+            }
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(HOUSE_ADS, slot.clone()), &house_ads);
+
+        env.events()
+            .publish((symbol_short!("house_ads"), slot), house_ads.len());
+
+        Ok(())
+    }
+
+    /// Set the tokens accepted for bids besides the contract token (only owner)
+    ///
+    /// Leading bids already placed in a removed token are still refunded
+    /// and settled in that token.
+    pub fn set_accepted_tokens(env: Env, tokens: Vec<Address>) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        if tokens.len() > MAX_ACCEPTED_TOKENS {
+            return Err(Error::InvalidTokens);
+        }
+
+        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        for (index, token) in tokens.iter().enumerate() {
+            if token == token_address || tokens.iter().skip(index + 1).any(|other| other == token) {
+                return Err(Error::InvalidTokens);
+            }
+        }
+
+        env.storage().persistent().set(&ACCEPTED_TOKENS, &tokens);
+
+        env.events().publish((symbol_short!("tokens"),), tokens);
+
+        Ok(())
+    }
+
+    /// Set the SEP-40 price oracle used to value bids (only owner)
+    pub fn set_price_oracle(env: Env, oracle: Address) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        env.storage().persistent().set(&PRICE_ORACLE, &oracle);
+
+        env.events().publish((symbol_short!("oracle"),), oracle);
+
+        Ok(())
+    }
+
+    /// Set the maximum age of an oracle price before it is stale (only owner)
+    pub fn set_oracle_max_age(env: Env, max_age: u64) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        if max_age == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage().persistent().set(&ORACLE_MAX_AGE, &max_age);
+
+        env.events().publish((symbol_short!("oracle_mx"),), max_age);
+
+        Ok(())
+    }
+
+    /// Denominate a slot's starting bid and flat increment in the price
+    /// oracle's base asset instead of the contract token (only owner)
+    pub fn set_fiat_floors(
+        env: Env,
+        slot: Symbol,
+        min_starting_bid: i128,
+        min_bid_increment: i128,
+    ) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        if min_starting_bid <= 0 || min_bid_increment <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        if min_bid_increment > min_starting_bid * MAX_INCREMENT_FACTOR {
+            return Err(Error::IncrementTooHigh);
+        }
+
+        env.storage().persistent().set(
+            &(FIAT_FLOORS, slot.clone()),
+            &FiatFloors {
+                min_starting_bid,
+                min_bid_increment,
+            },
+        );
+
+        env.events().publish(
+            (symbol_short!("fiat"), slot),
+            (min_starting_bid, min_bid_increment),
+        );
+
+        Ok(())
+    }
+
+    /// Go back to the slot's floors in contract token units (only owner)
+    pub fn clear_fiat_floors(env: Env, slot: Symbol) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        env.storage()
+            .persistent()
+            .remove(&(FIAT_FLOORS, slot.clone()));
+
+        env.events().publish((symbol_short!("fiat_off"), slot), ());
+
+        Ok(())
+    }
+
+    /// Set the reward paid to keepers settling expired auctions (only owner)
+    pub fn set_keeper_reward(
+        env: Env,
+        fixed_amount: i128,
+        bps: u32,
+        max_amount: i128,
+    ) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        if fixed_amount < 0 || max_amount < 0 || bps > BPS_DENOMINATOR as u32 {
+            return Err(Error::InvalidKeeperReward);
+        }
+
+        if fixed_amount > max_amount {
+            return Err(Error::InvalidKeeperReward);
+        }
+
+        let keeper_reward = KeeperReward {
+            fixed_amount,
+            bps,
+            max_amount,
+        };
+        env.storage()
+            .persistent()
+            .set(&KEEPER_REWARD, &keeper_reward);
+
+        env.events()
+            .publish((symbol_short!("keeper"),), (fixed_amount, bps, max_amount));
+
+        Ok(())
+    }
+
+    /// Set the trusted address that attests scan counts (only owner)
+    pub fn set_scan_reporter(env: Env, reporter: Address) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        env.storage().persistent().set(&SCAN_REPORTER, &reporter);
+
+        env.events().publish((symbol_short!("scan_rep"),), reporter);
+
+        Ok(())
+    }
+
+    /// Set the trusted address that reports display downtime (only owner)
+    pub fn set_device_reporter(env: Env, reporter: Address) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        env.storage().persistent().set(&DEVICE_REPORTER, &reporter);
+
+        env.events().publish((symbol_short!("dev_rep"),), reporter);
+
+        Ok(())
+    }
+
+    /// Vest proceeds linearly over display windows settled from now on
+    /// instead of releasing them at window end (only owner)
+    pub fn set_linear_vesting(env: Env, enabled: bool) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        env.storage().persistent().set(&LINEAR_VESTING, &enabled);
+
+        env.events().publish((symbol_short!("vesting"),), enabled);
+
+        Ok(())
+    }
+
+    /// Switch a slot to Harberger ownership instead of daily auctions, or
+    /// change its daily tax rate in basis points of the price (only owner)
+    ///
+    /// Only possible once no auction is running and every winner display
+    /// window has ended.
+    pub fn set_harberger_mode(env: Env, slot: Symbol, tax_bps: u32) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        if tax_bps == 0 || tax_bps > BPS_DENOMINATOR as u32 {
+            return Err(Error::InvalidBasisPoints);
+        }
+
+        let current_auction = Self::get_current_auction(env.clone(), slot.clone());
+        if current_auction.starting_time > 0 && !current_auction.is_ended {
+            return Err(Error::AuctionInProgress);
+        }
+
+        // A holder would otherwise pay tax while winner windows are shown
+        if !Self::get_display_schedule(env.clone(), slot.clone()).is_empty() {
+            return Err(Error::DisplayScheduled);
+        }
+
+        // Tax up to now is charged at the old rate
+        if let Some(mut holding) = Self::settle_harberger_tax(&env, &slot) {
+            holding.assessed_at = env.ledger().timestamp();
+            holding.tax_paid = 0;
+            env.storage()
+                .persistent()
+                .set(&(HOLDING, slot.clone()), &holding);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(HARBERGER, slot.clone()), &tax_bps);
+
+        env.events()
+            .publish((symbol_short!("harberger"), slot), tax_bps);
+
+        Ok(())
+    }
+
+    /// Return a Harberger slot to daily auctions, refunding the holder's
+    /// unused tax deposit (only owner)
+    pub fn disable_harberger_mode(env: Env, slot: Symbol) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_harberger(&env, &slot)?;
+
+        if let Some(holding) = Self::settle_harberger_tax(&env, &slot) {
+            let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+            token::Client::new(&env, &token_address).transfer(
+                &env.current_contract_address(),
+                &holding.holder,
+                &holding.deposit,
+            );
+            env.storage().persistent().remove(&(HOLDING, slot.clone()));
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&(HARBERGER, slot.clone()));
+
+        env.events().publish((symbol_short!("harb_off"), slot), ());
+
+        Ok(())
+    }
+
+    /// Set the platform royalty taken from display rights sales, in basis
+    /// points (only owner)
+    pub fn set_listing_royalty(env: Env, bps: u32) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        if bps > BPS_DENOMINATOR as u32 {
+            return Err(Error::InvalidBasisPoints);
+        }
+
+        env.storage().persistent().set(&ROYALTY, &bps);
+
+        env.events().publish((symbol_short!("royalty"),), bps);
+
+        Ok(())
+    }
+
+    /// Enable or disable starting the next auction on settlement (only owner)
+    pub fn set_continuous_mode(env: Env, slot: Symbol, enabled: bool) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        env.storage()
+            .persistent()
+            .set(&(CONTINUOUS_MODE, slot.clone()), &enabled);
+
+        env.events()
+            .publish((symbol_short!("continue"), slot), enabled);
+
+        Ok(())
+    }
+
+    /// Set the ceiling for bid parameters (only owner)
+    pub fn set_max_bid_param(env: Env, slot: Symbol, new_ceiling: i128) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+        Self::require_slot(&env, &slot)?;
+
+        if new_ceiling <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Existing parameters must still fit under the new ceiling
+        if Self::get_min_bid_increment(&env, &slot) > new_ceiling
+            || Self::get_min_starting_bid(&env, &slot) > new_ceiling
+        {
+            return Err(Error::AmountTooHigh);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(MAX_BID_PARAM, slot.clone()), &new_ceiling);
+
+        env.events()
+            .publish((symbol_short!("max_param"), slot), new_ceiling);
+
+        Ok(())
+    }
+
+    /// Update platform wallet (only owner)
+    pub fn set_platform_wallet(env: Env, new_wallet: Address) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&PLATFORM_WALLET, &new_wallet);
+
+        env.events()
+            .publish((symbol_short!("platform"),), new_wallet);
+
+        Ok(())
+    }
+
+    /// Delegate contract upgrades to another address, e.g. a factory (only owner)
+    pub fn set_upgrader(env: Env, upgrader: Address) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        env.storage().persistent().set(&UPGRADER, &upgrader);
+
+        env.events().publish((symbol_short!("upgrader"),), upgrader);
+
+        Ok(())
+    }
+
+    /// Replace the contract code (only upgrader, defaults to owner)
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        let upgrader: Address = env
+            .storage()
+            .persistent()
+            .get(&UPGRADER)
+            .unwrap_or_else(|| env.storage().persistent().get(&OWNER).unwrap());
+        upgrader.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events()
+            .publish((symbol_short!("upgraded"),), new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Transfer ownership (only owner)
+    pub fn transfer_ownership(env: Env, new_owner: Address) -> Result<(), Error> {
+        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
+        owner.require_auth();
+
+        env.storage().persistent().set(&OWNER, &new_owner);
+
+        env.events()
+            .publish((symbol_short!("owner"),), (owner, new_owner));
+
+        Ok(())
+    }
+
+    // === HELPER FUNCTIONS ===
+
+    /// Get minimum bid increment
+    fn get_min_bid_increment(env: &Env, slot: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(MIN_BID_INCREMENT, slot.clone()))
+            .unwrap_or(DEFAULT_MIN_BID_INCREMENT)
+    }
+
+    /// Get minimum starting bid
+    fn get_min_starting_bid(env: &Env, slot: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(MIN_STARTING_BID, slot.clone()))
+            .unwrap_or(DEFAULT_MIN_STARTING_BID)
+    }
+
+    /// Get percentage-based minimum bid increment
+    fn get_min_increment_bps(env: &Env, slot: &Symbol) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&(MIN_INCREMENT_BPS, slot.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Get ceiling for bid parameters
+    fn get_max_bid_param(env: &Env, slot: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(MAX_BID_PARAM, slot.clone()))
+            .unwrap_or(DEFAULT_MAX_BID_PARAM)
+    }
+
+    /// Get the keeper reward configuration
+    fn get_keeper_reward(env: &Env) -> KeeperReward {
+        env.storage()
+            .persistent()
+            .get(&KEEPER_REWARD)
+            .unwrap_or(KeeperReward {
+                fixed_amount: 0,
+                bps: 0,
+                max_amount: 0,
+            })
+    }
+
+    /// Check if settlement also starts the next auction
+    fn is_continuous_mode(env: &Env, slot: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(CONTINUOUS_MODE, slot.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Check if new escrows vest linearly over the display window
+    fn is_linear_vesting(env: &Env) -> bool {
+        env.storage()
+            .persistent()
+            .get(&LINEAR_VESTING)
+            .unwrap_or(false)
+    }
+
+    /// Calculate the keeper reward for a winning bid
+    fn calculate_keeper_reward(env: &Env, winning_bid: i128) -> i128 {
+        let keeper_reward = Self::get_keeper_reward(env);
+        let reward =
+            keeper_reward.fixed_amount + winning_bid * keeper_reward.bps as i128 / BPS_DENOMINATOR;
+
+        reward.min(keeper_reward.max_amount).min(winning_bid)
+    }
+
+    /// Validate a minimum increment / starting bid pair against the configured bounds
+    fn validate_bid_params(
+        env: &Env,
+        slot: &Symbol,
+        increment: i128,
+        starting_bid: i128,
+    ) -> Result<(), Error> {
+        if increment <= 0 || starting_bid <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let max_bid_param = Self::get_max_bid_param(env, slot);
+        if increment > max_bid_param || starting_bid > max_bid_param {
+            return Err(Error::AmountTooHigh);
+        }
+
+        if increment > starting_bid * MAX_INCREMENT_FACTOR {
+            return Err(Error::IncrementTooHigh);
+        }
+
+        Ok(())
+    }
+
+    /// Load the current auction, failing unless it is open for bids
+    fn get_open_auction(env: &Env, slot: &Symbol) -> Result<Auction, Error> {
+        Self::require_slot(env, slot)?;
+
+        let current_auction: Auction = env
+            .storage()
+            .persistent()
+            .get(&(CURRENT_AUCTION, slot.clone()))
+            .ok_or(Error::NoActiveAuction)?;
+
+        // Check if auction is active
+        if env.ledger().timestamp() >= current_auction.ending_time {
+            return Err(Error::AuctionEnded);
+        }
+
+        if current_auction.starting_time == 0 {
+            return Err(Error::NoActiveAuction);
+        }
+
+        Ok(current_auction)
+    }
+
+    /// Append a bid to an auction's bounded bid ladder
+    fn record_bid(
+        env: &Env,
+        auction_id: u64,
+        bidder: &Address,
+        amount: i128,
+        preferred_url: &String,
+    ) {
+        // Each bid is its own entry so the ladder never grows a single
+        // ledger entry; the oldest is dropped beyond the cap
+        let index = Self::get_total_bids(env, auction_id);
+        if index >= MAX_BIDS_PER_AUCTION {
+            env.storage()
+                .persistent()
+                .remove(&(BIDS, auction_id, index - MAX_BIDS_PER_AUCTION));
+        }
+
+        env.storage().persistent().set(
+            &(BIDS, auction_id, index),
+            &BidRecord {
+                bidder: bidder.clone(),
+                amount,
+                preferred_url: preferred_url.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&(BID_COUNT, auction_id), &(index + 1));
+    }
+
+    /// Number of bids ever recorded for an auction, including dropped ones
+    fn get_total_bids(env: &Env, auction_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&(BID_COUNT, auction_id))
+            .unwrap_or(0)
+    }
+
+    /// Fail unless `payer` could fund a bid of `amount` in `token_address`
+    ///
+    /// A proxy counter takes nothing from the challenger, so this keeps an
+    /// unfunded bid from pushing the price up to the proxy's ceiling.
+    fn require_funded(
+        env: &Env,
+        payer: &Address,
+        spender: Option<&Address>,
+        token_address: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let token = token::Client::new(env, token_address);
+        let base_token: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        let available = match spender {
+            Some(spender) => token.balance(payer).min(token.allowance(payer, spender)),
+            None if *token_address == base_token => token
+                .balance(payer)
+                .max(Self::get_balance(env.clone(), payer.clone())),
+            None => token.balance(payer),
+        };
+
+        if available < amount {
+            return Err(Error::InsufficientBalance);
+        }
+
+        Ok(())
+    }
+
+    /// Refund escrowed bid funds and track them in the bidder's stats
+    ///
+    /// `value` is the refund in contract token units, which stats use.
+    fn refund_bid(env: &Env, token: &token::Client, to: &Address, amount: i128, value: i128) {
+        token.transfer(&env.current_contract_address(), to, &amount);
+
+        let mut stats = Self::get_bidder_stats(env.clone(), to.clone());
+        stats.total_refunded += value;
+        env.storage().persistent().set(&(STATS, to.clone()), &stats);
+    }
+
+    /// Refund the current leading bid to its payer the way it was funded
+    ///
+    /// Bids reserved from a deposit balance go back into it; others are
+    /// transferred back in the token they were paid in.
+    fn refund_leading_bid(env: &Env, auction: &Auction) {
+        if !auction.bid_from_balance {
+            let token = token::Client::new(env, &auction.bid_token);
+            Self::refund_bid(
+                env,
+                &token,
+                &auction.bid_payer,
+                auction.bid_amount,
+                auction.highest_bid,
+            );
+            return;
+        }
+
+        let balance = Self::get_balance(env.clone(), auction.bid_payer.clone());
+        Self::set_balance(env, &auction.bid_payer, balance + auction.bid_amount);
+
+        let mut stats = Self::get_bidder_stats(env.clone(), auction.bid_payer.clone());
+        stats.total_refunded += auction.highest_bid;
+        env.storage()
+            .persistent()
+            .set(&(STATS, auction.bid_payer.clone()), &stats);
+    }
+
+    /// Store an internal deposit balance
+    fn set_balance(env: &Env, address: &Address, balance: i128) {
+        env.storage()
+            .persistent()
+            .set(&(BALANCE, address.clone()), &balance);
+    }
+
+    /// Count an accepted bid towards the bidder's stats
+    fn record_bid_placed(env: &Env, bidder: &Address) {
+        let mut stats = Self::get_bidder_stats(env.clone(), bidder.clone());
+        stats.bids_placed += 1;
+        env.storage()
+            .persistent()
+            .set(&(STATS, bidder.clone()), &stats);
+    }
+
+    /// Record a won auction in the winner's stats, and its price in the
+    /// payer's stats and the leaderboard
+    fn record_win(env: &Env, winner: &Address, payer: &Address, auction_id: u64, amount: i128) {
+        let mut stats = Self::get_bidder_stats(env.clone(), winner.clone());
+        stats.auctions_won += 1;
+        stats.last_win_id = auction_id;
+        env.storage()
+            .persistent()
+            .set(&(STATS, winner.clone()), &stats);
+
+        let mut stats = Self::get_bidder_stats(env.clone(), payer.clone());
+        stats.total_paid += amount;
+        env.storage()
+            .persistent()
+            .set(&(STATS, payer.clone()), &stats);
+
+        // Re-insert the payer at its sorted position, highest spend first
+        let mut leaderboard = Self::get_leaderboard(env.clone());
+        if let Some(index) = leaderboard.iter().position(|entry| entry.bidder == *payer) {
+            leaderboard.remove(index as u32);
+        }
+
+        let position = leaderboard
+            .iter()
+            .position(|entry| entry.total_paid < stats.total_paid)
+            .unwrap_or(leaderboard.len() as usize) as u32;
+
+        if position < LEADERBOARD_SIZE {
+            leaderboard.insert(
+                position,
+                LeaderboardEntry {
+                    bidder: payer.clone(),
+                    total_paid: stats.total_paid,
+                },
+            );
+            if leaderboard.len() > LEADERBOARD_SIZE {
+                leaderboard.pop_back();
+            }
+            env.storage().persistent().set(&LEADERBOARD, &leaderboard);
+        }
+    }
+
+    /// Return unused proxy escrow to its bidder; a pool's stays pooled
+    fn release_proxy_escrow(env: &Env, proxy: &ProxyBid, amount: i128) {
+        if proxy.pool_id.is_some() {
+            return;
+        }
+
+        let token = Self::get_token_client(env);
+        Self::refund_bid(env, &token, &proxy.bidder, amount, amount);
+    }
+
+    /// A contributor's stake in a pool, zero if they never contributed
+    fn get_pool_share(env: &Env, pool_id: u64, contributor: &Address) -> PoolShare {
+        env.storage()
+            .persistent()
+            .get(&(POOL_SHARE, pool_id, contributor.clone()))
+            .unwrap_or(PoolShare {
+                amount: 0,
+                withdrawn: 0,
+            })
+    }
+
+    /// Bid with a pool's balance as its proxy ceiling
+    ///
+    /// Mirrors `place_proxy_bid`, with the contract bidding for the pool
+    /// and the escrow taken from the pooled balance. A pool below the
+    /// minimum bid simply waits for more contributions.
+    fn bid_from_pool(env: &Env, pool: &Pool) -> Result<(), Error> {
+        let slot = &pool.slot;
+        let mut current_auction = Self::get_open_auction(env, slot)?;
+        let current_time = env.ledger().timestamp();
+        let bidder = env.current_contract_address();
+        let minimum_bid = Self::calculate_minimum_bid(env, slot, current_auction.highest_bid)?;
+
+        let visible_bid = match Self::get_active_proxy(env, slot, current_auction.auction_id) {
+            // Already leading - only the ceiling rises
+            Some(mut proxy) if proxy.pool_id == Some(pool.pool_id) => {
+                proxy.max_amount = pool.balance;
+                env.storage()
+                    .persistent()
+                    .set(&(PROXY_BID, slot.clone()), &proxy);
+                return Ok(());
+            }
+            Some(proxy) => {
+                if pool.balance < minimum_bid {
+                    return Ok(());
+                }
+
+                // Existing proxy wins ties and defends up to its ceiling
+                if proxy.max_amount >= pool.balance {
+                    let visible_bid =
+                        proxy
+                            .max_amount
+                            .min(Self::calculate_minimum_bid(env, slot, pool.balance)?);
+                    current_auction.highest_bid = visible_bid;
+                    current_auction.bid_amount = visible_bid;
+
+                    env.storage()
+                        .persistent()
+                        .set(&(CURRENT_AUCTION, slot.clone()), &current_auction);
+
+                    // Only the counter is recorded, as for any other challenger
+                    Self::record_bid(
+                        env,
+                        current_auction.auction_id,
+                        &current_auction.highest_bidder,
+                        visible_bid,
+                        &current_auction.preferred_url,
+                    );
+
+                    env.events().publish(
+                        (
+                            symbol_short!("px_count"),
+                            slot.clone(),
+                            current_auction.auction_id,
+                        ),
+                        (
+                            pool.creator.clone(),
+                            pool.balance,
+                            visible_bid,
+                            current_time,
+                        ),
+                    );
+
+                    return Ok(());
+                }
+
+                Self::release_proxy_escrow(env, &proxy, proxy.max_amount);
+                pool.balance
+                    .min(Self::calculate_minimum_bid(env, slot, proxy.max_amount)?)
+            }
+            None => {
+                if pool.balance < minimum_bid {
+                    return Ok(());
+                }
+
+                if current_auction.highest_bid > 0 {
+                    Self::refund_leading_bid(env, &current_auction);
+                }
+
+                minimum_bid
+            }
+        };
+
+        env.storage().persistent().set(
+            &(PROXY_BID, slot.clone()),
+            &ProxyBid {
+                auction_id: current_auction.auction_id,
+                bidder: bidder.clone(),
+                max_amount: pool.balance,
+                pool_id: Some(pool.pool_id),
+            },
+        );
+
+        // The creator controls the URL; the contract pays from the pool
+        if current_auction.highest_bidder != pool.creator {
+            Self::reset_url_controls(env, &mut current_auction);
+        }
+        current_auction.highest_bid = visible_bid;
+        current_auction.bid_token = Self::get_token_client(env).address.clone();
+        current_auction.bid_amount = visible_bid;
+        current_auction.bid_from_balance = false;
+        current_auction.highest_bidder = pool.creator.clone();
+        current_auction.bid_payer = bidder.clone();
+        current_auction.preferred_url = pool.url.clone();
 
         env.storage()
             .persistent()
-            .set(&CURRENT_AUCTION, &new_auction);
+            .set(&(CURRENT_AUCTION, slot.clone()), &current_auction);
+
+        Self::record_bid(
+            env,
+            current_auction.auction_id,
+            &pool.creator,
+            visible_bid,
+            &pool.url,
+        );
 
-        // Emit event
         env.events().publish(
-            (symbol_short!("started"), counter),
-            (current_time, new_auction.ending_time),
+            (
+                symbol_short!("pool_bid"),
+                slot.clone(),
+                current_auction.auction_id,
+            ),
+            (pool.pool_id, visible_bid, pool.url.clone(), current_time),
         );
 
         Ok(())
     }
 
-    /// End the current auction
-    pub fn end_auction(env: Env) -> Result<(), Error> {
-        let current_auction: Auction = env.storage().persistent().get(&CURRENT_AUCTION).unwrap();
+    /// Charge a winning pool and attribute the payment to its contributors
+    /// in proportion to their contributions
+    fn record_pool_win(env: &Env, pool_id: u64, auction: &Auction) {
+        let mut pool = Self::get_pool(env.clone(), pool_id).unwrap();
+        pool.spent = auction.highest_bid;
+        env.storage().persistent().set(&(POOL, pool_id), &pool);
+        env.storage()
+            .persistent()
+            .set(&(WINNING_POOL, auction.auction_id), &pool_id);
+
+        for contributor in pool.contributors.iter() {
+            let share = Self::get_pool_share(env, pool_id, &contributor);
+            Self::record_win(
+                env,
+                &contributor,
+                &contributor,
+                auction.auction_id,
+                auction.highest_bid * share.amount / pool.balance,
+            );
+        }
+    }
 
-        if current_auction.starting_time == 0 {
-            return Err(Error::NoAuctionToEnd);
+    /// Get the proxy bid held for an auction, if any
+    fn get_active_proxy(env: &Env, slot: &Symbol, auction_id: u64) -> Option<ProxyBid> {
+        env.storage()
+            .persistent()
+            .get::<_, ProxyBid>(&(PROXY_BID, slot.clone()))
+            .filter(|proxy| proxy.auction_id == auction_id)
+    }
+
+    /// Value an amount of `token` in contract token units
+    ///
+    /// Both prices come from the same oracle, so its decimals and base
+    /// asset cancel out.
+    fn get_token_value(env: &Env, token: &Address, amount: i128) -> Result<i128, Error> {
+        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        if *token == token_address {
+            return Ok(amount);
         }
 
-        let current_time = env.ledger().timestamp();
-        if current_time <= current_auction.ending_time {
-            return Err(Error::AuctionNotEnded);
+        let base_price = Self::get_price(env, &token_address)?;
+        let token_price = Self::get_price(env, token)?;
+        Ok(amount * token_price / base_price)
+    }
+
+    /// Latest oracle price for a Stellar asset contract
+    fn get_price(env: &Env, token: &Address) -> Result<i128, Error> {
+        let oracle: Address = env
+            .storage()
+            .persistent()
+            .get(&PRICE_ORACLE)
+            .ok_or(Error::PriceUnavailable)?;
+
+        let price = PriceOracleClient::new(env, &oracle)
+            .lastprice(&Asset::Stellar(token.clone()))
+            .ok_or(Error::PriceUnavailable)?;
+        if price.price <= 0 {
+            return Err(Error::PriceUnavailable);
         }
 
-        if current_auction.is_ended {
-            return Err(Error::AlreadyEnded);
+        let max_age = Self::get_oracle_max_age(env.clone());
+        if env.ledger().timestamp() > price.timestamp + max_age {
+            return Err(Error::OracleStale);
         }
 
-        Self::end_current_auction(&env)
+        Ok(price.price)
     }
 
-    /// Internal function to end current auction
-    fn end_current_auction(env: &Env) -> Result<(), Error> {
-        let mut current_auction: Auction =
-            env.storage().persistent().get(&CURRENT_AUCTION).unwrap();
+    /// Starting bid and flat increment in contract token units
+    ///
+    /// Fiat floors are converted at the latest oracle price, rounding up.
+    fn get_bid_floors(env: &Env, slot: &Symbol) -> Result<(i128, i128), Error> {
+        let fiat_floors = match Self::get_fiat_floors(env.clone(), slot.clone()) {
+            Some(fiat_floors) => fiat_floors,
+            None => {
+                return Ok((
+                    Self::get_min_starting_bid(env, slot),
+                    Self::get_min_bid_increment(env, slot),
+                ))
+            }
+        };
 
-        let current_time = env.ledger().timestamp();
+        let token = Self::get_token_client(env);
+        let price = Self::get_price(env, &token.address)?;
+        let unit = 10i128.pow(token.decimals());
+        let convert = |amount: i128| (amount * unit + price - 1) / price;
 
-        // Transfer winning bid to platform wallet if there's a winner
-        if current_auction.highest_bid > 0 {
-            let platform_wallet: Address =
-                env.storage().persistent().get(&PLATFORM_WALLET).unwrap();
-            let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
-            let token = token::Client::new(env, &token_address);
+        Ok((
+            convert(fiat_floors.min_starting_bid),
+            convert(fiat_floors.min_bid_increment),
+        ))
+    }
 
-            token.transfer(
-                &env.current_contract_address(),
-                &platform_wallet,
-                &current_auction.highest_bid,
-            );
+    /// Get a client for the configured bid token
+    fn get_token_client(env: &Env) -> token::Client<'_> {
+        let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+        token::Client::new(env, &token_address)
+    }
+
+    /// Calculate the minimum acceptable bid over a given highest bid
+    ///
+    /// The base increment comes from the highest matching tier, or the flat
+    /// increment if none matches. The percentage increment applies on top of
+    /// it with the base acting as a floor.
+    fn calculate_minimum_bid(env: &Env, slot: &Symbol, highest_bid: i128) -> Result<i128, Error> {
+        let (starting_bid, flat_increment) = Self::get_bid_floors(env, slot)?;
+        if highest_bid == 0 {
+            return Ok(starting_bid);
         }
 
-        // Mark auction as ended and set URL expiry
-        current_auction.is_ended = true;
-        current_auction.url_expiry_time = current_time + URL_DISPLAY_DURATION;
-
-        // Store completed auction in history - Fixed: use static string instead of format!
-        let auction_id = current_auction.auction_id;
-        let auction_key = if auction_id <= 99 {
-            // For auction IDs 1-99, we can create static keys
-            match auction_id {
-                1 => symbol_short!("auct_1"),
-                2 => symbol_short!("auct_2"),
-                3 => symbol_short!("auct_3"),
-                4 => symbol_short!("auct_4"),
-                5 => symbol_short!("auct_5"),
-                _ => symbol_short!("auct_def"), // fallback for other IDs
+        let mut base_increment = flat_increment;
+        for tier in Self::get_increment_tiers(env.clone(), slot.clone()).iter() {
+            if highest_bid < tier.min_bid {
+                break;
             }
-        } else {
-            symbol_short!("auct_def") // fallback for high IDs
-        };
+            base_increment = tier.increment;
+        }
 
-        env.storage()
-            .persistent()
-            .set(&auction_key, &current_auction);
+        let bps = Self::get_min_increment_bps(env, slot) as i128;
+        let percent_increment = highest_bid * bps / BPS_DENOMINATOR;
 
-        // Update last completed auction
-        env.storage()
-            .persistent()
-            .set(&LAST_AUCTION, &current_auction);
+        Ok(highest_bid + base_increment.max(percent_increment))
+    }
 
-        // Update current auction
+    /// Get the paid slot holder's URL while its display window is running
+    fn get_winner_display_url(env: &Env, slot: &Symbol) -> Option<String> {
+        Self::get_current_display(env.clone(), slot.clone()).map(|window| window.url)
+    }
+
+    /// Get all stored display windows, including expired ones not yet pruned
+    fn get_display_queue(env: &Env, slot: &Symbol) -> Vec<DisplayWindow> {
         env.storage()
             .persistent()
-            .set(&CURRENT_AUCTION, &current_auction);
+            .get(&(DISPLAY_QUEUE, slot.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
 
-        // Emit event - Fixed: clone String values
-        env.events().publish(
-            (symbol_short!("ended"), current_auction.auction_id),
-            (
-                current_auction.highest_bidder.clone(),
-                current_auction.highest_bid,
-                current_auction.preferred_url.clone(),
-                current_time,
-                current_auction.url_expiry_time,
-            ),
-        );
+    /// Stored display window of a settled auction, if still queued
+    fn get_queued_window(env: &Env, slot: &Symbol, auction_id: u64) -> Option<DisplayWindow> {
+        Self::get_display_queue(env, slot)
+            .iter()
+            .find(|window| window.auction_id == auction_id)
+    }
 
-        Ok(())
+    /// Replace the stored display window with the same auction id
+    fn save_queued_window(env: &Env, slot: &Symbol, window: DisplayWindow) {
+        let mut queue = Self::get_display_queue(env, slot);
+        if let Some(index) = queue
+            .iter()
+            .position(|queued| queued.auction_id == window.auction_id)
+        {
+            queue.set(index as u32, window);
+            env.storage()
+                .persistent()
+                .set(&(DISPLAY_QUEUE, slot.clone()), &queue);
+        }
     }
 
-    /// Get the QR URL to display
-    pub fn get_qr_url(env: Env) -> String {
-        let last_auction: Auction = env
+    /// Window the current auction's winner will get once settled
+    ///
+    /// Lets read paths serve the winner as soon as `ending_time` passes,
+    /// without waiting for someone to call `end_auction`.
+    fn get_pending_display(env: &Env, slot: &Symbol) -> Option<DisplayWindow> {
+        let current_auction: Auction = env
             .storage()
             .persistent()
-            .get(&LAST_AUCTION)
-            .unwrap_or_else(|| Self::get_empty_auction(&env));
-
-        let current_time = env.ledger().timestamp();
+            .get(&(CURRENT_AUCTION, slot.clone()))?;
 
-        // If last auction is ended and URL is still valid
-        if last_auction.is_ended
-            && current_time < last_auction.url_expiry_time
-            && last_auction.preferred_url.len() > 0
+        if current_auction.is_ended
+            || current_auction.starting_time == 0
+            || env.ledger().timestamp() < current_auction.ending_time
+            || current_auction.highest_bid == 0
+            || current_auction.preferred_url.is_empty()
         {
-            return last_auction.preferred_url;
+            return None;
         }
 
-        String::from_str(&env, "")
+        let start_time = Self::next_display_start(env, slot, current_auction.ending_time);
+        Some(DisplayWindow {
+            auction_id: current_auction.auction_id,
+            holder: current_auction.highest_bidder,
+            url: current_auction.preferred_url,
+            start_time,
+            end_time: start_time + URL_DISPLAY_DURATION,
+        })
     }
 
-    /// Get current auction URL (during bidding)
-    pub fn get_current_auction_url(env: Env) -> String {
-        if Self::is_auction_active(env.clone()) {
-            let current_auction: Auction =
-                env.storage().persistent().get(&CURRENT_AUCTION).unwrap();
-            if current_auction.preferred_url.len() > 0 {
-                return current_auction.preferred_url;
-            }
+    /// Stored display windows followed by the pending unsettled one, if any
+    fn get_effective_display_queue(env: &Env, slot: &Symbol) -> Vec<DisplayWindow> {
+        let mut queue = Self::get_display_queue(env, slot);
+        if let Some(window) = Self::get_pending_display(env, slot) {
+            queue.push_back(window);
         }
-        String::from_str(&env, "")
+        queue
     }
 
-    /// Check if there's an active QR URL
-    pub fn has_active_qr_url(env: Env) -> bool {
-        Self::get_qr_url(env).len() > 0
+    /// Get the last auction as it stands, treating an expired unsettled
+    /// current auction as already ended
+    fn get_effective_last_auction(env: &Env, slot: &Symbol) -> Auction {
+        if let Some(window) = Self::get_pending_display(env, slot) {
+            let mut auction = Self::get_current_auction(env.clone(), slot.clone());
+            auction.is_ended = true;
+            auction.url_expiry_time = window.end_time;
+            return auction;
+        }
+
+        Self::get_last_auction(env.clone(), slot.clone())
     }
 
-    /// Get QR URL status and source
-    pub fn get_qr_url_status(env: Env) -> QRStatus {
-        if Self::is_auction_active(env.clone()) {
-            let current_auction: Auction =
-                env.storage().persistent().get(&CURRENT_AUCTION).unwrap();
-            if current_auction.preferred_url.len() > 0 {
-                return QRStatus {
-                    status: String::from_str(&env, "auction_active"),
-                    source: String::from_str(&env, "Current Auction"),
-                };
+    /// Earliest start for a new window: `not_before` or when the queue drains
+    fn next_display_start(env: &Env, slot: &Symbol, not_before: u64) -> u64 {
+        Self::get_display_queue(env, slot)
+            .last()
+            .map(|window| window.end_time.max(not_before))
+            .unwrap_or(not_before)
+    }
+
+    /// Append a window to the display queue, dropping expired windows
+    fn queue_display_window(env: &Env, slot: &Symbol, window: DisplayWindow) {
+        let current_time = env.ledger().timestamp();
+        let mut queue = Vec::new(env);
+        for queued in Self::get_display_queue(env, slot).iter() {
+            if queued.end_time > current_time {
+                queue.push_back(queued);
             }
         }
+        queue.push_back(window);
 
-        let last_auction: Auction = env
-            .storage()
+        env.storage()
             .persistent()
-            .get(&LAST_AUCTION)
-            .unwrap_or_else(|| Self::get_empty_auction(&env));
-        let current_time = env.ledger().timestamp();
+            .set(&(DISPLAY_QUEUE, slot.clone()), &queue);
+    }
 
-        if last_auction.is_ended
-            && current_time < last_auction.url_expiry_time
-            && last_auction.preferred_url.len() > 0
-        {
-            return QRStatus {
-                status: String::from_str(&env, "winner_display"),
-                source: String::from_str(&env, "Winner Display"),
-            };
+    /// Deterministically pick the house ad for the current rotation period
+    fn select_house_ad(env: &Env, slot: &Symbol) -> Option<HouseAd> {
+        let house_ads = Self::get_house_ads(env.clone(), slot.clone());
+        let total_weight: u64 = house_ads.iter().map(|ad| ad.weight as u64).sum();
+        if total_weight == 0 {
+            return None;
         }
 
-        QRStatus {
-            status: String::from_str(&env, "default"),
-            source: String::from_str(&env, "Default"),
+        let period = env.ledger().timestamp() / HOUSE_AD_ROTATION_PERIOD;
+        let mut ticket = period % total_weight;
+        for house_ad in house_ads.iter() {
+            if ticket < house_ad.weight as u64 {
+                return Some(house_ad);
+            }
+            ticket -= house_ad.weight as u64;
         }
+
+        None
     }
 
-    /// Get QR URL expiry time
-    pub fn get_qr_url_expiry_time(env: Env) -> u64 {
-        if Self::is_auction_active(env.clone()) {
-            let current_auction: Auction =
-                env.storage().persistent().get(&CURRENT_AUCTION).unwrap();
-            if current_auction.preferred_url.len() > 0 {
-                return current_auction.ending_time + URL_DISPLAY_DURATION;
+    /// Check a bid or display URL is present and within the length cap
+    fn validate_url(url: &String) -> Result<(), Error> {
+        if url.is_empty() {
+            return Err(Error::EmptyUrl);
+        }
+        if url.len() > MAX_URL_LEN {
+            return Err(Error::UrlTooLong);
+        }
+        Ok(())
+    }
+
+    /// Check metadata field lengths; a title is required
+    fn validate_metadata(metadata: &QRMetadata) -> Result<(), Error> {
+        if metadata.title.is_empty()
+            || metadata.title.len() > MAX_TITLE_LEN
+            || metadata.description.len() > MAX_DESCRIPTION_LEN
+            || metadata.advertiser.len() > MAX_ADVERTISER_LEN
+            || metadata.category.len() > MAX_CATEGORY_LEN
+        {
+            return Err(Error::InvalidMetadata);
+        }
+        Ok(())
+    }
+
+    /// Get an auction whose winner still controls its content
+    ///
+    /// That is the slot's current auction once it has a bid (including an
+    /// expired one awaiting settlement), or a settled auction whose display
+    /// window has not ended yet.
+    fn get_editable_auction(env: &Env, slot: &Symbol, auction_id: u64) -> Result<Auction, Error> {
+        Self::require_slot(env, slot)?;
+
+        let current_auction = Self::get_current_auction(env.clone(), slot.clone());
+        if current_auction.auction_id == auction_id && !current_auction.is_ended {
+            if current_auction.highest_bid == 0 {
+                return Err(Error::AuctionNotFound);
             }
+            if let Some(window) = Self::get_pending_display(env, slot) {
+                if env.ledger().timestamp() >= window.end_time {
+                    return Err(Error::DisplayEnded);
+                }
+            }
+            return Ok(current_auction);
         }
 
-        let last_auction: Auction = env
+        let auction: Auction = env
             .storage()
             .persistent()
-            .get(&LAST_AUCTION)
-            .unwrap_or_else(|| Self::get_empty_auction(&env));
-        let current_time = env.ledger().timestamp();
-
-        if last_auction.is_ended && current_time < last_auction.url_expiry_time {
-            return last_auction.url_expiry_time;
+            .get(&(AUCTION, auction_id))
+            .ok_or(Error::AuctionNotFound)?;
+        if auction.slot != *slot || auction.highest_bid == 0 {
+            return Err(Error::AuctionNotFound);
+        }
+        if env.ledger().timestamp() >= auction.url_expiry_time {
+            return Err(Error::DisplayEnded);
         }
 
-        0
+        Ok(auction)
     }
 
-    /// Get time remaining in current auction
-    pub fn get_time_remaining(env: Env) -> u64 {
-        let current_auction: Auction = env
-            .storage()
-            .persistent()
-            .get(&CURRENT_AUCTION)
-            .unwrap_or_else(|| Self::get_empty_auction(&env));
-        let current_time = env.ledger().timestamp();
+    /// Require auth from the URL holder or their manager
+    fn require_url_controller(env: &Env, auction: &Auction, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+        Self::require_no_pool(env, auction)?;
 
-        if current_time >= current_auction.ending_time {
-            return 0;
+        if *caller == Self::get_url_holder(env, auction)
+            || Self::get_active_manager(env, auction).as_ref() == Some(caller)
+        {
+            return Ok(());
         }
 
-        current_auction.ending_time - current_time
+        Err(Error::Unauthorized)
     }
 
-    /// Check if auction is currently active
-    pub fn is_auction_active(env: Env) -> bool {
-        let current_auction: Auction = env
-            .storage()
-            .persistent()
-            .get(&CURRENT_AUCTION)
-            .unwrap_or_else(|| Self::get_empty_auction(&env));
-        let current_time = env.ledger().timestamp();
+    /// Fail if a pool leads or has won the auction, whose URL and display
+    /// rights are fixed when the pool is opened
+    fn require_no_pool(env: &Env, auction: &Auction) -> Result<(), Error> {
+        let pool_id: Option<u64> = if auction.is_ended {
+            env.storage()
+                .persistent()
+                .get(&(WINNING_POOL, auction.auction_id))
+        } else {
+            Self::get_active_proxy(env, &auction.slot, auction.auction_id)
+                .and_then(|proxy| proxy.pool_id)
+        };
+        if pool_id.is_some() {
+            return Err(Error::PoolAuction);
+        }
 
-        current_auction.starting_time > 0
-            && current_time < current_auction.ending_time
-            && !current_auction.is_ended
+        Ok(())
     }
 
-    /// Get current auction details
-    pub fn get_current_auction(env: Env) -> Auction {
+    /// Drop the metadata and manager set by the previous leader
+    fn reset_url_controls(env: &Env, auction: &mut Auction) {
+        auction.metadata = Self::get_empty_metadata(env);
         env.storage()
             .persistent()
-            .get(&CURRENT_AUCTION)
-            .unwrap_or_else(|| Self::get_empty_auction(&env))
+            .remove(&(URL_MANAGER, auction.auction_id));
     }
 
-    /// Get last completed auction
-    pub fn get_last_auction(env: Env) -> Auction {
-        env.storage()
+    /// Manager appointed by the auction's current URL holder, if any
+    fn get_active_manager(env: &Env, auction: &Auction) -> Option<Address> {
+        let record: UrlManager = env
+            .storage()
             .persistent()
-            .get(&LAST_AUCTION)
-            .unwrap_or_else(|| Self::get_empty_auction(&env))
-    }
+            .get(&(URL_MANAGER, auction.auction_id))?;
 
-    /// Get specific auction by ID (limited implementation due to format! constraint)
-    pub fn get_auction(env: Env, auction_id: u64) -> Option<Auction> {
-        let auction_key = match auction_id {
-            1 => symbol_short!("auct_1"),
-            2 => symbol_short!("auct_2"),
-            3 => symbol_short!("auct_3"),
-            4 => symbol_short!("auct_4"),
-            5 => symbol_short!("auct_5"),
-            _ => return None, // For now, only support first 5 auctions due to format! limitation
-        };
-        env.storage().persistent().get(&auction_key)
+        if record.holder != Self::get_url_holder(env, auction) {
+            return None;
+        }
+
+        Some(record.manager)
     }
 
-    /// Get auction counter
-    pub fn get_auction_counter(env: Env) -> u64 {
+    /// Address controlling an auction's URL: the leading bidder, or whoever
+    /// was last transferred the display rights once settled
+    fn get_url_holder(env: &Env, auction: &Auction) -> Address {
+        if !auction.is_ended {
+            return auction.highest_bidder.clone();
+        }
+
         env.storage()
             .persistent()
-            .get(&AUCTION_COUNTER)
-            .unwrap_or(0)
+            .get(&(DISPLAY_HOLDER, auction.auction_id))
+            .unwrap_or_else(|| auction.highest_bidder.clone())
     }
 
-    /// Get contract information
-    pub fn get_contract_info(env: Env) -> ContractInfo {
-        ContractInfo {
-            owner: env.storage().persistent().get(&OWNER).unwrap(),
-            platform_wallet: env.storage().persistent().get(&PLATFORM_WALLET).unwrap(),
-            token_address: env.storage().persistent().get(&TOKEN).unwrap(),
-            auction_counter: Self::get_auction_counter(env.clone()),
-            min_bid_increment: Self::get_min_bid_increment(&env),
-            min_starting_bid: Self::get_min_starting_bid(&env),
-        }
+    /// Daily Harberger tax rate of a slot, if it is in Harberger mode
+    fn get_harberger_tax_bps(env: &Env, slot: &Symbol) -> Option<u32> {
+        env.storage().persistent().get(&(HARBERGER, slot.clone()))
     }
 
-    /// Get auction summary (everything frontend needs)
-    pub fn get_auction_summary(env: Env) -> AuctionSummary {
-        let current_auction = Self::get_current_auction(env.clone());
-        let last_auction = Self::get_last_auction(env.clone());
-        let is_active = Self::is_auction_active(env.clone());
-        let time_remaining = Self::get_time_remaining(env.clone());
-        let has_active_qr = Self::has_active_qr_url(env.clone());
-        let qr_url = Self::get_qr_url(env.clone());
+    /// Fail unless the slot exists and is in Harberger mode
+    fn require_harberger(env: &Env, slot: &Symbol) -> Result<u32, Error> {
+        Self::require_slot(env, slot)?;
+        Self::get_harberger_tax_bps(env, slot).ok_or(Error::NotHarbergerSlot)
+    }
 
-        AuctionSummary {
-            current_auction,
-            last_auction,
-            is_active,
-            time_remaining,
-            has_active_qr,
-            qr_url,
+    /// Check a self-assessed price against the slot's bid ceiling
+    fn validate_harberger_price(env: &Env, slot: &Symbol, price: i128) -> Result<(), Error> {
+        if price <= 0 {
+            return Err(Error::InvalidAmount);
         }
+        if price > Self::get_max_bid_param(env, slot) {
+            return Err(Error::AmountTooHigh);
+        }
+        Ok(())
     }
 
-    /// Calculate minimum bid for current auction
-    pub fn get_minimum_bid(env: Env) -> i128 {
-        let current_auction = Self::get_current_auction(env.clone());
-        let min_starting_bid = Self::get_min_starting_bid(&env);
-        let min_bid_increment = Self::get_min_bid_increment(&env);
+    /// Tax accrued and not yet collected, capped at the remaining deposit
+    fn get_harberger_tax_due(holding: &HarbergerHolding, tax_bps: u32, now: u64) -> i128 {
+        let elapsed = now.saturating_sub(holding.assessed_at) as i128;
+        let accrued =
+            holding.price * tax_bps as i128 * elapsed / (BPS_DENOMINATOR * TAX_PERIOD as i128);
 
-        if current_auction.highest_bid == 0 {
-            min_starting_bid
-        } else {
-            current_auction.highest_bid + min_bid_increment
-        }
+        (accrued - holding.tax_paid).min(holding.deposit)
     }
 
-    /// Get auction history (simplified - returns last 5 auctions)
-    pub fn get_auction_history(env: Env) -> Vec<Auction> {
-        let mut auctions = Vec::new(&env);
+    /// Time at which the accrued tax exhausts the holder's deposit
+    fn get_harberger_expiry(holding: &HarbergerHolding, tax_bps: u32) -> u64 {
+        let tax_per_period = holding.price * tax_bps as i128;
+        let funded = (holding.deposit + holding.tax_paid) * BPS_DENOMINATOR * TAX_PERIOD as i128;
+        let seconds = (funded + tax_per_period - 1) / tax_per_period;
 
-        // Due to format! limitation, we can only retrieve first 5 auctions
-        for i in 1..=5 {
-            if let Some(auction) = Self::get_auction(env.clone(), i) {
-                auctions.push_back(auction);
-            }
+        holding
+            .assessed_at
+            .saturating_add(seconds.min(u64::MAX as i128) as u64)
+    }
+
+    /// Current Harberger holding with accrued tax deducted, unless lapsed
+    fn get_active_holding(env: &Env, slot: &Symbol) -> Option<HarbergerHolding> {
+        let tax_bps = Self::get_harberger_tax_bps(env, slot)?;
+        let mut holding: HarbergerHolding =
+            env.storage().persistent().get(&(HOLDING, slot.clone()))?;
+
+        let now = env.ledger().timestamp();
+        if now >= Self::get_harberger_expiry(&holding, tax_bps) {
+            return None;
         }
 
-        auctions
+        let due = Self::get_harberger_tax_due(&holding, tax_bps, now);
+        holding.deposit -= due;
+        holding.tax_paid += due;
+        Some(holding)
     }
 
-    // === OWNER FUNCTIONS ===
+    /// Pay accrued Harberger tax to the platform and vacate the slot once
+    /// the deposit is exhausted, returning the holding if still active
+    fn settle_harberger_tax(env: &Env, slot: &Symbol) -> Option<HarbergerHolding> {
+        let tax_bps = Self::get_harberger_tax_bps(env, slot)?;
+        let mut holding: HarbergerHolding =
+            env.storage().persistent().get(&(HOLDING, slot.clone()))?;
 
-    /// Set minimum bid increment (only owner)
-    pub fn set_min_bid_increment(env: Env, new_increment: i128) -> Result<(), Error> {
-        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
-        owner.require_auth();
+        let now = env.ledger().timestamp();
+        let due = Self::get_harberger_tax_due(&holding, tax_bps, now);
+        if due > 0 {
+            let token_address: Address = env.storage().persistent().get(&TOKEN).unwrap();
+            Self::pay_platform(env, &token::Client::new(env, &token_address), due);
+            holding.deposit -= due;
+            holding.tax_paid += due;
 
-        env.storage()
-            .persistent()
-            .set(&MIN_BID_INCREMENT, &new_increment);
+            env.events().publish(
+                (symbol_short!("h_tax"), slot.clone()),
+                (holding.holder.clone(), due),
+            );
+        }
 
-        env.events()
-            .publish((symbol_short!("min_inc"),), new_increment);
+        if now >= Self::get_harberger_expiry(&holding, tax_bps) {
+            env.storage().persistent().remove(&(HOLDING, slot.clone()));
+            env.events()
+                .publish((symbol_short!("h_lapse"), slot.clone()), holding.holder);
+            return None;
+        }
 
-        Ok(())
+        env.storage()
+            .persistent()
+            .set(&(HOLDING, slot.clone()), &holding);
+        Some(holding)
     }
 
-    /// Set minimum starting bid (only owner)
-    pub fn set_min_starting_bid(env: Env, new_starting_bid: i128) -> Result<(), Error> {
-        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
-        owner.require_auth();
-
+    /// Record a settled auction's new display rights holder, dropping any
+    /// listing or manager of the previous holder
+    fn set_url_holder(env: &Env, auction: &Auction, holder: &Address) {
         env.storage()
             .persistent()
-            .set(&MIN_STARTING_BID, &new_starting_bid);
-
-        env.events()
-            .publish((symbol_short!("min_bid"),), new_starting_bid);
+            .set(&(DISPLAY_HOLDER, auction.auction_id), holder);
+        env.storage()
+            .persistent()
+            .remove(&(LISTING, auction.auction_id));
+        env.storage()
+            .persistent()
+            .remove(&(URL_MANAGER, auction.auction_id));
 
-        Ok(())
+        if let Some(mut window) = Self::get_queued_window(env, &auction.slot, auction.auction_id) {
+            window.holder = holder.clone();
+            Self::save_queued_window(env, &auction.slot, window);
+        }
     }
 
-    /// Update platform wallet (only owner)
-    pub fn set_platform_wallet(env: Env, new_wallet: Address) -> Result<(), Error> {
-        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
-        owner.require_auth();
+    /// Write back an auction to every place a copy of it is kept
+    fn save_auction(env: &Env, slot: &Symbol, auction: &Auction) {
+        if auction.is_ended {
+            env.storage()
+                .persistent()
+                .set(&(AUCTION, auction.auction_id), auction);
+
+            let last_auction = Self::get_last_auction(env.clone(), slot.clone());
+            if last_auction.auction_id == auction.auction_id {
+                env.storage()
+                    .persistent()
+                    .set(&(LAST_AUCTION, slot.clone()), auction);
+            }
+        }
 
-        env.storage()
-            .persistent()
-            .set(&PLATFORM_WALLET, &new_wallet);
+        let current_auction = Self::get_current_auction(env.clone(), slot.clone());
+        if current_auction.auction_id == auction.auction_id {
+            env.storage()
+                .persistent()
+                .set(&(CURRENT_AUCTION, slot.clone()), auction);
+        }
+    }
 
-        env.events()
-            .publish((symbol_short!("platform"),), new_wallet);
+    /// Metadata of a settled or still-current auction
+    fn get_auction_metadata(env: &Env, slot: &Symbol, auction_id: u64) -> QRMetadata {
+        let current_auction = Self::get_current_auction(env.clone(), slot.clone());
+        if current_auction.auction_id == auction_id {
+            return current_auction.metadata;
+        }
 
-        Ok(())
+        Self::get_auction(env.clone(), auction_id)
+            .map(|auction| auction.metadata)
+            .unwrap_or_else(|| Self::get_empty_metadata(env))
     }
 
-    /// Transfer ownership (only owner)
-    pub fn transfer_ownership(env: Env, new_owner: Address) -> Result<(), Error> {
-        let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
-        owner.require_auth();
+    /// Get the escrow record for an auction's proceeds
+    fn get_escrow(env: &Env, auction_id: u64) -> Option<ProceedsEscrow> {
+        env.storage().persistent().get(&(ESCROW, auction_id))
+    }
 
-        env.storage().persistent().set(&OWNER, &new_owner);
+    /// Vested but unreleased proceeds before the window closes
+    ///
+    /// Linear escrows vest with delivered display time, so reported
+    /// downtime is excluded. Anything not yet vested stays available for
+    /// downtime refunds at close.
+    fn calculate_claimable(env: &Env, auction: &Auction, escrow: &ProceedsEscrow) -> i128 {
+        let current_time = env.ledger().timestamp();
+        if !escrow.linear_vesting || current_time >= auction.url_expiry_time {
+            return 0;
+        }
 
-        env.events()
-            .publish((symbol_short!("owner"),), (owner, new_owner));
+        let window_start = auction.url_expiry_time - URL_DISPLAY_DURATION;
+        let downtime = Self::get_total_downtime(env.clone(), auction.auction_id);
+        let delivered = current_time
+            .saturating_sub(window_start)
+            .saturating_sub(downtime);
+        let vested = escrow.total * delivered as i128 / URL_DISPLAY_DURATION as i128;
 
-        Ok(())
+        (vested - escrow.released).max(0)
     }
 
-    // === HELPER FUNCTIONS ===
+    /// Transfer released proceeds to the platform wallet
+    fn pay_platform(env: &Env, token: &token::Client, amount: i128) {
+        let platform_wallet: Address = env.storage().persistent().get(&PLATFORM_WALLET).unwrap();
+        token.transfer(&env.current_contract_address(), &platform_wallet, &amount);
+    }
 
-    /// Get minimum bid increment
-    fn get_min_bid_increment(env: &Env) -> i128 {
+    /// Get the ids of a slot's most recent completed auctions
+    fn get_slot_history(env: &Env, slot: &Symbol) -> Vec<u64> {
         env.storage()
             .persistent()
-            .get(&MIN_BID_INCREMENT)
-            .unwrap_or(DEFAULT_MIN_BID_INCREMENT)
+            .get(&(SLOT_HISTORY, slot.clone()))
+            .unwrap_or_else(|| Vec::new(env))
     }
 
-    /// Get minimum starting bid
-    fn get_min_starting_bid(env: &Env) -> i128 {
-        env.storage()
-            .persistent()
-            .get(&MIN_STARTING_BID)
-            .unwrap_or(DEFAULT_MIN_STARTING_BID)
+    /// Fail unless the slot has been created
+    fn require_slot(env: &Env, slot: &Symbol) -> Result<(), Error> {
+        if !Self::list_slots(env.clone()).contains(slot) {
+            return Err(Error::SlotNotFound);
+        }
+        Ok(())
     }
 
     /// Create empty auction for defaults
-    fn get_empty_auction(env: &Env) -> Auction {
+    fn get_empty_auction(env: &Env, slot: &Symbol) -> Auction {
         let owner: Address = env.storage().persistent().get(&OWNER).unwrap();
         Auction {
             auction_id: 0,
+            slot: slot.clone(),
             starting_time: 0,
             ending_time: 0,
             highest_bid: 0,
-            highest_bidder: owner,
+            highest_bidder: owner.clone(),
+            bid_payer: owner,
+            bid_token: env.storage().persistent().get(&TOKEN).unwrap(),
+            bid_amount: 0,
+            bid_from_balance: false,
             preferred_url: String::from_str(env, ""),
             is_ended: true,
             url_expiry_time: 0,
+            metadata: Self::get_empty_metadata(env),
+        }
+    }
+
+    /// Create empty metadata for auctions without an ad card
+    fn get_empty_metadata(env: &Env) -> QRMetadata {
+        QRMetadata {
+            title: String::from_str(env, ""),
+            description: String::from_str(env, ""),
+            advertiser: String::from_str(env, ""),
+            image_hash: None,
+            category: String::from_str(env, ""),
         }
     }
 }
+
+#[cfg(test)]
+mod test;
@@ -1,21 +1,1356 @@
 #![cfg(test)]
+extern crate std;
 
 use super::*;
-use soroban_sdk::{vec, Env, String};
+use mock_oracle::{MockOracle, MockOracleClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::vec;
 
-#[test]
-fn test() {
+// $1 at the mock oracle's default 14 decimals
+const USD: i128 = 100_000_000_000_000;
+
+struct Setup<'a> {
+    env: Env,
+    client: BlitzClient<'a>,
+    owner: Address,
+    platform_wallet: Address,
+    token: Address,
+    slot: Symbol,
+}
+
+fn setup<'a>() -> Setup<'a> {
     let env = Env::default();
-    let contract_id = env.register(Contract, ());
-    let client = ContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+
+    let owner = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(owner.clone())
+        .address();
+
+    let client = BlitzClient::new(&env, &env.register(Blitz, ()));
+    client.initialize(&owner, &platform_wallet, &token);
+
+    let slot = symbol_short!("main");
+    client.create_slot(&slot);
+
+    Setup {
+        env,
+        client,
+        owner,
+        platform_wallet,
+        token,
+        slot,
+    }
+}
+
+/// Register a mock price oracle quoting in USD and point the contract at it
+fn register_oracle<'a>(t: &Setup) -> MockOracleClient<'a> {
+    let oracle = MockOracleClient::new(&t.env, &t.env.register(MockOracle, ()));
+    oracle.initialize(
+        &t.owner,
+        &mock_oracle::Asset::Other(symbol_short!("USD")),
+        &14,
+    );
+    t.client.set_price_oracle(&oracle.address);
+    oracle
+}
+
+/// Quote a token at the current ledger time
+fn set_price(t: &Setup, oracle: &MockOracleClient, token: &Address, price: i128) {
+    oracle.set_price(
+        &mock_oracle::Asset::Stellar(token.clone()),
+        &price,
+        &t.env.ledger().timestamp(),
+    );
+}
+
+fn mint(t: &Setup, to: &Address, amount: i128) {
+    StellarAssetClient::new(&t.env, &t.token).mint(to, &amount);
+}
+
+/// Win the slot's next auction with a plain bid and settle it
+///
+/// Returns the winner and the start of their display window.
+fn win_auction(t: &Setup, amount: i128) -> (Address, u64) {
+    let winner = Address::generate(&t.env);
+    mint(t, &winner, amount);
+    t.client.start_auction(&t.slot);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client.place_bid(&t.slot, &winner, &amount, &url);
+
+    let ending_time = t.client.get_current_auction(&t.slot).ending_time;
+    t.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = ending_time + 1);
+    t.client.end_auction(&t.slot, &t.owner);
+
+    (winner, ending_time)
+}
+
+/// Move the ledger to `timestamp`
+fn warp(t: &Setup, timestamp: u64) {
+    t.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = timestamp);
+}
+
+/// Register a second token and accept it for bids
+fn register_accepted_token(t: &Setup) -> Address {
+    let token = t
+        .env
+        .register_stellar_asset_contract_v2(t.owner.clone())
+        .address();
+    t.client.set_accepted_tokens(&vec![&t.env, token.clone()]);
+    token
+}
+
+#[test]
+fn test_fiat_floors_convert_at_oracle_price() {
+    let t = setup();
+    let oracle = register_oracle(&t);
+    set_price(&t, &oracle, &t.token, USD / 2);
+
+    // $1 starting bid, $0.10 increment at $0.50 per token
+    t.client.set_fiat_floors(&t.slot, &USD, &(USD / 10));
+    t.client.start_auction(&t.slot);
+    assert_eq!(t.client.get_minimum_bid(&t.slot), 20_000_000);
+
+    let bidder = Address::generate(&t.env);
+    mint(&t, &bidder, 1_000_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client.place_bid(&t.slot, &bidder, &20_000_000, &url);
+    assert_eq!(t.client.get_minimum_bid(&t.slot), 22_000_000);
+}
+
+#[test]
+fn test_fiat_floors_round_up() {
+    let t = setup();
+    let oracle = register_oracle(&t);
+    set_price(&t, &oracle, &t.token, 3 * USD / 10);
+
+    // 33_333_333.3 and 3_333_333.3 stroops at $0.30 per token
+    t.client.set_fiat_floors(&t.slot, &USD, &(USD / 10));
+    t.client.start_auction(&t.slot);
+    assert_eq!(t.client.get_minimum_bid(&t.slot), 33_333_334);
+
+    let bidder = Address::generate(&t.env);
+    mint(&t, &bidder, 1_000_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    assert_eq!(
+        t.client.try_place_bid(&t.slot, &bidder, &33_333_333, &url),
+        Err(Ok(Error::BidTooLow))
+    );
+    t.client.place_bid(&t.slot, &bidder, &33_333_334, &url);
+    assert_eq!(t.client.get_minimum_bid(&t.slot), 33_333_334 + 3_333_334);
+}
+
+#[test]
+fn test_get_price_rejects_stale_price() {
+    let t = setup();
+    let oracle = register_oracle(&t);
+    set_price(&t, &oracle, &t.token, USD);
+
+    let get_price = || {
+        t.env
+            .as_contract(&t.client.address, || Blitz::get_price(&t.env, &t.token))
+    };
+
+    t.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp += DEFAULT_ORACLE_MAX_AGE);
+    assert_eq!(get_price(), Ok(USD));
+
+    t.env.ledger().with_mut(|ledger| ledger.timestamp += 1);
+    assert_eq!(get_price(), Err(Error::OracleStale));
+
+    t.client.set_fiat_floors(&t.slot, &USD, &(USD / 10));
+    t.client.start_auction(&t.slot);
+    assert_eq!(
+        t.client.try_get_minimum_bid(&t.slot),
+        Err(Ok(Error::OracleStale))
+    );
+
+    // A longer max age makes the same price usable again
+    t.client.set_oracle_max_age(&(DEFAULT_ORACLE_MAX_AGE + 1));
+    assert_eq!(get_price(), Ok(USD));
+}
+
+#[test]
+fn test_get_price_unavailable_without_oracle() {
+    let t = setup();
+
+    let get_price = || {
+        t.env
+            .as_contract(&t.client.address, || Blitz::get_price(&t.env, &t.token))
+    };
+    assert_eq!(get_price(), Err(Error::PriceUnavailable));
+
+    t.client.set_fiat_floors(&t.slot, &USD, &(USD / 10));
+    t.client.start_auction(&t.slot);
+    assert_eq!(
+        t.client.try_get_minimum_bid(&t.slot),
+        Err(Ok(Error::PriceUnavailable))
+    );
+
+    // An oracle without a quote for the token is no better
+    register_oracle(&t);
+    assert_eq!(get_price(), Err(Error::PriceUnavailable));
+}
+
+#[test]
+fn test_token_value_and_minimum_token_bid_round() {
+    let t = setup();
+    let oracle = register_oracle(&t);
+    let other = register_accepted_token(&t);
+    set_price(&t, &oracle, &t.token, 3 * USD / 10);
+    set_price(&t, &oracle, &other, 7 * USD / 10);
+    t.client.start_auction(&t.slot);
+
+    // 1 XLM at $0.30 is 4_285_714.28 stroops of a $0.70 token, rounded up
+    assert_eq!(t.client.get_minimum_token_bid(&t.slot, &other), 4_285_715);
+
+    // Values round down, so only the rounded-up amount clears the minimum
+    let get_token_value = |amount: i128| {
+        t.env.as_contract(&t.client.address, || {
+            Blitz::get_token_value(&t.env, &other, amount)
+        })
+    };
+    assert_eq!(get_token_value(4_285_714), Ok(9_999_999));
+    assert_eq!(get_token_value(4_285_715), Ok(10_000_001));
+
+    let bidder = Address::generate(&t.env);
+    StellarAssetClient::new(&t.env, &other).mint(&bidder, &1_000_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    assert_eq!(
+        t.client
+            .try_place_token_bid(&t.slot, &bidder, &other, &4_285_714, &url),
+        Err(Ok(Error::BidTooLow))
+    );
+    t.client
+        .place_token_bid(&t.slot, &bidder, &other, &4_285_715, &url);
+
+    let auction = t.client.get_current_auction(&t.slot);
+    assert_eq!(auction.highest_bid, 10_000_001);
+    assert_eq!(auction.bid_amount, 4_285_715);
+    assert_eq!(auction.bid_token, other);
+}
+
+#[test]
+fn test_outbid_token_bid_refunded_in_original_token() {
+    let t = setup();
+    let oracle = register_oracle(&t);
+    let other = register_accepted_token(&t);
+    set_price(&t, &oracle, &t.token, 3 * USD / 10);
+    set_price(&t, &oracle, &other, 7 * USD / 10);
+    t.client.start_auction(&t.slot);
+
+    let token_bidder = Address::generate(&t.env);
+    let bidder = Address::generate(&t.env);
+    StellarAssetClient::new(&t.env, &other).mint(&token_bidder, &1_000_000_000);
+    mint(&t, &bidder, 1_000_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+
+    let other_token = TokenClient::new(&t.env, &other);
+    t.client
+        .place_token_bid(&t.slot, &token_bidder, &other, &4_285_715, &url);
+    assert_eq!(
+        other_token.balance(&token_bidder),
+        1_000_000_000 - 4_285_715
+    );
+    assert_eq!(other_token.balance(&t.client.address), 4_285_715);
+
+    t.client.place_bid(&t.slot, &bidder, &20_000_000, &url);
+    assert_eq!(other_token.balance(&token_bidder), 1_000_000_000);
+    assert_eq!(other_token.balance(&t.client.address), 0);
+    assert_eq!(TokenClient::new(&t.env, &t.token).balance(&token_bidder), 0);
+
+    // Stats track the refund in contract token units
+    assert_eq!(
+        t.client.get_bidder_stats(&token_bidder).total_refunded,
+        10_000_001
+    );
+}
+
+#[test]
+fn test_token_bid_settles_in_bid_token() {
+    let t = setup();
+    let oracle = register_oracle(&t);
+    let other = register_accepted_token(&t);
+    set_price(&t, &oracle, &t.token, 3 * USD / 10);
+    set_price(&t, &oracle, &other, 7 * USD / 10);
+    t.client.set_keeper_reward(&1_000_000, &0, &1_000_000);
+    t.client.start_auction(&t.slot);
+
+    let bidder = Address::generate(&t.env);
+    StellarAssetClient::new(&t.env, &other).mint(&bidder, &1_000_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client
+        .place_token_bid(&t.slot, &bidder, &other, &4_285_715, &url);
+
+    let ending_time = t.client.get_current_auction(&t.slot).ending_time;
+    t.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = ending_time + 1);
+    let keeper = Address::generate(&t.env);
+    t.client.end_auction(&t.slot, &keeper);
+
+    // The 0.1 XLM reward is paid in the bid token at the bid's rate
+    let other_token = TokenClient::new(&t.env, &other);
+    let token = TokenClient::new(&t.env, &t.token);
+    let reward = 1_000_000 * 4_285_715 / 10_000_001;
+    assert_eq!(other_token.balance(&keeper), reward);
+    assert_eq!(token.balance(&keeper), 0);
+    assert_eq!(t.client.get_escrowed_proceeds(&1), 4_285_715 - reward);
+
+    let url_expiry_time = t.client.get_auction(&1).unwrap().url_expiry_time;
+    t.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = url_expiry_time);
+    t.client.close_display_window(&1);
+    assert_eq!(other_token.balance(&t.platform_wallet), 4_285_715 - reward);
+    assert_eq!(token.balance(&t.platform_wallet), 0);
+    assert_eq!(other_token.balance(&t.client.address), 0);
+}
+
+#[test]
+fn test_close_display_window_refunds_downtime() {
+    let t = setup();
+    let reporter = Address::generate(&t.env);
+    t.client.set_device_reporter(&reporter);
+    let (winner, window_start) = win_auction(&t, 100_000_000);
+    let window_end = window_start + URL_DISPLAY_DURATION;
+
+    warp(&t, window_end - 1);
+    assert_eq!(
+        t.client.try_close_display_window(&1),
+        Err(Ok(Error::DisplayNotEnded))
+    );
+
+    warp(&t, window_end);
+    t.client
+        .report_downtime(&1, &window_start, &(window_start + 8_640));
+    t.client
+        .report_downtime(&1, &(window_start + 20_000), &(window_start + 21_000));
+    t.client.close_display_window(&1);
+
+    // Refund is total * downtime / duration, rounded down
+    let refund = 100_000_000 * 9_640 / URL_DISPLAY_DURATION as i128;
+    assert_eq!(refund, 11_157_407);
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&winner), refund);
+    assert_eq!(token.balance(&t.platform_wallet), 100_000_000 - refund);
+    assert_eq!(t.client.get_bidder_stats(&winner).total_refunded, refund);
+
+    let proceeds = t.client.get_proceeds(&1);
+    assert_eq!(proceeds.refunded, refund);
+    assert_eq!(proceeds.released, 100_000_000 - refund);
+    assert_eq!(
+        t.client.try_close_display_window(&1),
+        Err(Ok(Error::NothingEscrowed))
+    );
+}
 
-    let words = client.hello(&String::from_str(&env, "Dev"));
+#[test]
+fn test_downtime_refund_capped_at_escrowed() {
+    let t = setup();
+    let reporter = Address::generate(&t.env);
+    t.client.set_device_reporter(&reporter);
+    t.client.set_linear_vesting(&true);
+    let (winner, window_start) = win_auction(&t, 100_000_000);
+    let window_end = window_start + URL_DISPLAY_DURATION;
+
+    warp(&t, window_start + URL_DISPLAY_DURATION / 2);
+    assert_eq!(t.client.claim_proceeds(&1), 50_000_000);
+
+    // 60% downtime reported after half the proceeds were released
+    warp(&t, window_end);
+    t.client.report_downtime(
+        &1,
+        &window_start,
+        &(window_start + URL_DISPLAY_DURATION * 3 / 5),
+    );
+    t.client.close_display_window(&1);
+
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&winner), 50_000_000);
+    assert_eq!(token.balance(&t.platform_wallet), 50_000_000);
+    assert_eq!(token.balance(&t.client.address), 0);
+
+    let proceeds = t.client.get_proceeds(&1);
+    assert_eq!(
+        (proceeds.released, proceeds.refunded),
+        (50_000_000, 50_000_000)
+    );
+}
+
+#[test]
+fn test_pool_downtime_refund_credited_to_pool() {
+    let t = setup();
+    let reporter = Address::generate(&t.env);
+    t.client.set_device_reporter(&reporter);
+    t.client.start_auction(&t.slot);
+
+    let creator = Address::generate(&t.env);
+    let first = Address::generate(&t.env);
+    let second = Address::generate(&t.env);
+    mint(&t, &first, 30_000_000);
+    mint(&t, &second, 10_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    let pool_id = t.client.open_pool(&t.slot, &creator, &url);
+    t.client.contribute(&pool_id, &first, &30_000_000);
+    t.client.contribute(&pool_id, &second, &10_000_000);
+
+    let window_start = t.client.get_current_auction(&t.slot).ending_time;
+    warp(&t, window_start + 1);
+    t.client.end_auction(&t.slot, &t.owner);
+    assert_eq!(t.client.get_pool(&pool_id).unwrap().spent, 10_000_000);
+
+    warp(&t, window_start + URL_DISPLAY_DURATION);
+    t.client
+        .report_downtime(&1, &window_start, &(window_start + 8_640));
+    t.client.close_display_window(&1);
+
+    // The refund stays with the pool instead of going to the bid payer
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(t.client.get_pool(&pool_id).unwrap().refunded, 1_000_000);
+    assert_eq!(token.balance(&t.platform_wallet), 9_000_000);
+    assert_eq!(token.balance(&t.client.address), 31_000_000);
+
+    // Contributors withdraw pro rata from unspent funds plus the refund
+    assert_eq!(t.client.withdraw_from_pool(&pool_id, &first), 23_250_000);
+    assert_eq!(t.client.withdraw_from_pool(&pool_id, &second), 7_750_000);
+    assert_eq!(token.balance(&t.client.address), 0);
+}
+
+#[test]
+fn test_pool_contribution_raises_bid() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let creator = Address::generate(&t.env);
+    let contributor = Address::generate(&t.env);
+    let bidder = Address::generate(&t.env);
+    mint(&t, &contributor, 25_000_000);
+    mint(&t, &bidder, 20_000_000);
+    let pool_url = String::from_str(&t.env, "https://pool.example.com");
+    let url = String::from_str(&t.env, "https://example.com");
+    let pool_id = t.client.open_pool(&t.slot, &creator, &pool_url);
+
+    // Below the minimum bid the pool just waits
+    t.client.contribute(&pool_id, &contributor, &5_000_000);
+    assert_eq!(t.client.get_current_auction(&t.slot).highest_bid, 0);
+
+    t.client.contribute(&pool_id, &contributor, &10_000_000);
+    let auction = t.client.get_current_auction(&t.slot);
+    assert_eq!(auction.highest_bid, 10_000_000);
+    assert_eq!(auction.highest_bidder, creator);
+    assert_eq!(auction.preferred_url, pool_url);
+
+    // The pool defends up to its balance, then is outbid
+    t.client.place_bid(&t.slot, &bidder, &12_000_000, &url);
     assert_eq!(
-        words,
-        vec![
-            &env,
-            String::from_str(&env, "Hello"),
-            String::from_str(&env, "Dev"),
-        ]
+        t.client.get_current_auction(&t.slot).highest_bid,
+        13_000_000
     );
+    t.client.place_bid(&t.slot, &bidder, &20_000_000, &url);
+    assert_eq!(t.client.get_current_auction(&t.slot).highest_bidder, bidder);
+
+    // Topping up bids again and refunds the outbid bidder
+    t.client.contribute(&pool_id, &contributor, &10_000_000);
+    let auction = t.client.get_current_auction(&t.slot);
+    assert_eq!(auction.highest_bid, 21_000_000);
+    assert_eq!(auction.highest_bidder, creator);
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&bidder), 20_000_000);
+
+    // The ladder records the pool's creator, never the contract
+    let bids = t.client.get_bids(&1, &0, &10);
+    let bidders: std::vec::Vec<Address> = bids.iter().map(|bid| bid.bidder).collect();
+    assert_eq!(bidders, [creator.clone(), creator.clone(), bidder, creator]);
+}
+
+#[test]
+fn test_competing_pools() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let creator_a = Address::generate(&t.env);
+    let creator_b = Address::generate(&t.env);
+    let backer_a = Address::generate(&t.env);
+    let first_b = Address::generate(&t.env);
+    let second_b = Address::generate(&t.env);
+    mint(&t, &backer_a, 30_000_000);
+    mint(&t, &first_b, 20_000_000);
+    mint(&t, &second_b, 20_000_000);
+    let pool_a = t.client.open_pool(
+        &t.slot,
+        &creator_a,
+        &String::from_str(&t.env, "https://a.example.com"),
+    );
+    let pool_b = t.client.open_pool(
+        &t.slot,
+        &creator_b,
+        &String::from_str(&t.env, "https://b.example.com"),
+    );
+
+    // The leading pool counters a smaller rival
+    t.client.contribute(&pool_a, &backer_a, &30_000_000);
+    t.client.contribute(&pool_b, &first_b, &20_000_000);
+    let auction = t.client.get_current_auction(&t.slot);
+    assert_eq!(auction.highest_bid, 21_000_000);
+    assert_eq!(auction.highest_bidder, creator_a);
+
+    // A larger balance takes the lead one increment over the rival's ceiling
+    t.client.contribute(&pool_b, &second_b, &20_000_000);
+    let auction = t.client.get_current_auction(&t.slot);
+    assert_eq!(auction.highest_bid, 31_000_000);
+    assert_eq!(auction.highest_bidder, creator_b);
+
+    let bids = t.client.get_bids(&1, &0, &10);
+    assert!(bids.iter().all(|bid| bid.bidder != t.client.address));
+    assert_eq!(bids.get(bids.len() - 1).unwrap().bidder, creator_b);
+
+    let ending_time = auction.ending_time;
+    warp(&t, ending_time + 1);
+    t.client.end_auction(&t.slot, &t.owner);
+
+    // The losing pool returns everything, the winner its unspent remainder
+    assert_eq!(t.client.withdraw_from_pool(&pool_a, &backer_a), 30_000_000);
+    assert_eq!(t.client.withdraw_from_pool(&pool_b, &first_b), 4_500_000);
+    assert_eq!(t.client.withdraw_from_pool(&pool_b, &second_b), 4_500_000);
+    assert_eq!(
+        t.client.try_withdraw_from_pool(&pool_a, &backer_a),
+        Err(Ok(Error::NothingToWithdraw))
+    );
+
+    // The win is attributed to the winning pool's contributors
+    let stats = t.client.get_bidder_stats(&first_b);
+    assert_eq!(stats.auctions_won, 1);
+    assert_eq!(stats.total_paid, 15_500_000);
+    assert_eq!(t.client.get_bidder_stats(&second_b).total_paid, 15_500_000);
+    assert_eq!(t.client.get_bidder_stats(&backer_a).auctions_won, 0);
+    assert_eq!(t.client.get_bidder_stats(&creator_b).auctions_won, 0);
+}
+
+#[test]
+fn test_pool_url_and_display_rights_fixed() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let creator = Address::generate(&t.env);
+    let contributor = Address::generate(&t.env);
+    let buyer = Address::generate(&t.env);
+    mint(&t, &contributor, 10_000_000);
+    let pool_url = String::from_str(&t.env, "https://pool.example.com");
+    let url = String::from_str(&t.env, "https://example.com");
+    let pool_id = t.client.open_pool(&t.slot, &creator, &pool_url);
+    t.client.contribute(&pool_id, &contributor, &10_000_000);
+
+    // Neither while the pool leads...
+    assert_eq!(
+        t.client.try_update_url(&t.slot, &1, &creator, &url),
+        Err(Ok(Error::PoolAuction))
+    );
+    assert_eq!(
+        t.client.try_appoint_manager(&t.slot, &1, &buyer),
+        Err(Ok(Error::PoolAuction))
+    );
+
+    let ending_time = t.client.get_current_auction(&t.slot).ending_time;
+    warp(&t, ending_time + 1);
+    t.client.end_auction(&t.slot, &t.owner);
+
+    // ...nor once it has won
+    assert_eq!(
+        t.client.try_update_url(&t.slot, &1, &creator, &url),
+        Err(Ok(Error::PoolAuction))
+    );
+    assert_eq!(
+        t.client.try_transfer_display(&1, &buyer),
+        Err(Ok(Error::PoolAuction))
+    );
+    assert_eq!(
+        t.client.try_list_display(&1, &50_000_000),
+        Err(Ok(Error::PoolAuction))
+    );
+    assert_eq!(t.client.get_auction(&1).unwrap().preferred_url, pool_url);
+}
+
+#[test]
+fn test_claim_proceeds_with_late_downtime() {
+    let t = setup();
+    let reporter = Address::generate(&t.env);
+    t.client.set_device_reporter(&reporter);
+    t.client.set_linear_vesting(&true);
+    let (winner, window_start) = win_auction(&t, 100_000_000);
+    let window_end = window_start + URL_DISPLAY_DURATION;
+
+    warp(&t, window_start + URL_DISPLAY_DURATION / 2);
+    assert_eq!(t.client.claim_proceeds(&1), 50_000_000);
+
+    // Downtime in the already claimed half delays further vesting
+    warp(&t, window_start + URL_DISPLAY_DURATION * 3 / 4);
+    t.client.report_downtime(
+        &1,
+        &window_start,
+        &(window_start + URL_DISPLAY_DURATION / 4),
+    );
+    assert_eq!(t.client.get_proceeds(&1).claimable, 0);
+    assert_eq!(
+        t.client.try_claim_proceeds(&1),
+        Err(Ok(Error::NothingEscrowed))
+    );
+
+    // After the window, claiming closes it and refunds the downtime
+    warp(&t, window_end);
+    assert_eq!(t.client.claim_proceeds(&1), 25_000_000);
+
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&winner), 25_000_000);
+    assert_eq!(token.balance(&t.platform_wallet), 75_000_000);
+    assert_eq!(
+        t.client.try_claim_proceeds(&1),
+        Err(Ok(Error::NothingEscrowed))
+    );
+}
+
+#[test]
+fn test_gift_bid_refunds_payer() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let payer = Address::generate(&t.env);
+    let beneficiary = Address::generate(&t.env);
+    let rival = Address::generate(&t.env);
+    mint(&t, &payer, 100_000_000);
+    mint(&t, &rival, 100_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+
+    t.client
+        .place_gift_bid(&t.slot, &payer, &beneficiary, &20_000_000, &url);
+    let auction = t.client.get_current_auction(&t.slot);
+    assert_eq!(auction.highest_bidder, beneficiary);
+    assert_eq!(auction.bid_payer, payer);
+
+    t.client.place_bid(&t.slot, &rival, &30_000_000, &url);
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&payer), 100_000_000);
+    assert_eq!(token.balance(&beneficiary), 0);
+}
+
+#[test]
+fn test_gift_bid_cannot_target_leader() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let victim = Address::generate(&t.env);
+    let attacker = Address::generate(&t.env);
+    mint(&t, &victim, 500_000_000);
+    mint(&t, &attacker, 500_000_000);
+    let victim_url = String::from_str(&t.env, "https://victim.example");
+    let attacker_url = String::from_str(&t.env, "https://attacker.example");
+    t.client
+        .place_proxy_bid(&t.slot, &victim, &500_000_000, &victim_url);
+
+    // Naming the proxy holder must not cancel their proxy or replace their URL
+    assert_eq!(
+        t.client
+            .try_place_gift_bid(&t.slot, &attacker, &victim, &11_000_000, &attacker_url),
+        Err(Ok(Error::BeneficiaryLeading))
+    );
+
+    t.client
+        .place_bid(&t.slot, &attacker, &11_000_000, &attacker_url);
+    let auction = t.client.get_current_auction(&t.slot);
+    assert_eq!(auction.highest_bidder, victim);
+    assert_eq!(auction.preferred_url, victim_url);
+    assert_eq!(auction.highest_bid, 12_000_000);
+}
+
+#[test]
+fn test_gift_bid_by_proxy_payer_replaces_proxy() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let holder = Address::generate(&t.env);
+    let friend = Address::generate(&t.env);
+    mint(&t, &holder, 500_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client
+        .place_proxy_bid(&t.slot, &holder, &100_000_000, &url);
+
+    // The proxy's own payer may hand the lead to someone else
+    t.client
+        .place_gift_bid(&t.slot, &holder, &friend, &20_000_000, &url);
+    let auction = t.client.get_current_auction(&t.slot);
+    assert_eq!(auction.highest_bidder, friend);
+    assert_eq!(auction.highest_bid, 20_000_000);
+    assert_eq!(
+        TokenClient::new(&t.env, &t.token).balance(&holder),
+        480_000_000
+    );
+}
+
+#[test]
+fn test_bid_from_allowance() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let agency = Address::generate(&t.env);
+    let payer = Address::generate(&t.env);
+    let client = Address::generate(&t.env);
+    let rival = Address::generate(&t.env);
+    mint(&t, &payer, 100_000_000);
+    mint(&t, &rival, 100_000_000);
+    let token = TokenClient::new(&t.env, &t.token);
+    token.approve(&payer, &agency, &50_000_000, &1_000);
+
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client
+        .place_bid_from(&t.slot, &agency, &payer, &client, &20_000_000, &url);
+
+    // Pulled from the payer's allowance, not the agency's balance
+    assert_eq!(token.balance(&payer), 80_000_000);
+    assert_eq!(token.allowance(&payer, &agency), 30_000_000);
+    let auction = t.client.get_current_auction(&t.slot);
+    assert_eq!(auction.highest_bidder, client);
+    assert_eq!(auction.bid_payer, payer);
+
+    // The beneficiary controls the URL; neither payer nor agency does
+    let new_url = String::from_str(&t.env, "https://client.example");
+    assert_eq!(
+        t.client.try_update_url(&t.slot, &1, &agency, &new_url),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.client.try_update_url(&t.slot, &1, &payer, &new_url),
+        Err(Ok(Error::Unauthorized))
+    );
+    t.client.update_url(&t.slot, &1, &client, &new_url);
+    assert_eq!(t.client.get_current_auction_url(&t.slot), new_url);
+
+    // Outbid refunds go to the payer
+    t.client.place_bid(&t.slot, &rival, &30_000_000, &url);
+    assert_eq!(token.balance(&payer), 100_000_000);
+    assert_eq!(token.balance(&agency), 0);
+    assert_eq!(token.balance(&client), 0);
+}
+
+#[test]
+fn test_bid_from_allowance_cannot_target_leader() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let victim = Address::generate(&t.env);
+    let agency = Address::generate(&t.env);
+    let payer = Address::generate(&t.env);
+    mint(&t, &victim, 500_000_000);
+    mint(&t, &payer, 100_000_000);
+    TokenClient::new(&t.env, &t.token).approve(&payer, &agency, &100_000_000, &1_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client
+        .place_proxy_bid(&t.slot, &victim, &500_000_000, &url);
+
+    assert_eq!(
+        t.client
+            .try_place_bid_from(&t.slot, &agency, &payer, &victim, &11_000_000, &url),
+        Err(Ok(Error::BeneficiaryLeading))
+    );
+    assert_eq!(TokenClient::new(&t.env, &t.token).balance(&victim), 0);
+}
+
+#[test]
+fn test_proxy_defends_lead() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let holder = Address::generate(&t.env);
+    let challenger = Address::generate(&t.env);
+    mint(&t, &holder, 1_000_000_000);
+    mint(&t, &challenger, 1_000_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+
+    t.client
+        .place_proxy_bid(&t.slot, &holder, &100_000_000, &url);
+    assert_eq!(
+        t.client.get_current_auction(&t.slot).highest_bid,
+        10_000_000
+    );
+
+    // The proxy pays one increment over the challenger and takes nothing
+    t.client.place_bid(&t.slot, &challenger, &20_000_000, &url);
+    let auction = t.client.get_current_auction(&t.slot);
+    assert_eq!(auction.highest_bidder, holder);
+    assert_eq!(auction.highest_bid, 21_000_000);
+
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&challenger), 1_000_000_000);
+    assert_eq!(token.balance(&holder), 900_000_000);
+
+    let bids = t.client.get_bids(&1, &0, &10);
+    assert_eq!(bids.len(), 2);
+    assert_eq!(bids.get(1).unwrap().bidder, holder);
+    assert_eq!(bids.get(1).unwrap().amount, 21_000_000);
+}
+
+#[test]
+fn test_proxy_counter_requires_funded_challenger() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let holder = Address::generate(&t.env);
+    let challenger = Address::generate(&t.env);
+    mint(&t, &holder, 1_000_000_000);
+    mint(&t, &challenger, 50_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client
+        .place_proxy_bid(&t.slot, &holder, &500_000_000, &url);
+
+    // An unfunded bid can't probe the ceiling or push the price up to it
+    assert_eq!(
+        t.client
+            .try_place_bid(&t.slot, &challenger, &500_000_000, &url),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        t.client
+            .try_place_proxy_bid(&t.slot, &challenger, &500_000_000, &url),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        t.client.get_current_auction(&t.slot).highest_bid,
+        10_000_000
+    );
+
+    // Funds held as a deposit balance count too
+    t.client.deposit(&challenger, &50_000_000);
+    t.client.place_bid(&t.slot, &challenger, &50_000_000, &url);
+    assert_eq!(
+        t.client.get_current_auction(&t.slot).highest_bid,
+        51_000_000
+    );
+    assert_eq!(t.client.get_balance(&challenger), 50_000_000);
+}
+
+#[test]
+fn test_raise_own_proxy_ceiling() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let holder = Address::generate(&t.env);
+    mint(&t, &holder, 1_000_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client
+        .place_proxy_bid(&t.slot, &holder, &50_000_000, &url);
+
+    assert_eq!(
+        t.client
+            .try_place_proxy_bid(&t.slot, &holder, &50_000_000, &url),
+        Err(Ok(Error::BidTooLow))
+    );
+
+    // Only the difference is escrowed and the visible price stays put
+    t.client
+        .place_proxy_bid(&t.slot, &holder, &80_000_000, &url);
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&holder), 920_000_000);
+    assert_eq!(
+        t.client.get_current_auction(&t.slot).highest_bid,
+        10_000_000
+    );
+}
+
+#[test]
+fn test_outbid_proxy_releases_escrow() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let holder = Address::generate(&t.env);
+    let challenger = Address::generate(&t.env);
+    mint(&t, &holder, 1_000_000_000);
+    mint(&t, &challenger, 1_000_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client
+        .place_proxy_bid(&t.slot, &holder, &100_000_000, &url);
+
+    t.client.place_bid(&t.slot, &challenger, &150_000_000, &url);
+    let auction = t.client.get_current_auction(&t.slot);
+    assert_eq!(auction.highest_bidder, challenger);
+    assert_eq!(auction.highest_bid, 150_000_000);
+
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&holder), 1_000_000_000);
+    assert_eq!(token.balance(&t.client.address), 150_000_000);
+}
+
+#[test]
+fn test_proxy_refunds_unused_escrow_at_settlement() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+
+    let holder = Address::generate(&t.env);
+    let challenger = Address::generate(&t.env);
+    mint(&t, &holder, 1_000_000_000);
+    mint(&t, &challenger, 1_000_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client
+        .place_proxy_bid(&t.slot, &holder, &100_000_000, &url);
+    t.client.place_bid(&t.slot, &challenger, &20_000_000, &url);
+
+    let ending_time = t.client.get_current_auction(&t.slot).ending_time;
+    warp(&t, ending_time + 1);
+    t.client.end_auction(&t.slot, &t.owner);
+
+    // Only the final visible price is kept
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&holder), 1_000_000_000 - 21_000_000);
+    assert_eq!(t.client.get_escrowed_proceeds(&1), 21_000_000);
+    assert_eq!(token.balance(&t.client.address), 21_000_000);
+}
+
+/// Put the slot in Harberger mode at 10% a day and claim it at 100 tokens
+/// with a 30 token deposit, good for three days
+fn claim_harberger(t: &Setup) -> Address {
+    t.client.set_harberger_mode(&t.slot, &1_000);
+
+    let holder = Address::generate(&t.env);
+    mint(t, &holder, 30_000_000);
+    t.client.claim_slot(
+        &t.slot,
+        &holder,
+        &100_000_000,
+        &String::from_str(&t.env, "https://holder.example.com"),
+        &30_000_000,
+    );
+    holder
+}
+
+#[test]
+fn test_harberger_tax_accrues_per_second() {
+    let t = setup();
+    claim_harberger(&t);
+    let token = TokenClient::new(&t.env, &t.token);
+
+    // Half a day at 10 tokens a day
+    warp(&t, 1000 + TAX_PERIOD / 2);
+    let holding = t.client.get_harberger_holding(&t.slot).unwrap();
+    assert_eq!(holding.deposit, 25_000_000);
+    assert_eq!(holding.tax_paid, 5_000_000);
+    t.client.collect_tax(&t.slot);
+    assert_eq!(token.balance(&t.platform_wallet), 5_000_000);
+
+    // A further hour is charged pro rata, rounding down
+    warp(&t, 1000 + TAX_PERIOD / 2 + 3_600);
+    t.client.collect_tax(&t.slot);
+    assert_eq!(token.balance(&t.platform_wallet), 5_416_666);
+    assert_eq!(
+        t.client.get_harberger_holding(&t.slot).unwrap().deposit,
+        30_000_000 - 5_416_666
+    );
+}
+
+#[test]
+fn test_harberger_takeover_pays_price_and_refunds_deposit() {
+    let t = setup();
+    let holder = claim_harberger(&t);
+    let buyer = Address::generate(&t.env);
+    mint(&t, &buyer, 110_000_000);
+
+    warp(&t, 1000 + TAX_PERIOD / 2);
+    let url = String::from_str(&t.env, "https://buyer.example.com");
+    t.client
+        .claim_slot(&t.slot, &buyer, &200_000_000, &url, &10_000_000);
+
+    // The holder gets their price plus what is left of their deposit
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&holder), 100_000_000 + 25_000_000);
+    assert_eq!(token.balance(&buyer), 0);
+    assert_eq!(token.balance(&t.platform_wallet), 5_000_000);
+    assert_eq!(token.balance(&t.client.address), 10_000_000);
+
+    let holding = t.client.get_harberger_holding(&t.slot).unwrap();
+    assert_eq!(holding.holder, buyer);
+    assert_eq!(holding.price, 200_000_000);
+    assert_eq!(holding.tax_paid, 0);
+    assert_eq!(t.client.get_qr_url(&t.slot), url);
+}
+
+#[test]
+fn test_harberger_lapses_to_house_ads() {
+    let t = setup();
+    let house_url = String::from_str(&t.env, "https://house.example.com");
+    t.client.set_house_ads(
+        &t.slot,
+        &vec![
+            &t.env,
+            HouseAd {
+                id: 1,
+                url: house_url.clone(),
+                weight: 1,
+            },
+        ],
+    );
+    claim_harberger(&t);
+    assert_eq!(
+        t.client.get_qr_url(&t.slot),
+        String::from_str(&t.env, "https://holder.example.com")
+    );
+    assert_eq!(
+        t.client.get_qr_url_expiry_time(&t.slot),
+        1000 + 3 * TAX_PERIOD
+    );
+
+    warp(&t, 1000 + 3 * TAX_PERIOD);
+    assert!(t.client.get_harberger_holding(&t.slot).is_none());
+    assert!(!t.client.has_active_qr_url(&t.slot));
+    assert_eq!(t.client.get_qr_url(&t.slot), house_url);
+
+    // Collecting takes the whole deposit and vacates the slot
+    t.client.collect_tax(&t.slot);
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&t.platform_wallet), 30_000_000);
+    assert_eq!(
+        t.client.try_top_up_tax(&t.slot, &1_000_000),
+        Err(Ok(Error::SlotVacant))
+    );
+}
+
+#[test]
+fn test_tax_runway_near_expiry() {
+    let t = setup();
+    claim_harberger(&t);
+    assert_eq!(t.client.get_tax_runway(&t.slot), 3 * TAX_PERIOD);
+
+    warp(&t, 1000 + 3 * TAX_PERIOD - 1);
+    assert_eq!(t.client.get_tax_runway(&t.slot), 1);
+    assert!(t.client.get_harberger_holding(&t.slot).is_some());
+
+    warp(&t, 1000 + 3 * TAX_PERIOD);
+    assert_eq!(t.client.get_tax_runway(&t.slot), 0);
+}
+
+#[test]
+fn test_disable_harberger_mode_refunds_deposit() {
+    let t = setup();
+    let holder = claim_harberger(&t);
+
+    warp(&t, 1000 + TAX_PERIOD / 2);
+    t.client.disable_harberger_mode(&t.slot);
+
+    // Tax up to now goes to the platform, the rest back to the holder
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&holder), 25_000_000);
+    assert_eq!(token.balance(&t.platform_wallet), 5_000_000);
+    assert_eq!(token.balance(&t.client.address), 0);
+    assert!(t.client.get_harberger_holding(&t.slot).is_none());
+
+    // The slot is back to daily auctions
+    let buyer = Address::generate(&t.env);
+    assert_eq!(
+        t.client.try_claim_slot(
+            &t.slot,
+            &buyer,
+            &100_000_000,
+            &String::from_str(&t.env, "https://buyer.example.com"),
+            &1_000_000,
+        ),
+        Err(Ok(Error::NotHarbergerSlot))
+    );
+    t.client.start_auction(&t.slot);
+}
+
+#[test]
+fn test_buy_listing_splits_royalty() {
+    let t = setup();
+    t.client.set_listing_royalty(&500);
+    let (seller, _) = win_auction(&t, 10_000_000);
+    let buyer = Address::generate(&t.env);
+    mint(&t, &buyer, 20_000_000);
+
+    t.client.list_display(&1, &20_000_000);
+    t.client.buy_listing(&1, &buyer);
+
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(token.balance(&seller), 19_000_000);
+    assert_eq!(token.balance(&t.platform_wallet), 1_000_000);
+    assert_eq!(token.balance(&buyer), 0);
+    assert_eq!(t.client.get_display_holder(&1), buyer);
+    assert!(t.client.get_listing(&1).is_none());
+
+    // The buyer now controls the URL, the seller no longer does
+    let url = String::from_str(&t.env, "https://buyer.example.com");
+    t.client.update_url(&t.slot, &1, &buyer, &url);
+    assert_eq!(
+        t.client.try_update_url(&t.slot, &1, &seller, &url),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_listing_lapses_at_window_end() {
+    let t = setup();
+    let (_, window_start) = win_auction(&t, 10_000_000);
+    let buyer = Address::generate(&t.env);
+    mint(&t, &buyer, 20_000_000);
+
+    t.client.list_display(&1, &20_000_000);
+    warp(&t, window_start + URL_DISPLAY_DURATION - 1);
+    assert!(t.client.get_listing(&1).is_some());
+
+    warp(&t, window_start + URL_DISPLAY_DURATION);
+    assert!(t.client.get_listing(&1).is_none());
+    assert_eq!(
+        t.client.try_buy_listing(&1, &buyer),
+        Err(Ok(Error::ListingNotFound))
+    );
+}
+
+#[test]
+fn test_listing_lapses_on_transfer() {
+    let t = setup();
+    win_auction(&t, 10_000_000);
+    let recipient = Address::generate(&t.env);
+    let buyer = Address::generate(&t.env);
+    mint(&t, &buyer, 20_000_000);
+
+    t.client.list_display(&1, &20_000_000);
+    t.client.transfer_display(&1, &recipient);
+
+    assert!(t.client.get_listing(&1).is_none());
+    assert_eq!(
+        t.client.try_buy_listing(&1, &buyer),
+        Err(Ok(Error::ListingNotFound))
+    );
+    assert_eq!(t.client.get_display_holder(&1), recipient);
+}
+
+#[test]
+fn test_cancel_listing() {
+    let t = setup();
+    win_auction(&t, 10_000_000);
+    let buyer = Address::generate(&t.env);
+    mint(&t, &buyer, 20_000_000);
+
+    t.client.list_display(&1, &20_000_000);
+    t.client.cancel_listing(&1);
+
+    assert!(t.client.get_listing(&1).is_none());
+    assert_eq!(
+        t.client.try_buy_listing(&1, &buyer),
+        Err(Ok(Error::ListingNotFound))
+    );
+    assert_eq!(
+        t.client.try_cancel_listing(&1),
+        Err(Ok(Error::ListingNotFound))
+    );
+}
+
+#[test]
+fn test_bid_reserved_from_balance() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+    let bidder = Address::generate(&t.env);
+    mint(&t, &bidder, 30_000_000);
+    t.client.deposit(&bidder, &30_000_000);
+
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client.place_bid(&t.slot, &bidder, &10_000_000, &url);
+
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(t.client.get_balance(&bidder), 20_000_000);
+    assert_eq!(token.balance(&t.client.address), 30_000_000);
+    assert!(t.client.get_current_auction(&t.slot).bid_from_balance);
+}
+
+#[test]
+fn test_bid_transferred_when_balance_short() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+    let bidder = Address::generate(&t.env);
+    mint(&t, &bidder, 15_000_000);
+    t.client.deposit(&bidder, &5_000_000);
+
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client.place_bid(&t.slot, &bidder, &10_000_000, &url);
+
+    // The balance is left alone and the whole bid is transferred in
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(t.client.get_balance(&bidder), 5_000_000);
+    assert_eq!(token.balance(&bidder), 0);
+    assert!(!t.client.get_current_auction(&t.slot).bid_from_balance);
+}
+
+#[test]
+fn test_outbid_balance_bid_refunded_to_balance() {
+    let t = setup();
+    t.client.start_auction(&t.slot);
+    let bidder = Address::generate(&t.env);
+    let rival = Address::generate(&t.env);
+    mint(&t, &bidder, 30_000_000);
+    mint(&t, &rival, 11_000_000);
+    t.client.deposit(&bidder, &30_000_000);
+
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client.place_bid(&t.slot, &bidder, &10_000_000, &url);
+    t.client.place_bid(&t.slot, &rival, &11_000_000, &url);
+
+    // Credited back to the balance rather than transferred out
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(t.client.get_balance(&bidder), 30_000_000);
+    assert_eq!(token.balance(&bidder), 0);
+    assert_eq!(
+        t.client.get_bidder_stats(&bidder).total_refunded,
+        10_000_000
+    );
+}
+
+#[test]
+fn test_withdraw_limits() {
+    let t = setup();
+    let depositor = Address::generate(&t.env);
+    mint(&t, &depositor, 20_000_000);
+    t.client.deposit(&depositor, &20_000_000);
+
+    assert_eq!(
+        t.client.try_withdraw(&depositor, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        t.client.try_withdraw(&depositor, &20_000_001),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    t.client.withdraw(&depositor, &4_000_000);
+    let token = TokenClient::new(&t.env, &t.token);
+    assert_eq!(t.client.get_balance(&depositor), 16_000_000);
+    assert_eq!(token.balance(&depositor), 4_000_000);
+
+    // Funds reserved for a bid cannot be withdrawn
+    t.client.start_auction(&t.slot);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client.place_bid(&t.slot, &depositor, &10_000_000, &url);
+    assert_eq!(
+        t.client.try_withdraw(&depositor, &6_000_001),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
+/// Minimum bid the contract requires over `highest_bid` on the main slot
+fn minimum_over(t: &Setup, highest_bid: i128) -> i128 {
+    t.env.as_contract(&t.client.address, || {
+        Blitz::calculate_minimum_bid(&t.env, &t.slot, highest_bid).unwrap()
+    })
+}
+
+#[test]
+fn test_increment_tier_selection() {
+    let t = setup();
+    t.client.set_increment_tiers(
+        &t.slot,
+        &vec![
+            &t.env,
+            IncrementTier {
+                min_bid: 50_000_000,
+                increment: 5_000_000,
+            },
+            IncrementTier {
+                min_bid: 100_000_000,
+                increment: 20_000_000,
+            },
+        ],
+    );
+
+    // No bid yet asks the starting bid, below the first tier the flat one
+    assert_eq!(minimum_over(&t, 0), 10_000_000);
+    assert_eq!(minimum_over(&t, 20_000_000), 21_000_000);
+
+    // Each tier applies from its own minimum up to the next
+    assert_eq!(minimum_over(&t, 50_000_000), 55_000_000);
+    assert_eq!(minimum_over(&t, 99_999_999), 104_999_999);
+    assert_eq!(minimum_over(&t, 100_000_000), 120_000_000);
+    assert_eq!(minimum_over(&t, 500_000_000), 520_000_000);
+
+    t.client.start_auction(&t.slot);
+    let bidder = Address::generate(&t.env);
+    mint(&t, &bidder, 50_000_000);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.client.place_bid(&t.slot, &bidder, &50_000_000, &url);
+    assert_eq!(t.client.get_minimum_bid(&t.slot), 55_000_000);
+}
+
+#[test]
+fn test_increment_bps_against_floor() {
+    let t = setup();
+    t.client.set_min_bid_increment_bps(&t.slot, &1_000);
+
+    // The larger of 10% and the flat increment
+    assert_eq!(minimum_over(&t, 8_000_000), 9_000_000);
+    assert_eq!(minimum_over(&t, 20_000_000), 22_000_000);
+
+    // The same holds against a tier's increment
+    t.client.set_increment_tiers(
+        &t.slot,
+        &vec![
+            &t.env,
+            IncrementTier {
+                min_bid: 100_000_000,
+                increment: 20_000_000,
+            },
+        ],
+    );
+    assert_eq!(minimum_over(&t, 150_000_000), 170_000_000);
+    assert_eq!(minimum_over(&t, 300_000_000), 330_000_000);
+}
+
+#[test]
+fn test_get_bids_pages_by_absolute_index() {
+    let t = setup();
+    let bidder = Address::generate(&t.env);
+    let url = String::from_str(&t.env, "https://example.com");
+    t.env.as_contract(&t.client.address, || {
+        for index in 0..(MAX_BIDS_PER_AUCTION + 5) {
+            Blitz::record_bid(&t.env, 1, &bidder, index as i128, &url);
+        }
+    });
+
+    // The five oldest bids were dropped
+    assert_eq!(t.client.get_first_bid_index(&1), 5);
+    assert_eq!(t.client.get_bid_count(&1), MAX_BIDS_PER_AUCTION);
+
+    let amounts = |cursor: u32, limit: u32| -> std::vec::Vec<i128> {
+        t.client
+            .get_bids(&1, &cursor, &limit)
+            .iter()
+            .map(|bid| bid.amount)
+            .collect()
+    };
+    assert_eq!(amounts(0, 3), [5, 6, 7]);
+    assert_eq!(amounts(100, 2), [100, 101]);
+    assert_eq!(amounts(204, 10), [204]);
+    assert!(amounts(205, 10).is_empty());
+    assert_eq!(amounts(5, 100).len() as u32, MAX_BIDS_PAGE);
+
+    // A later bid drops another but leaves existing cursors in place
+    t.env.as_contract(&t.client.address, || {
+        Blitz::record_bid(&t.env, 1, &bidder, 205, &url);
+    });
+    assert_eq!(amounts(100, 2), [100, 101]);
+    assert_eq!(amounts(0, 1), [6]);
+
+    // Paging from the first index walks every stored bid once
+    let mut cursor = t.client.get_first_bid_index(&1);
+    let mut seen = 0;
+    loop {
+        let page = t.client.get_bids(&1, &cursor, &MAX_BIDS_PAGE);
+        if page.is_empty() {
+            break;
+        }
+        seen += page.len();
+        cursor += page.len();
+    }
+    assert_eq!(seen, MAX_BIDS_PER_AUCTION);
+    assert_eq!(cursor, MAX_BIDS_PER_AUCTION + 6);
 }
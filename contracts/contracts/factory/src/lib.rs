@@ -0,0 +1,302 @@
+#![no_std]
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, xdr::ToXdr,
+    Address, BytesN, Env, Symbol, Vec,
+};
+
+// Constants
+const MAX_TENANTS: u32 = 200;
+const MAX_UPGRADE_BATCH: u32 = 20; // tenants upgraded per `upgrade_all` call
+
+// Storage keys
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const WASM_HASH: Symbol = symbol_short!("WASM");
+const TENANTS: Symbol = symbol_short!("TENANTS");
+const TENANT: Symbol = symbol_short!("TENANT"); // (TENANT, tenant) -> Tenant
+
+// Error codes
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    TenantExists = 3,
+    TenantNotFound = 4,
+    TooManyTenants = 5,
+    InvalidLimit = 6,
+}
+
+// Data structures
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tenant {
+    pub tenant: Symbol,
+    pub contract_address: Address,
+    pub owner: Address,
+    pub platform_wallet: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Outcome of one `upgrade_all` batch
+///
+/// `next_cursor` resumes the rollout; it equals the tenant count once every
+/// tenant has been visited.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeBatch {
+    pub upgraded: u32,
+    pub failed: Vec<Symbol>,
+    pub next_cursor: u32,
+}
+
+/// Subset of the Blitz interface the factory needs
+#[contractclient(name = "BlitzClient")]
+pub trait BlitzInterface {
+    fn initialize(env: Env, owner: Address, platform_wallet: Address, token_address: Address);
+    fn set_upgrader(env: Env, upgrader: Address);
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>);
+}
+
+#[contract]
+pub struct BlitzFactory;
+
+#[contractimpl]
+impl BlitzFactory {
+    /// Initialize the factory with the Blitz wasm hash to deploy
+    pub fn initialize(env: Env, admin: Address, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        admin.require_auth();
+
+        if env.storage().persistent().has(&ADMIN) {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        env.storage().persistent().set(&ADMIN, &admin);
+        env.storage().persistent().set(&WASM_HASH, &wasm_hash);
+        env.storage()
+            .persistent()
+            .set(&TENANTS, &Vec::<Symbol>::new(&env));
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, wasm_hash));
+
+        Ok(())
+    }
+
+    /// Deploy and initialize a Blitz instance for a tenant (only admin)
+    ///
+    /// The contract address is derived from the tenant id, so it can be
+    /// predicted with `get_deployed_address` before deployment. The tenant
+    /// owner must also authorize this call, since it initializes the
+    /// instance in their name and hands upgrade rights to the factory.
+    pub fn deploy_tenant(
+        env: Env,
+        tenant: Symbol,
+        owner: Address,
+        platform_wallet: Address,
+        token_address: Address,
+    ) -> Result<Address, Error> {
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        if env.storage().persistent().has(&(TENANT, tenant.clone())) {
+            return Err(Error::TenantExists);
+        }
+
+        let mut tenants = Self::list_tenants(env.clone());
+        if tenants.len() >= MAX_TENANTS {
+            return Err(Error::TooManyTenants);
+        }
+
+        let wasm_hash: BytesN<32> = env.storage().persistent().get(&WASM_HASH).unwrap();
+
+        // Deploy, initialize and take over upgrades in one atomic call
+        let contract_address = env
+            .deployer()
+            .with_current_contract(Self::tenant_salt(&env, &tenant))
+            .deploy_v2(wasm_hash.clone(), ());
+
+        let blitz = BlitzClient::new(&env, &contract_address);
+        blitz.initialize(&owner, &platform_wallet, &token_address);
+        blitz.set_upgrader(&env.current_contract_address());
+
+        let record = Tenant {
+            tenant: tenant.clone(),
+            contract_address: contract_address.clone(),
+            owner,
+            platform_wallet,
+            wasm_hash,
+        };
+        env.storage()
+            .persistent()
+            .set(&(TENANT, tenant.clone()), &record);
+
+        tenants.push_back(tenant.clone());
+        env.storage().persistent().set(&TENANTS, &tenants);
+
+        env.events().publish(
+            (symbol_short!("deployed"), tenant),
+            contract_address.clone(),
+        );
+
+        Ok(contract_address)
+    }
+
+    /// Set the wasm hash used for new deployments and upgrades (only admin)
+    pub fn set_wasm_hash(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        env.storage().persistent().set(&WASM_HASH, &new_wasm_hash);
+
+        env.events()
+            .publish((symbol_short!("wasm"),), new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Upgrade a single tenant to the current wasm hash (only admin)
+    pub fn upgrade_tenant(env: Env, tenant: Symbol) -> Result<(), Error> {
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        let wasm_hash: BytesN<32> = env.storage().persistent().get(&WASM_HASH).unwrap();
+        Self::upgrade_one(&env, &tenant, &wasm_hash)
+    }
+
+    /// Upgrade up to `limit` tenants still on an older wasm hash, starting
+    /// at `cursor` in deployment order (only admin)
+    ///
+    /// A rollout spans as many calls as needed, each resuming from the
+    /// returned `next_cursor`. Tenants whose upgrade fails, e.g. because
+    /// their owner took back upgrade rights, are skipped and reported.
+    /// `limit` must be at least one so every call makes progress.
+    pub fn upgrade_all(env: Env, cursor: u32, limit: u32) -> Result<UpgradeBatch, Error> {
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        if limit == 0 {
+            return Err(Error::InvalidLimit);
+        }
+
+        let wasm_hash: BytesN<32> = env.storage().persistent().get(&WASM_HASH).unwrap();
+        let tenants = Self::list_tenants(env.clone());
+        let start = cursor.min(tenants.len());
+        let end = start
+            .saturating_add(limit.min(MAX_UPGRADE_BATCH))
+            .min(tenants.len());
+
+        let mut batch = UpgradeBatch {
+            upgraded: 0,
+            failed: Vec::new(&env),
+            next_cursor: end,
+        };
+        for tenant in tenants.slice(start..end).iter() {
+            let mut record = Self::get_tenant(env.clone(), tenant.clone())?;
+            if record.wasm_hash == wasm_hash {
+                continue;
+            }
+
+            let blitz = BlitzClient::new(&env, &record.contract_address);
+            if let Ok(Ok(())) = blitz.try_upgrade(&wasm_hash) {
+                Self::record_upgrade(&env, &mut record, &wasm_hash);
+                batch.upgraded += 1;
+            } else {
+                env.events().publish(
+                    (symbol_short!("up_failed"), tenant.clone()),
+                    wasm_hash.clone(),
+                );
+                batch.failed.push_back(tenant);
+            }
+        }
+
+        Ok(batch)
+    }
+
+    /// Transfer factory administration (only admin)
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        env.storage().persistent().set(&ADMIN, &new_admin);
+
+        env.events()
+            .publish((symbol_short!("admin"),), (admin, new_admin));
+
+        Ok(())
+    }
+
+    // === QUERY FUNCTIONS ===
+
+    /// Get a tenant's registry record
+    pub fn get_tenant(env: Env, tenant: Symbol) -> Result<Tenant, Error> {
+        env.storage()
+            .persistent()
+            .get(&(TENANT, tenant))
+            .ok_or(Error::TenantNotFound)
+    }
+
+    /// List all tenant ids in deployment order
+    pub fn list_tenants(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&TENANTS)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the address a tenant's contract is (or will be) deployed at
+    pub fn get_deployed_address(env: Env, tenant: Symbol) -> Address {
+        env.deployer()
+            .with_current_contract(Self::tenant_salt(&env, &tenant))
+            .deployed_address()
+    }
+
+    /// Get the wasm hash used for new deployments and upgrades
+    pub fn get_wasm_hash(env: Env) -> Result<BytesN<32>, Error> {
+        env.storage()
+            .persistent()
+            .get(&WASM_HASH)
+            .ok_or(Error::NotInitialized)
+    }
+
+    // === HELPER FUNCTIONS ===
+
+    /// Get the factory admin
+    fn get_admin(env: &Env) -> Result<Address, Error> {
+        env.storage()
+            .persistent()
+            .get(&ADMIN)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Deterministic deployment salt for a tenant id
+    fn tenant_salt(env: &Env, tenant: &Symbol) -> BytesN<32> {
+        env.crypto().sha256(&tenant.clone().to_xdr(env)).into()
+    }
+
+    /// Upgrade a tenant's contract and record its new wasm hash
+    fn upgrade_one(env: &Env, tenant: &Symbol, wasm_hash: &BytesN<32>) -> Result<(), Error> {
+        let mut record = Self::get_tenant(env.clone(), tenant.clone())?;
+
+        BlitzClient::new(env, &record.contract_address).upgrade(wasm_hash);
+        Self::record_upgrade(env, &mut record, wasm_hash);
+
+        Ok(())
+    }
+
+    /// Store a tenant's new wasm hash after a successful upgrade
+    fn record_upgrade(env: &Env, record: &mut Tenant, wasm_hash: &BytesN<32>) {
+        record.wasm_hash = wasm_hash.clone();
+        env.storage()
+            .persistent()
+            .set(&(TENANT, record.tenant.clone()), record);
+
+        env.events().publish(
+            (symbol_short!("upgraded"), record.tenant.clone()),
+            wasm_hash.clone(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test;
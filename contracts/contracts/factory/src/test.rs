@@ -0,0 +1,147 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Bytes, IntoVal};
+
+// Build the workspace for wasm32v1-none first, see the README
+mod blitz {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/blitz.wasm");
+}
+
+struct Setup<'a> {
+    env: Env,
+    client: BlitzFactoryClient<'a>,
+    admin: Address,
+    token: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    // Deploying initializes the tenant in its owner's name, a nested auth
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+
+    let wasm_hash = env.deployer().upload_contract_wasm(blitz::WASM);
+    let client = BlitzFactoryClient::new(&env, &env.register(BlitzFactory, ()));
+    client.initialize(&admin, &wasm_hash);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+    }
+}
+
+/// Deploy a tenant with a fresh owner, returning the owner
+fn deploy(t: &Setup, tenant: &Symbol) -> Address {
+    let owner = Address::generate(&t.env);
+    let platform_wallet = Address::generate(&t.env);
+    t.client
+        .deploy_tenant(tenant, &owner, &platform_wallet, &t.token);
+    owner
+}
+
+/// Upload the Blitz wasm again with an extra custom section, giving the
+/// same code under a new hash to upgrade to
+fn upload_new_version(t: &Setup) -> BytesN<32> {
+    let mut wasm = Bytes::from_slice(&t.env, blitz::WASM);
+    // Custom section 0 of 8 bytes: a 7 byte name and no payload
+    wasm.extend_from_slice(&[0, 8, 7]);
+    wasm.extend_from_slice(b"version");
+    t.env.deployer().upload_contract_wasm(wasm)
+}
+
+#[test]
+fn test_deploy_tenant() {
+    let t = setup();
+    let tenant = symbol_short!("acme");
+    let predicted = t.client.get_deployed_address(&tenant);
+
+    let owner = Address::generate(&t.env);
+    let platform_wallet = Address::generate(&t.env);
+    let address = t
+        .client
+        .deploy_tenant(&tenant, &owner, &platform_wallet, &t.token);
+    assert_eq!(address, predicted);
+
+    let record = t.client.get_tenant(&tenant);
+    assert_eq!(record.contract_address, address);
+    assert_eq!(record.owner, owner);
+    assert_eq!(record.wasm_hash, t.client.get_wasm_hash());
+    assert_eq!(t.client.list_tenants(), Vec::from_array(&t.env, [tenant]));
+
+    // The instance is initialized and cannot be taken over
+    let blitz = blitz::Client::new(&t.env, &address);
+    assert_eq!(
+        blitz.try_initialize(&owner, &platform_wallet, &t.token),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            blitz::Error::AlreadyInitialized as u32
+        )))
+    );
+}
+
+#[test]
+fn test_deploy_tenant_twice() {
+    let t = setup();
+    let tenant = symbol_short!("acme");
+    deploy(&t, &tenant);
+
+    let owner = Address::generate(&t.env);
+    assert_eq!(
+        t.client
+            .try_deploy_tenant(&tenant, &owner, &owner, &t.token),
+        Err(Ok(Error::TenantExists))
+    );
+}
+
+#[test]
+fn test_upgrade_all_reports_reclaimed_tenant() {
+    let t = setup();
+    let kept = symbol_short!("kept");
+    let reclaimed = symbol_short!("reclaimed");
+    deploy(&t, &kept);
+    let owner = deploy(&t, &reclaimed);
+
+    // The owner takes back upgrade rights from the factory
+    let address = t.client.get_tenant(&reclaimed).contract_address;
+    blitz::Client::new(&t.env, &address).set_upgrader(&owner);
+
+    let new_hash = upload_new_version(&t);
+    t.client.set_wasm_hash(&new_hash);
+
+    // Only the admin signs the rollout, so the owner's upgrade check fails
+    t.env.mock_auths(&[MockAuth {
+        address: &t.admin,
+        invoke: &MockAuthInvoke {
+            contract: &t.client.address,
+            fn_name: "upgrade_all",
+            args: (0u32, 10u32).into_val(&t.env),
+            sub_invokes: &[],
+        },
+    }]);
+    let batch = t.client.upgrade_all(&0, &10);
+
+    assert_eq!(batch.upgraded, 1);
+    assert_eq!(batch.failed, Vec::from_array(&t.env, [reclaimed.clone()]));
+    assert_eq!(batch.next_cursor, 2);
+    assert_eq!(t.client.get_tenant(&kept).wasm_hash, new_hash);
+    assert_ne!(t.client.get_tenant(&reclaimed).wasm_hash, new_hash);
+}
+
+#[test]
+fn test_upgrade_all_rejects_zero_limit() {
+    let t = setup();
+    deploy(&t, &symbol_short!("acme"));
+
+    assert_eq!(
+        t.client.try_upgrade_all(&0, &0),
+        Err(Ok(Error::InvalidLimit))
+    );
+}
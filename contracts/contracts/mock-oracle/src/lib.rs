@@ -0,0 +1,98 @@
+#![no_std]
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol,
+};
+
+// Storage keys
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const BASE: Symbol = symbol_short!("BASE");
+const DECIMALS: Symbol = symbol_short!("DECIMALS");
+const PRICE: Symbol = symbol_short!("PRICE"); // (PRICE, asset) -> PriceData
+
+// Error codes
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    InvalidPrice = 3,
+}
+
+// Data structures
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// Admin-fed price feed exposing the read side of SEP-40, for local
+/// testing of oracle-dependent Blitz features
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    /// Initialize with the quote asset and price decimals
+    pub fn initialize(env: Env, admin: Address, base: Asset, decimals: u32) -> Result<(), Error> {
+        admin.require_auth();
+
+        if env.storage().persistent().has(&ADMIN) {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        env.storage().persistent().set(&ADMIN, &admin);
+        env.storage().persistent().set(&BASE, &base);
+        env.storage().persistent().set(&DECIMALS, &decimals);
+
+        Ok(())
+    }
+
+    /// Set an asset's price as observed at `timestamp` (only admin)
+    pub fn set_price(env: Env, asset: Asset, price: i128, timestamp: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&ADMIN)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if price <= 0 {
+            return Err(Error::InvalidPrice);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(PRICE, asset.clone()), &PriceData { price, timestamp });
+
+        env.events()
+            .publish((symbol_short!("price"), asset), (price, timestamp));
+
+        Ok(())
+    }
+
+    // === SEP-40 FUNCTIONS ===
+
+    /// Get the most recent price for an asset
+    pub fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+        env.storage().persistent().get(&(PRICE, asset))
+    }
+
+    /// Get the asset prices are quoted in
+    pub fn base(env: Env) -> Asset {
+        env.storage().persistent().get(&BASE).unwrap()
+    }
+
+    /// Get the number of decimals prices are expressed with
+    pub fn decimals(env: Env) -> u32 {
+        env.storage().persistent().get(&DECIMALS).unwrap_or(14)
+    }
+}
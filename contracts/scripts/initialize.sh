@@ -96,8 +96,9 @@ EOF
     echo -e "${BLUE}🎉 Initialization completed!${NC}"
     echo ""
     echo -e "${YELLOW}Next steps:${NC}"
-    echo "1. Run './manage.sh summary' to check contract status"
-    echo "2. Run './manage.sh start-auction' to start the first auction"
+    echo "1. Run './manage.sh create-slot' to register the 'main' QR slot"
+    echo "2. Run './manage.sh summary' to check contract status"
+    echo "3. Run './manage.sh start-auction' to start the first auction"
 else
     echo -e "${RED}❌ Initialization failed!${NC}"
     exit 1
@@ -20,15 +20,21 @@ else
     exit 1
 fi
 
+# QR slot to operate on (override with SLOT=<name> ./manage.sh ...)
+SLOT=${SLOT:-main}
+
 # Function to display help
 show_help() {
     echo -e "${BLUE}🔧 Blitz Auction Contract Management${NC}"
     echo "=================================="
     echo ""
     echo -e "${YELLOW}Available commands:${NC}"
+    echo "  create-slot         - Register the selected QR slot (owner only)"
+    echo "  list-slots          - List all registered QR slots"
     echo "  summary              - Get contract and auction summary"
     echo "  start-auction        - Start a new auction"
     echo "  end-auction          - End current auction"
+    echo "  close-display        - Release escrowed proceeds after a display window ends"
     echo "  current-auction      - Get current auction details"
     echo "  last-auction         - Get last completed auction"
     echo "  auction-history      - Get auction history"
@@ -44,6 +50,7 @@ show_help() {
     echo ""
     echo -e "${CYAN}Contract ID: ${CONTRACT_ID}${NC}"
     echo -e "${CYAN}Network: ${NETWORK}${NC}"
+    echo -e "${CYAN}Slot: ${SLOT}${NC}"
 }
 
 # Function to invoke contract
@@ -73,14 +80,27 @@ call_contract() {
 
 # Main command handler
 case "$1" in
+    "create-slot")
+        echo -e "${YELLOW}🆕 Creating slot ${SLOT}...${NC}"
+        invoke_contract create_slot --slot $SLOT
+        if [ $? -eq 0 ]; then
+            echo -e "${GREEN}✅ Slot created successfully!${NC}"
+        fi
+        ;;
+    
+    "list-slots")
+        echo -e "${BLUE}📍 Getting registered slots...${NC}"
+        call_contract list_slots
+        ;;
+    
     "summary")
         echo -e "${BLUE}📊 Getting auction summary...${NC}"
-        call_contract get_auction_summary
+        call_contract get_auction_summary --slot $SLOT
         ;;
     
     "start-auction")
         echo -e "${YELLOW}🚀 Starting new auction...${NC}"
-        invoke_contract start_auction
+        invoke_contract start_auction --slot $SLOT
         if [ $? -eq 0 ]; then
             echo -e "${GREEN}✅ Auction started successfully!${NC}"
         fi
@@ -88,25 +108,40 @@ case "$1" in
     
     "end-auction")
         echo -e "${YELLOW}🏁 Ending current auction...${NC}"
-        invoke_contract end_auction
+        invoke_contract end_auction --slot $SLOT --keeper $SOURCE_ACCOUNT
         if [ $? -eq 0 ]; then
             echo -e "${GREEN}✅ Auction ended successfully!${NC}"
         fi
         ;;
     
+    "close-display")
+        read -p "Enter auction ID: " AUCTION_ID
+
+        if [ -z "$AUCTION_ID" ]; then
+            echo -e "${RED}❌ Auction ID is required!${NC}"
+            exit 1
+        fi
+
+        echo -e "${YELLOW}⏳ Closing display window...${NC}"
+        invoke_contract close_display_window --auction_id $AUCTION_ID
+        if [ $? -eq 0 ]; then
+            echo -e "${GREEN}✅ Proceeds released!${NC}"
+        fi
+        ;;
+
     "current-auction")
         echo -e "${BLUE}📋 Getting current auction details...${NC}"
-        call_contract get_current_auction
+        call_contract get_current_auction --slot $SLOT
         ;;
     
     "last-auction")
         echo -e "${BLUE}📋 Getting last auction details...${NC}"
-        call_contract get_last_auction
+        call_contract get_last_auction --slot $SLOT
         ;;
     
     "auction-history")
         echo -e "${BLUE}📚 Getting auction history...${NC}"
-        call_contract get_auction_history
+        call_contract get_auction_history --slot $SLOT
         ;;
     
     "place-bid")
@@ -123,7 +158,7 @@ case "$1" in
         fi
         
         echo -e "${YELLOW}⏳ Placing bid...${NC}"
-        invoke_contract place_bid --bidder $BIDDER --amount $AMOUNT --preferred_url "$URL"
+        invoke_contract place_bid --slot $SLOT --bidder $BIDDER --amount $AMOUNT --preferred_url "$URL"
         if [ $? -eq 0 ]; then
             echo -e "${GREEN}✅ Bid placed successfully!${NC}"
         fi
@@ -131,7 +166,7 @@ case "$1" in
     
     "get-qr-url")
         echo -e "${BLUE}📱 Getting QR URL...${NC}"
-        call_contract get_qr_url
+        call_contract get_qr_url --slot $SLOT
         ;;
     
     "contract-info")
@@ -141,17 +176,17 @@ case "$1" in
     
     "time-remaining")
         echo -e "${BLUE}⏰ Getting time remaining...${NC}"
-        call_contract get_time_remaining
+        call_contract get_time_remaining --slot $SLOT
         ;;
     
     "minimum-bid")
         echo -e "${BLUE}💵 Getting minimum bid...${NC}"
-        call_contract get_minimum_bid
+        call_contract get_minimum_bid --slot $SLOT
         ;;
     
     "is-active")
         echo -e "${BLUE}❓ Checking if auction is active...${NC}"
-        call_contract is_auction_active
+        call_contract is_auction_active --slot $SLOT
         ;;
     
     "set-min-increment")
@@ -162,7 +197,7 @@ case "$1" in
         fi
         
         echo -e "${YELLOW}🔧 Setting minimum bid increment to $2 stroops...${NC}"
-        invoke_contract set_min_bid_increment --new_increment $2
+        invoke_contract set_min_bid_increment --slot $SLOT --new_increment $2
         if [ $? -eq 0 ]; then
             echo -e "${GREEN}✅ Minimum bid increment updated!${NC}"
         fi
@@ -176,7 +211,7 @@ case "$1" in
         fi
         
         echo -e "${YELLOW}🔧 Setting minimum starting bid to $2 stroops...${NC}"
-        invoke_contract set_min_starting_bid --new_starting_bid $2
+        invoke_contract set_min_starting_bid --slot $SLOT --new_starting_bid $2
         if [ $? -eq 0 ]; then
             echo -e "${GREEN}✅ Minimum starting bid updated!${NC}"
         fi
@@ -20,6 +20,7 @@ export default function AuctionDisplay() {
   const [bidAmount, setBidAmount] = useState("");
   const [timeLeft, setTimeLeft] = useState(0);
   const [isPlacingBid, setIsPlacingBid] = useState(false);
+  const [minimumBid, setMinimumBid] = useState("");
 
   useEffect(() => {
     if (!isConnected) {
@@ -35,6 +36,25 @@ export default function AuctionDisplay() {
     }
   }, [timeRemaining]);
 
+  // Minimum next bid as the contract computes it, increment tiers included
+  useEffect(() => {
+    if (!auctionClient || !currentAuction) return;
+
+    let cancelled = false;
+    auctionClient
+      .getMinimumBid()
+      .then((amount) => {
+        if (!cancelled) setMinimumBid(auctionClient.formatBidAmount(amount));
+      })
+      .catch(() => {
+        if (!cancelled) setMinimumBid("");
+      });
+
+    return () => {
+      cancelled = true;
+    };
+  }, [auctionClient, currentAuction]);
+
   // Countdown
   useEffect(() => {
     if (timeLeft <= 0) return;
@@ -129,6 +149,7 @@ export default function AuctionDisplay() {
       }
 
       const assembled = await bidderClient.place_bid({
+        slot: auctionClient.slot,
         bidder: publicKey,
         amount: BigInt(contractAmount.toString()),
         preferred_url: bidUrl,
@@ -179,22 +200,6 @@ export default function AuctionDisplay() {
     }
   };
 
-  const getMinimumBid = () => {
-    if (!currentAuction) return "0.01";
-
-    try {
-      if (currentAuction.highest_bid && currentAuction.highest_bid !== "0") {
-        const nextBid = BigInt(currentAuction.highest_bid) + BigInt("10000");
-        return auctionClient.formatBidAmount(nextBid.toString());
-      }
-      return "0.01";
-    } catch (error) {
-      return "0.01";
-    }
-  };
-
-  const minimumBid = getMinimumBid();
-
   if (isLoading) {
     return (
       <div className="bg-gray-900 border border-gray-700 rounded-xl p-6 shadow-lg">
@@ -362,12 +367,12 @@ export default function AuctionDisplay() {
               min={minimumBid}
               value={bidAmount}
               onChange={(e) => setBidAmount(e.target.value)}
-              placeholder={`Minimum: ${minimumBid} XLM`}
+              placeholder={minimumBid ? `Minimum: ${minimumBid} XLM` : ""}
               className="w-full px-3 py-2 bg-gray-800 border border-gray-600 rounded-lg text-white placeholder-gray-400 focus:outline-none focus:border-blue-500"
               required
             />
             <p className="text-xs text-gray-400 mt-1">
-              Minimum bid: {minimumBid} XLM
+              Minimum bid: {minimumBid || "..."} XLM
             </p>
           </div>
 
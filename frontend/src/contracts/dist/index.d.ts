@@ -1,6 +1,6 @@
 import { Buffer } from "buffer";
 import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions, Result } from '@stellar/stellar-sdk/contract';
-import type { u64, i128, Option } from '@stellar/stellar-sdk/contract';
+import type { u32, u64, i128, Option } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -38,29 +38,341 @@ export declare const Errors: {
     9: {
         message: string;
     };
+    10: {
+        message: string;
+    };
+    11: {
+        message: string;
+    };
+    12: {
+        message: string;
+    };
+    13: {
+        message: string;
+    };
+    14: {
+        message: string;
+    };
+    15: {
+        message: string;
+    };
+    16: {
+        message: string;
+    };
+    17: {
+        message: string;
+    };
+    18: {
+        message: string;
+    };
+    19: {
+        message: string;
+    };
+    20: {
+        message: string;
+    };
+    21: {
+        message: string;
+    };
+    22: {
+        message: string;
+    };
+    23: {
+        message: string;
+    };
+    24: {
+        message: string;
+    };
+    25: {
+        message: string;
+    };
+    26: {
+        message: string;
+    };
+    27: {
+        message: string;
+    };
+    28: {
+        message: string;
+    };
+    29: {
+        message: string;
+    };
+    30: {
+        message: string;
+    };
+    31: {
+        message: string;
+    };
+    32: {
+        message: string;
+    };
+    33: {
+        message: string;
+    };
+    34: {
+        message: string;
+    };
+    35: {
+        message: string;
+    };
+    36: {
+        message: string;
+    };
+    37: {
+        message: string;
+    };
+    38: {
+        message: string;
+    };
+    39: {
+        message: string;
+    };
+    40: {
+        message: string;
+    };
+    41: {
+        message: string;
+    };
+    42: {
+        message: string;
+    };
+    43: {
+        message: string;
+    };
 };
+/**
+ * `highest_bid` is in units of the contract token; the leading bid was
+ * actually paid by `bid_payer` as `bid_amount` of `bid_token`, reserved
+ * from the payer's deposit balance if `bid_from_balance`. Refunds go to
+ * `bid_payer`; `highest_bidder` controls the URL until the display rights
+ * of a settled auction are transferred.
+ */
 export interface Auction {
     auction_id: u64;
+    bid_amount: i128;
+    bid_from_balance: boolean;
+    bid_payer: string;
+    bid_token: string;
     ending_time: u64;
     highest_bid: i128;
     highest_bidder: string;
     is_ended: boolean;
+    metadata: QRMetadata;
     preferred_url: string;
+    slot: string;
     starting_time: u64;
     url_expiry_time: u64;
 }
+/**
+ * Ad card details a winner attaches to their URL; empty until set
+ */
+export interface QRMetadata {
+    advertiser: string;
+    category: string;
+    description: string;
+    image_hash: Option<Buffer>;
+    title: string;
+}
+/**
+ * Increment applied once the highest bid reaches `min_bid`
+ */
+export interface IncrementTier {
+    increment: i128;
+    min_bid: i128;
+}
+/**
+ * Escrowed proxy bid; the ceiling is kept out of `Auction` on purpose
+ *
+ * Pools bid through a proxy whose ceiling is the pooled balance; their
+ * escrow stays with the pool instead of being refunded to `bidder`.
+ */
+export interface ProxyBid {
+    auction_id: u64;
+    bidder: string;
+    max_amount: i128;
+    pool_id: Option<u64>;
+}
+/**
+ * Crowdfunded bid on one auction, placed by the contract on the pool's behalf
+ *
+ * The contract pays as `bid_payer` while `creator` is the bidder who
+ * controls the URL, metadata and display rights if the pool wins.
+ *
+ * `spent` is the winning bid paid from the pool and `refunded` any
+ * downtime refund credited back to it; contributors withdraw the rest pro
+ * rata once the auction is settled.
+ */
+export interface Pool {
+    auction_id: u64;
+    balance: i128;
+    contributors: Array<string>;
+    creator: string;
+    pool_id: u64;
+    refunded: i128;
+    slot: string;
+    spent: i128;
+    url: string;
+}
+/**
+ * A contributor's stake in a pool
+ */
+export interface PoolShare {
+    amount: i128;
+    withdrawn: i128;
+}
+/**
+ * Single entry in an auction's on-chain bid ladder
+ */
+export interface BidRecord {
+    amount: i128;
+    bidder: string;
+    preferred_url: string;
+    timestamp: u64;
+}
+/**
+ * Lifetime aggregates for a single bidder address
+ */
+export interface BidderStats {
+    auctions_won: u32;
+    bids_placed: u32;
+    last_win_id: u64;
+    total_paid: i128;
+    total_refunded: i128;
+}
+export interface LeaderboardEntry {
+    bidder: string;
+    total_paid: i128;
+}
+/**
+ * Owner-managed fallback URL shown while no winner is displaying
+ */
+export interface HouseAd {
+    id: u32;
+    url: string;
+    weight: u32;
+}
+/**
+ * Paid display slot; windows are queued back-to-back and never overlap
+ */
+export interface DisplayWindow {
+    auction_id: u64;
+    end_time: u64;
+    holder: string;
+    start_time: u64;
+    url: string;
+}
+/**
+ * Reward paid from proceeds to whoever settles an expired auction
+ *
+ * The reward is `fixed_amount` plus `bps` of the winning bid, capped at
+ * `max_amount`.
+ */
+export interface KeeperReward {
+    bps: u32;
+    fixed_amount: i128;
+    max_amount: i128;
+}
+/**
+ * Address a URL holder has delegated URL and metadata edits to
+ *
+ * Only honoured while `holder` still controls the auction's URL.
+ */
+export interface UrlManager {
+    holder: string;
+    manager: string;
+}
+/**
+ * Holder of a slot in Harberger mode
+ *
+ * Tax accrues every second on the self-assessed `price` since
+ * `assessed_at`, at the slot's daily rate. `tax_paid` is what has already
+ * been collected from `deposit` since then; the holding lapses once the
+ * accrued tax exhausts the deposit.
+ */
+export interface HarbergerHolding {
+    assessed_at: u64;
+    deposit: i128;
+    holder: string;
+    price: i128;
+    tax_paid: i128;
+    url: string;
+}
+/**
+ * Remaining display rights of a settled auction offered for sale
+ *
+ * `price` is in units of the contract token. The listing lapses when the
+ * window ends or the rights change hands.
+ */
+export interface Listing {
+    auction_id: u64;
+    listed_at: u64;
+    price: i128;
+    seller: string;
+}
+/**
+ * Scans attested by the reporter for one hour of a display window
+ *
+ * `bucket` counts hours from the start of the window.
+ */
+export interface ScanBucket {
+    bucket: u32;
+    reported_at: u64;
+    scans: u32;
+}
+/**
+ * Period during which a slot's physical display was offline
+ */
+export interface DowntimeInterval {
+    end_time: u64;
+    start_time: u64;
+}
+/**
+ * Winning proceeds held until the auction's display window ends
+ *
+ * With `linear_vesting` the platform may claim proceeds as display time
+ * is delivered; otherwise everything is released when the window closes.
+ */
+export interface ProceedsEscrow {
+    linear_vesting: boolean;
+    refunded: i128;
+    released: i128;
+    token: string;
+    total: i128;
+}
+/**
+ * Locked vs released accounting for an auction's proceeds
+ */
+export interface ProceedsStatus {
+    claimable: i128;
+    locked: i128;
+    refunded: i128;
+    released: i128;
+    total: i128;
+}
 export interface QRStatus {
+    fallback_id: Option<u32>;
+    metadata: QRMetadata;
     source: string;
     status: string;
 }
 export interface ContractInfo {
     auction_counter: u64;
-    min_bid_increment: i128;
-    min_starting_bid: i128;
+    keeper_reward: KeeperReward;
     owner: string;
     platform_wallet: string;
+    slots: Array<string>;
     token_address: string;
 }
+export interface SlotConfig {
+    continuous_mode: boolean;
+    harberger_tax_bps: Option<u32>;
+    increment_tiers: Array<IncrementTier>;
+    max_bid_param: i128;
+    min_bid_increment: i128;
+    min_bid_increment_bps: u32;
+    min_starting_bid: i128;
+}
 export interface AuctionSummary {
     current_auction: Auction;
     has_active_qr: boolean;
@@ -69,6 +381,33 @@ export interface AuctionSummary {
     qr_url: string;
     time_remaining: u64;
 }
+/**
+ * Asset identifier in the SEP-40 price feed interface
+ */
+export type Asset = {
+    tag: "Stellar";
+    values: readonly [string];
+} | {
+    tag: "Other";
+    values: readonly [string];
+};
+/**
+ * Price record in the SEP-40 price feed interface
+ */
+export interface PriceData {
+    price: i128;
+    timestamp: u64;
+}
+/**
+ * Starting bid and flat increment in the price oracle's base asset
+ *
+ * Amounts are scaled by the oracle's decimals and converted to the
+ * contract token at the latest price whenever a minimum bid is computed.
+ */
+export interface FiatFloors {
+    min_bid_increment: i128;
+    min_starting_bid: i128;
+}
 export interface Client {
     /**
      * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -92,11 +431,55 @@ export interface Client {
          */
         simulate?: boolean;
     }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a create_slot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Register a new QR slot, one per physical placement (only owner)
+     */
+    create_slot: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a list_slots transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * List all registered slots
+     */
+    list_slots: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<string>>>;
     /**
      * Construct and simulate a place_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Place a bid on the current auction
+     *
+     * If another bidder holds a proxy bid with a ceiling at or above
+     * `amount`, the proxy counters automatically: the visible price rises
+     * but the proxy bidder stays in the lead and nothing is taken from
+     * `bidder`, who must still hold `amount` for the counter to happen.
      */
-    place_bid: ({ bidder, amount, preferred_url }: {
+    place_bid: ({ slot, bidder, amount, preferred_url }: {
+        slot: string;
         bidder: string;
         amount: i128;
         preferred_url: string;
@@ -115,10 +498,20 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a start_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Start a new auction (only owner)
+     * Construct and simulate a place_gift_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Place a bid paid by `payer` on behalf of `beneficiary`
+     *
+     * Lets a company buy a slot for someone else: `beneficiary` controls
+     * the URL and its metadata, while refunds go back to `payer`.
+     * Gifting to the current leader is refused.
      */
-    start_auction: (options?: {
+    place_gift_bid: ({ slot, payer, beneficiary, amount, preferred_url }: {
+        slot: string;
+        payer: string;
+        beneficiary: string;
+        amount: i128;
+        preferred_url: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -133,10 +526,22 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a end_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * End the current auction
+     * Construct and simulate a place_bid_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Place a bid funded from `payer`'s token allowance to `spender`
+     *
+     * Lets an agency or relayer bid on a client's behalf: `beneficiary`
+     * controls the URL if the bid wins, and `payer` is refunded if it is
+     * outbid. Bidding for the current leader with someone else's
+     * allowance is refused.
      */
-    end_auction: (options?: {
+    place_bid_from: ({ slot, spender, payer, beneficiary, amount, preferred_url }: {
+        slot: string;
+        spender: string;
+        payer: string;
+        beneficiary: string;
+        amount: i128;
+        preferred_url: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -151,10 +556,20 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_qr_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get the QR URL to display
+     * Construct and simulate a place_token_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Place a bid paid in one of the accepted tokens
+     *
+     * `amount` is in units of `token`; it is converted to the contract
+     * token's units through the price oracle to rank against other bids,
+     * and refunded in `token` if outbid.
      */
-    get_qr_url: (options?: {
+    place_token_bid: ({ slot, bidder, token, amount, preferred_url }: {
+        slot: string;
+        bidder: string;
+        token: string;
+        amount: i128;
+        preferred_url: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -167,12 +582,22 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_current_auction_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get current auction URL (during bidding)
+     * Construct and simulate a place_proxy_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Place a proxy (maximum) bid on the current auction
+     *
+     * The full `max_amount` is escrowed and the contract bids on the
+     * bidder's behalf up to that ceiling, only ever paying one increment
+     * over the next best bid. The ceiling itself is never exposed through
+     * `Auction`; unused escrow is refunded at settlement or when outbid.
      */
-    get_current_auction_url: (options?: {
+    place_proxy_bid: ({ slot, bidder, max_amount, preferred_url }: {
+        slot: string;
+        bidder: string;
+        max_amount: i128;
+        preferred_url: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -185,12 +610,19 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a has_active_qr_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Check if there's an active QR URL
+     * Construct and simulate a open_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Open a crowdfunded pool bidding for `url` on a slot's current auction
+     *
+     * The URL is fixed: while the pool leads and once it wins, its URL,
+     * metadata and display rights cannot be changed, transferred or sold.
      */
-    has_active_qr_url: (options?: {
+    open_pool: ({ slot, creator, url }: {
+        slot: string;
+        creator: string;
+        url: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -203,12 +635,21 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<boolean>>;
+    }) => Promise<AssembledTransaction<Result<u64>>>;
     /**
-     * Construct and simulate a get_qr_url_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get QR URL status and source
+     * Construct and simulate a contribute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Contribute contract tokens to a pool
+     *
+     * The pool bids as a single bidder, defending its lead with the pooled
+     * balance as its ceiling and only paying one increment over the next
+     * best bid. Each contribution raises the ceiling and, if the pool is
+     * not leading, bids again.
      */
-    get_qr_url_status: (options?: {
+    contribute: ({ pool_id, contributor, amount }: {
+        pool_id: u64;
+        contributor: string;
+        amount: i128;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -221,12 +662,19 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<QRStatus>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_qr_url_expiry_time transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get QR URL expiry time
+     * Construct and simulate a withdraw_from_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Withdraw a contributor's pro rata share of what a pool did not spend
+     *
+     * Available once the pool's auction is settled: the full contribution
+     * if the pool lost, the unspent remainder if it won. Downtime refunds
+     * on a winning pool's display can be withdrawn as they are credited.
      */
-    get_qr_url_expiry_time: (options?: {
+    withdraw_from_pool: ({ pool_id, contributor }: {
+        pool_id: u64;
+        contributor: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -239,12 +687,22 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
     /**
-     * Construct and simulate a get_time_remaining transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get time remaining in current auction
+     * Construct and simulate a set_qr_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Attach ad card metadata to an auction's URL
+     *
+     * Callable by the leading bidder while bidding is open and by the
+     * winner until their display window ends, or by their URL manager.
+     * Metadata is cleared whenever a different bidder takes the lead.
+     * A pool's bid keeps the content it was opened with.
      */
-    get_time_remaining: (options?: {
+    set_qr_metadata: ({ slot, auction_id, caller, metadata }: {
+        slot: string;
+        auction_id: u64;
+        caller: string;
+        metadata: QRMetadata;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -257,12 +715,19 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a is_auction_active transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Check if auction is currently active
+     * Construct and simulate a update_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Change the URL of a leading bid or of a winner's display window
+     *
+     * Same access rules as `set_qr_metadata`.
      */
-    is_auction_active: (options?: {
+    update_url: ({ slot, auction_id, caller, url }: {
+        slot: string;
+        auction_id: u64;
+        caller: string;
+        url: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -275,12 +740,17 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<boolean>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_current_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get current auction details
+     * Construct and simulate a appoint_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Let `manager` edit the URL and metadata of an auction the caller
+     * leads or holds the display rights of, replacing any previous manager
      */
-    get_current_auction: (options?: {
+    appoint_manager: ({ slot, auction_id, manager }: {
+        slot: string;
+        auction_id: u64;
+        manager: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -293,12 +763,16 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Auction>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_last_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get last completed auction
+     * Construct and simulate a revoke_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Revoke the URL manager of an auction the caller leads or holds the
+     * display rights of
      */
-    get_last_auction: (options?: {
+    revoke_manager: ({ slot, auction_id }: {
+        slot: string;
+        auction_id: u64;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -311,13 +785,19 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Auction>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get specific auction by ID (limited implementation due to format! constraint)
+     * Construct and simulate a transfer_display transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Transfer the remaining display rights of a settled auction
+     *
+     * The new holder controls the URL and metadata until the window ends;
+     * any manager appointed by the previous holder loses access. Refunds
+     * and proceeds are unaffected. A pool's display rights stay with its
+     * contributors.
      */
-    get_auction: ({ auction_id }: {
+    transfer_display: ({ auction_id, to }: {
         auction_id: u64;
+        to: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -331,12 +811,18 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Option<Auction>>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_auction_counter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get auction counter
+     * Construct and simulate a list_display transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * List the remaining display rights of a settled auction for sale at
+     * `price` contract tokens, replacing any previous listing
+     *
+     * Not available for auctions won by a pool.
      */
-    get_auction_counter: (options?: {
+    list_display: ({ auction_id, price }: {
+        auction_id: u64;
+        price: i128;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -349,12 +835,14 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_contract_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get contract information
+     * Construct and simulate a cancel_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Withdraw a display rights listing (only seller)
      */
-    get_contract_info: (options?: {
+    cancel_listing: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -367,12 +855,18 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<ContractInfo>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_auction_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get auction summary (everything frontend needs)
+     * Construct and simulate a buy_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Buy listed display rights at the ask price
+     *
+     * The seller receives the price minus the platform royalty and the
+     * buyer becomes the display holder.
      */
-    get_auction_summary: (options?: {
+    buy_listing: ({ auction_id, buyer }: {
+        auction_id: u64;
+        buyer: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -385,12 +879,23 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<AuctionSummary>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_minimum_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Calculate minimum bid for current auction
+     * Construct and simulate a claim_slot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Take over a Harberger slot
+     *
+     * The buyer pays the current holder their self-assessed price, and the
+     * holder's unused tax deposit is refunded to them. A vacant slot is
+     * free to claim. The buyer sets their own price and prepays `deposit`
+     * contract tokens of tax.
      */
-    get_minimum_bid: (options?: {
+    claim_slot: ({ slot, buyer, price, url, deposit }: {
+        slot: string;
+        buyer: string;
+        price: i128;
+        url: string;
+        deposit: i128;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -403,12 +908,36 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_auction_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get auction history (simplified - returns last 5 auctions)
+     * Construct and simulate a set_harberger_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Self-assess a new price for a Harberger slot (only holder)
+     */
+    set_harberger_price: ({ slot, price }: {
+        slot: string;
+        price: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a top_up_tax transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Add to the tax deposit of a Harberger slot (only holder)
      */
-    get_auction_history: (options?: {
+    top_up_tax: ({ slot, amount }: {
+        slot: string;
+        amount: i128;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -421,13 +950,14 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<Auction>>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a set_min_bid_increment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Set minimum bid increment (only owner)
+     * Construct and simulate a collect_tax transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Pay the tax accrued on a Harberger slot to the platform wallet,
+     * vacating the slot if the deposit has run out. Anyone may call this.
      */
-    set_min_bid_increment: ({ new_increment }: {
-        new_increment: i128;
+    collect_tax: ({ slot }: {
+        slot: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -443,11 +973,17 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a set_min_starting_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Set minimum starting bid (only owner)
+     * Construct and simulate a report_scans transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Report the scans counted during one hour of a settled auction's
+     * display window (only scan reporter)
+     *
+     * Buckets must be reported in increasing order and only once the hour
+     * they cover has passed.
      */
-    set_min_starting_bid: ({ new_starting_bid }: {
-        new_starting_bid: i128;
+    report_scans: ({ auction_id, bucket, scans }: {
+        auction_id: u64;
+        bucket: u32;
+        scans: u32;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -463,11 +999,1611 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a set_platform_wallet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Update platform wallet (only owner)
+     * Construct and simulate a report_downtime transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Record a period the slot's display was offline during a settled
+     * auction's window (only device reporter)
+     *
+     * Intervals are clipped to the window and must be reported in order
+     * without overlapping.
      */
-    set_platform_wallet: ({ new_wallet }: {
-        new_wallet: string;
+    report_downtime: ({ auction_id, start_time, end_time }: {
+        auction_id: u64;
+        start_time: u64;
+        end_time: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a close_display_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Release an auction's escrowed proceeds once its display window ends
+     *
+     * The winner is refunded pro rata for reported downtime, less anything
+     * already vested to the platform, and the rest goes to the platform
+     * wallet. Anyone may call this.
+     */
+    close_display_window: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a claim_proceeds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Pay the platform whatever has vested of an auction's proceeds
+     *
+     * Only linearly vesting escrows release anything before the window
+     * ends; after that this closes the window like `close_display_window`.
+     * Anyone may call this. Returns the amount paid to the platform.
+     */
+    claim_proceeds: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+     * Construct and simulate a deposit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deposit contract tokens into the caller's internal balance
+     *
+     * Bids covered by the balance are reserved from it instead of being
+     * transferred, and refunded into it when outbid.
+     */
+    deposit: ({ from, amount }: {
+        from: string;
+        amount: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a withdraw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Withdraw contract tokens from the caller's internal balance
+     */
+    withdraw: ({ to, amount }: {
+        to: string;
+        amount: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a start_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Start a new auction (only owner)
+     */
+    start_auction: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a end_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * End the current auction
+     *
+     * Anyone may settle an expired auction. The `keeper` receives the
+     * configured keeper reward out of the winning bid and, in continuous
+     * mode, the next auction is started in the same call.
+     */
+    end_auction: ({ slot, keeper }: {
+        slot: string;
+        keeper: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a get_qr_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the QR URL to display
+     *
+     * Falls back to the Harberger holder's URL, then to the
+     * owner-configured house ads while no winner is displaying.
+     */
+    get_qr_url: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a get_current_auction_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get current auction URL (during bidding)
+     */
+    get_current_auction_url: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a has_active_qr_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Check if there's an active winner or Harberger holder QR URL
+     */
+    has_active_qr_url: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<boolean>>;
+    /**
+     * Construct and simulate a get_qr_url_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get QR URL status and source
+     */
+    get_qr_url_status: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<QRStatus>>;
+    /**
+     * Construct and simulate a get_qr_url_expiry_time transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get QR URL expiry time
+     */
+    get_qr_url_expiry_time: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a get_current_display transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the display window currently being served, if any
+     *
+     * Includes the window of an expired auction that has not been settled yet.
+     */
+    get_current_display: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<DisplayWindow>>>;
+    /**
+     * Construct and simulate a get_display_schedule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the current and upcoming display windows in order
+     */
+    get_display_schedule: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DisplayWindow>>>;
+    /**
+     * Construct and simulate a get_time_remaining transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get time remaining in current auction
+     */
+    get_time_remaining: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a is_auction_active transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Check if auction is currently active
+     */
+    is_auction_active: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<boolean>>;
+    /**
+     * Construct and simulate a get_current_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get current auction details
+     */
+    get_current_auction: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Auction>>;
+    /**
+     * Construct and simulate a get_last_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get last completed auction
+     */
+    get_last_auction: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Auction>>;
+    /**
+     * Construct and simulate a get_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a completed auction by ID
+     */
+    get_auction: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<Auction>>>;
+    /**
+     * Construct and simulate a get_auction_counter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get auction counter
+     */
+    get_auction_counter: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a get_contract_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get contract information
+     */
+    get_contract_info: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<ContractInfo>>;
+    /**
+     * Construct and simulate a get_slot_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a slot's bid parameters and settings
+     */
+    get_slot_config: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<SlotConfig>>;
+    /**
+     * Construct and simulate a get_auction_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get auction summary (everything frontend needs)
+     */
+    get_auction_summary: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<AuctionSummary>>;
+    /**
+     * Construct and simulate a get_minimum_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Calculate minimum bid for current auction
+     *
+     * Fails with `OracleStale` if the slot's floors are set in fiat and
+     * the oracle price is too old to convert them.
+     */
+    get_minimum_bid: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+     * Construct and simulate a get_increment_tiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the tiered minimum bid increment table
+     */
+    get_increment_tiers: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<IncrementTier>>>;
+    /**
+     * Construct and simulate a get_bids transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a page of an auction's bid ladder, oldest first
+     *
+     * `cursor` is the index of the first bid to return among every bid
+     * ever placed on the auction, so it stays valid as old bids are
+     * dropped. A cursor before the oldest kept bid starts at that bid
+     * (see `get_first_bid_index`).
+     */
+    get_bids: ({ auction_id, cursor, limit }: {
+        auction_id: u64;
+        cursor: u32;
+        limit: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<BidRecord>>>;
+    /**
+     * Construct and simulate a get_bid_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the number of bids stored for an auction
+     */
+    get_bid_count: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a get_first_bid_index transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the index of the oldest bid still stored for an auction, the
+     * cursor to start paging `get_bids` from
+     */
+    get_first_bid_index: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a get_bidder_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get lifetime statistics for a bidder
+     */
+    get_bidder_stats: ({ bidder }: {
+        bidder: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<BidderStats>>;
+    /**
+     * Construct and simulate a get_leaderboard transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the top bidders by total amount paid for won auctions
+     */
+    get_leaderboard: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<LeaderboardEntry>>>;
+    /**
+     * Construct and simulate a get_house_ads transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the configured fallback house ads
+     */
+    get_house_ads: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<HouseAd>>>;
+    /**
+     * Construct and simulate a get_auction_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a slot's most recent completed auctions, oldest first
+     */
+    get_auction_history: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<Auction>>>;
+    /**
+     * Construct and simulate a get_scan_reports transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the scan reports submitted for an auction, in bucket order
+     */
+    get_scan_reports: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<ScanBucket>>>;
+    /**
+     * Construct and simulate a get_total_scans transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the total attested scans for an auction
+     */
+    get_total_scans: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a get_cost_per_scan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the winning bid divided by attested scans (0 before any scans)
+     */
+    get_cost_per_scan: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a get_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get an address's internal deposit balance in contract tokens
+     */
+    get_balance: ({ address }: {
+        address: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a get_url_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the address currently allowed to manage an auction's URL, if any
+     */
+    get_url_manager: ({ slot, auction_id }: {
+        slot: string;
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<string>>>;
+    /**
+     * Construct and simulate a get_display_holder transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the address holding a settled auction's display rights
+     */
+    get_display_holder: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<string>>>;
+    /**
+     * Construct and simulate a get_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a crowdfunded pool by id
+     */
+    get_pool: ({ pool_id }: {
+        pool_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<Pool>>>;
+    /**
+     * Construct and simulate a get_pool_contribution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get how much an address has contributed to a pool
+     */
+    get_pool_contribution: ({ pool_id, contributor }: {
+        pool_id: u64;
+        contributor: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a get_harberger_holding transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a Harberger slot's holder, price, URL and remaining deposit, if held
+     */
+    get_harberger_holding: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<HarbergerHolding>>>;
+    /**
+     * Construct and simulate a get_tax_runway transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the seconds until a Harberger holder's tax deposit runs out
+     */
+    get_tax_runway: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a get_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the open listing for an auction's display rights, if any
+     */
+    get_listing: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<Listing>>>;
+    /**
+     * Construct and simulate a get_listing_royalty transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the platform royalty on display rights sales, in basis points
+     */
+    get_listing_royalty: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a get_accepted_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the tokens accepted for bids besides the contract token
+     */
+    get_accepted_tokens: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<string>>>;
+    /**
+     * Construct and simulate a get_price_oracle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the price oracle used to value bids in accepted tokens, if any
+     */
+    get_price_oracle: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<string>>>;
+    /**
+     * Construct and simulate a get_minimum_token_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the minimum bid for the current auction in units of `token`
+     */
+    get_minimum_token_bid: ({ slot, token }: {
+        slot: string;
+        token: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+     * Construct and simulate a get_fiat_floors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a slot's fiat-denominated bid floors, if configured
+     */
+    get_fiat_floors: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<FiatFloors>>>;
+    /**
+     * Construct and simulate a get_oracle_max_age transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the maximum age in seconds of an oracle price before it is stale
+     */
+    get_oracle_max_age: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a get_downtime transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the downtime intervals reported for an auction's display window
+     */
+    get_downtime: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DowntimeInterval>>>;
+    /**
+     * Construct and simulate a get_total_downtime transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the total seconds of reported downtime for an auction
+     */
+    get_total_downtime: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a get_escrowed_proceeds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the proceeds still held for an auction's display window
+     */
+    get_escrowed_proceeds: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a get_proceeds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get locked, claimable, released and refunded proceeds for an auction
+     */
+    get_proceeds: ({ auction_id }: {
+        auction_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<ProceedsStatus>>;
+    /**
+     * Construct and simulate a get_device_reporter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the address allowed to report device downtime, if any
+     */
+    get_device_reporter: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<string>>>;
+    /**
+     * Construct and simulate a get_scan_reporter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the address allowed to report scans, if any
+     */
+    get_scan_reporter: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<string>>>;
+    /**
+     * Construct and simulate a set_min_bid_increment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set minimum bid increment (only owner)
+     */
+    set_min_bid_increment: ({ slot, new_increment }: {
+        slot: string;
+        new_increment: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_min_starting_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set minimum starting bid (only owner)
+     */
+    set_min_starting_bid: ({ slot, new_starting_bid }: {
+        slot: string;
+        new_starting_bid: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_min_bid_increment_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set percentage-based minimum bid increment in basis points (only owner)
+     *
+     * The effective increment is the larger of the flat increment and this
+     * percentage of the current highest bid. Zero disables it.
+     */
+    set_min_bid_increment_bps: ({ slot, new_bps }: {
+        slot: string;
+        new_bps: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_increment_tiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the tiered minimum bid increment table (only owner)
+     *
+     * Tiers must be sorted by strictly ascending `min_bid`. While a tier
+     * matches the current highest bid its increment replaces the flat one.
+     * An empty table disables tiering.
+     */
+    set_increment_tiers: ({ slot, tiers }: {
+        slot: string;
+        tiers: Array<IncrementTier>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_fallback_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set a single fallback URL shown when no winner is displaying (only owner)
+     */
+    set_fallback_url: ({ slot, url }: {
+        slot: string;
+        url: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_house_ads transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the weighted house ad rotation used as fallback (only owner)
+     *
+     * Entries rotate every `HOUSE_AD_ROTATION_PERIOD`, each receiving a
+     * share of periods proportional to its weight. An empty list clears the
+     * fallback.
+     */
+    set_house_ads: ({ slot, house_ads }: {
+        slot: string;
+        house_ads: Array<HouseAd>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_accepted_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the tokens accepted for bids besides the contract token (only owner)
+     *
+     * Leading bids already placed in a removed token are still refunded
+     * and settled in that token.
+     */
+    set_accepted_tokens: ({ tokens }: {
+        tokens: Array<string>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_price_oracle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the SEP-40 price oracle used to value bids (only owner)
+     */
+    set_price_oracle: ({ oracle }: {
+        oracle: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_oracle_max_age transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the maximum age of an oracle price before it is stale (only owner)
+     */
+    set_oracle_max_age: ({ max_age }: {
+        max_age: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_fiat_floors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Denominate a slot's starting bid and flat increment in the price
+     * oracle's base asset instead of the contract token (only owner)
+     */
+    set_fiat_floors: ({ slot, min_starting_bid, min_bid_increment }: {
+        slot: string;
+        min_starting_bid: i128;
+        min_bid_increment: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a clear_fiat_floors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Go back to the slot's floors in contract token units (only owner)
+     */
+    clear_fiat_floors: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_keeper_reward transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the reward paid to keepers settling expired auctions (only owner)
+     */
+    set_keeper_reward: ({ fixed_amount, bps, max_amount }: {
+        fixed_amount: i128;
+        bps: u32;
+        max_amount: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_scan_reporter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the trusted address that attests scan counts (only owner)
+     */
+    set_scan_reporter: ({ reporter }: {
+        reporter: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_device_reporter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the trusted address that reports display downtime (only owner)
+     */
+    set_device_reporter: ({ reporter }: {
+        reporter: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_linear_vesting transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Vest proceeds linearly over display windows settled from now on
+     * instead of releasing them at window end (only owner)
+     */
+    set_linear_vesting: ({ enabled }: {
+        enabled: boolean;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_harberger_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Switch a slot to Harberger ownership instead of daily auctions, or
+     * change its daily tax rate in basis points of the price (only owner)
+     *
+     * Only possible once no auction is running and every winner display
+     * window has ended.
+     */
+    set_harberger_mode: ({ slot, tax_bps }: {
+        slot: string;
+        tax_bps: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a disable_harberger_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Return a Harberger slot to daily auctions, refunding the holder's
+     * unused tax deposit (only owner)
+     */
+    disable_harberger_mode: ({ slot }: {
+        slot: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_listing_royalty transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the platform royalty taken from display rights sales, in basis
+     * points (only owner)
+     */
+    set_listing_royalty: ({ bps }: {
+        bps: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_continuous_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Enable or disable starting the next auction on settlement (only owner)
+     */
+    set_continuous_mode: ({ slot, enabled }: {
+        slot: string;
+        enabled: boolean;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_max_bid_param transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the ceiling for bid parameters (only owner)
+     */
+    set_max_bid_param: ({ slot, new_ceiling }: {
+        slot: string;
+        new_ceiling: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_platform_wallet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Update platform wallet (only owner)
+     */
+    set_platform_wallet: ({ new_wallet }: {
+        new_wallet: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_upgrader transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Delegate contract upgrades to another address, e.g. a factory (only owner)
+     */
+    set_upgrader: ({ upgrader }: {
+        upgrader: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Replace the contract code (only upgrader, defaults to owner)
+     */
+    upgrade: ({ new_wasm_hash }: {
+        new_wasm_hash: Buffer;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -518,7 +2654,34 @@ export declare class Client extends ContractClient {
     constructor(options: ContractClientOptions);
     readonly fromJSON: {
         initialize: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        create_slot: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        list_slots: (json: string) => AssembledTransaction<string[]>;
         place_bid: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        place_gift_bid: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        place_bid_from: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        place_token_bid: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        place_proxy_bid: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        open_pool: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        contribute: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        withdraw_from_pool: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_qr_metadata: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        update_url: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        appoint_manager: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        revoke_manager: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        transfer_display: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        list_display: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        cancel_listing: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        buy_listing: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        claim_slot: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_harberger_price: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        top_up_tax: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        collect_tax: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        report_scans: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        report_downtime: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        close_display_window: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        claim_proceeds: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        deposit: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        withdraw: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         start_auction: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         end_auction: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         get_qr_url: (json: string) => AssembledTransaction<string>;
@@ -526,6 +2689,8 @@ export declare class Client extends ContractClient {
         has_active_qr_url: (json: string) => AssembledTransaction<boolean>;
         get_qr_url_status: (json: string) => AssembledTransaction<QRStatus>;
         get_qr_url_expiry_time: (json: string) => AssembledTransaction<bigint>;
+        get_current_display: (json: string) => AssembledTransaction<Option<DisplayWindow>>;
+        get_display_schedule: (json: string) => AssembledTransaction<DisplayWindow[]>;
         get_time_remaining: (json: string) => AssembledTransaction<bigint>;
         is_auction_active: (json: string) => AssembledTransaction<boolean>;
         get_current_auction: (json: string) => AssembledTransaction<Auction>;
@@ -533,12 +2698,63 @@ export declare class Client extends ContractClient {
         get_auction: (json: string) => AssembledTransaction<Option<Auction>>;
         get_auction_counter: (json: string) => AssembledTransaction<bigint>;
         get_contract_info: (json: string) => AssembledTransaction<ContractInfo>;
+        get_slot_config: (json: string) => AssembledTransaction<SlotConfig>;
         get_auction_summary: (json: string) => AssembledTransaction<AuctionSummary>;
-        get_minimum_bid: (json: string) => AssembledTransaction<bigint>;
+        get_minimum_bid: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_increment_tiers: (json: string) => AssembledTransaction<IncrementTier[]>;
+        get_bids: (json: string) => AssembledTransaction<BidRecord[]>;
+        get_bid_count: (json: string) => AssembledTransaction<number>;
+        get_first_bid_index: (json: string) => AssembledTransaction<number>;
+        get_bidder_stats: (json: string) => AssembledTransaction<BidderStats>;
+        get_leaderboard: (json: string) => AssembledTransaction<LeaderboardEntry[]>;
+        get_house_ads: (json: string) => AssembledTransaction<HouseAd[]>;
         get_auction_history: (json: string) => AssembledTransaction<Auction[]>;
+        get_scan_reports: (json: string) => AssembledTransaction<ScanBucket[]>;
+        get_total_scans: (json: string) => AssembledTransaction<bigint>;
+        get_cost_per_scan: (json: string) => AssembledTransaction<bigint>;
+        get_balance: (json: string) => AssembledTransaction<bigint>;
+        get_url_manager: (json: string) => AssembledTransaction<Option<string>>;
+        get_display_holder: (json: string) => AssembledTransaction<Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_pool: (json: string) => AssembledTransaction<Option<Pool>>;
+        get_pool_contribution: (json: string) => AssembledTransaction<bigint>;
+        get_harberger_holding: (json: string) => AssembledTransaction<Option<HarbergerHolding>>;
+        get_tax_runway: (json: string) => AssembledTransaction<bigint>;
+        get_listing: (json: string) => AssembledTransaction<Option<Listing>>;
+        get_listing_royalty: (json: string) => AssembledTransaction<number>;
+        get_accepted_tokens: (json: string) => AssembledTransaction<string[]>;
+        get_price_oracle: (json: string) => AssembledTransaction<Option<string>>;
+        get_minimum_token_bid: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_fiat_floors: (json: string) => AssembledTransaction<Option<FiatFloors>>;
+        get_oracle_max_age: (json: string) => AssembledTransaction<bigint>;
+        get_downtime: (json: string) => AssembledTransaction<DowntimeInterval[]>;
+        get_total_downtime: (json: string) => AssembledTransaction<bigint>;
+        get_escrowed_proceeds: (json: string) => AssembledTransaction<bigint>;
+        get_proceeds: (json: string) => AssembledTransaction<ProceedsStatus>;
+        get_device_reporter: (json: string) => AssembledTransaction<Option<string>>;
+        get_scan_reporter: (json: string) => AssembledTransaction<Option<string>>;
         set_min_bid_increment: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         set_min_starting_bid: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_min_bid_increment_bps: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_increment_tiers: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_fallback_url: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_house_ads: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_accepted_tokens: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_price_oracle: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_oracle_max_age: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_fiat_floors: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        clear_fiat_floors: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_keeper_reward: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_scan_reporter: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_device_reporter: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_linear_vesting: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_harberger_mode: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        disable_harberger_mode: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_listing_royalty: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_continuous_mode: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_max_bid_param: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         set_platform_wallet: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_upgrader: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        upgrade: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         transfer_ownership: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
     };
 }
@@ -26,6 +26,40 @@ export const Errors = {
   7: { message: "AlreadyEnded" },
   8: { message: "Unauthorized" },
   9: { message: "AlreadyInitialized" },
+  10: { message: "InvalidAmount" },
+  11: { message: "AmountTooHigh" },
+  12: { message: "IncrementTooHigh" },
+  13: { message: "InvalidBasisPoints" },
+  14: { message: "InvalidTiers" },
+  15: { message: "InvalidHouseAds" },
+  16: { message: "InvalidKeeperReward" },
+  17: { message: "SlotNotFound" },
+  18: { message: "SlotExists" },
+  19: { message: "TooManySlots" },
+  20: { message: "InvalidMetadata" },
+  21: { message: "AuctionNotFound" },
+  22: { message: "DisplayEnded" },
+  23: { message: "InvalidScanBucket" },
+  24: { message: "InvalidDowntime" },
+  25: { message: "DisplayNotEnded" },
+  26: { message: "NothingEscrowed" },
+  27: { message: "TokenNotAccepted" },
+  28: { message: "PriceUnavailable" },
+  29: { message: "InvalidTokens" },
+  30: { message: "OracleStale" },
+  31: { message: "InsufficientBalance" },
+  32: { message: "ListingNotFound" },
+  33: { message: "NotHarbergerSlot" },
+  34: { message: "HarbergerSlot" },
+  35: { message: "AuctionInProgress" },
+  36: { message: "SlotVacant" },
+  37: { message: "PoolNotFound" },
+  38: { message: "TooManyContributors" },
+  39: { message: "NothingToWithdraw" },
+  40: { message: "UrlTooLong" },
+  41: { message: "DisplayScheduled" },
+  42: { message: "BeneficiaryLeading" },
+  43: { message: "PoolAuction" },
 };
 export class Client extends ContractClient {
   options;
@@ -38,33 +72,135 @@ export class Client extends ContractClient {
   constructor(options) {
     super(
       new ContractSpec([
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACQAAAAAAAAAMQXVjdGlvbkVuZGVkAAAAAQAAAAAAAAAPTm9BY3RpdmVBdWN0aW9uAAAAAAIAAAAAAAAACUJpZFRvb0xvdwAAAAAAAAMAAAAAAAAACEVtcHR5VXJsAAAABAAAAAAAAAAOTm9BdWN0aW9uVG9FbmQAAAAAAAUAAAAAAAAAD0F1Y3Rpb25Ob3RFbmRlZAAAAAAGAAAAAAAAAAxBbHJlYWR5RW5kZWQAAAAHAAAAAAAAAAxVbmF1dGhvcml6ZWQAAAAIAAAAAAAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAAk=",
-        "AAAAAQAAAAAAAAAAAAAAB0F1Y3Rpb24AAAAACAAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAALZW5kaW5nX3RpbWUAAAAABgAAAAAAAAALaGlnaGVzdF9iaWQAAAAACwAAAAAAAAAOaGlnaGVzdF9iaWRkZXIAAAAAABMAAAAAAAAACGlzX2VuZGVkAAAAAQAAAAAAAAANcHJlZmVycmVkX3VybAAAAAAAABAAAAAAAAAADXN0YXJ0aW5nX3RpbWUAAAAAAAAGAAAAAAAAAA91cmxfZXhwaXJ5X3RpbWUAAAAABg==",
-        "AAAAAQAAAAAAAAAAAAAACFFSU3RhdHVzAAAAAgAAAAAAAAAGc291cmNlAAAAAAAQAAAAAAAAAAZzdGF0dXMAAAAAABA=",
-        "AAAAAQAAAAAAAAAAAAAADENvbnRyYWN0SW5mbwAAAAYAAAAAAAAAD2F1Y3Rpb25fY291bnRlcgAAAAAGAAAAAAAAABFtaW5fYmlkX2luY3JlbWVudAAAAAAAAAsAAAAAAAAAEG1pbl9zdGFydGluZ19iaWQAAAALAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAD3BsYXRmb3JtX3dhbGxldAAAAAATAAAAAAAAAA10b2tlbl9hZGRyZXNzAAAAAAAAEw==",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAKwAAAAAAAAAMQXVjdGlvbkVuZGVkAAAAAQAAAAAAAAAPTm9BY3RpdmVBdWN0aW9uAAAAAAIAAAAAAAAACUJpZFRvb0xvdwAAAAAAAAMAAAAAAAAACEVtcHR5VXJsAAAABAAAAAAAAAAOTm9BdWN0aW9uVG9FbmQAAAAAAAUAAAAAAAAAD0F1Y3Rpb25Ob3RFbmRlZAAAAAAGAAAAAAAAAAxBbHJlYWR5RW5kZWQAAAAHAAAAAAAAAAxVbmF1dGhvcml6ZWQAAAAIAAAAAAAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAAkAAAAAAAAADUludmFsaWRBbW91bnQAAAAAAAAKAAAAAAAAAA1BbW91bnRUb29IaWdoAAAAAAAACwAAAAAAAAAQSW5jcmVtZW50VG9vSGlnaAAAAAwAAAAAAAAAEkludmFsaWRCYXNpc1BvaW50cwAAAAAADQAAAAAAAAAMSW52YWxpZFRpZXJzAAAADgAAAAAAAAAPSW52YWxpZEhvdXNlQWRzAAAAAA8AAAAAAAAAE0ludmFsaWRLZWVwZXJSZXdhcmQAAAAAEAAAAAAAAAAMU2xvdE5vdEZvdW5kAAAAEQAAAAAAAAAKU2xvdEV4aXN0cwAAAAAAEgAAAAAAAAAMVG9vTWFueVNsb3RzAAAAEwAAAAAAAAAPSW52YWxpZE1ldGFkYXRhAAAAABQAAAAAAAAAD0F1Y3Rpb25Ob3RGb3VuZAAAAAAVAAAAAAAAAAxEaXNwbGF5RW5kZWQAAAAWAAAAAAAAABFJbnZhbGlkU2NhbkJ1Y2tldAAAAAAAABcAAAAAAAAAD0ludmFsaWREb3dudGltZQAAAAAYAAAAAAAAAA9EaXNwbGF5Tm90RW5kZWQAAAAAGQAAAAAAAAAPTm90aGluZ0VzY3Jvd2VkAAAAABoAAAAAAAAAEFRva2VuTm90QWNjZXB0ZWQAAAAbAAAAAAAAABBQcmljZVVuYXZhaWxhYmxlAAAAHAAAAAAAAAANSW52YWxpZFRva2VucwAAAAAAAB0AAAAAAAAAC09yYWNsZVN0YWxlAAAAAB4AAAAAAAAAE0luc3VmZmljaWVudEJhbGFuY2UAAAAAHwAAAAAAAAAPTGlzdGluZ05vdEZvdW5kAAAAACAAAAAAAAAAEE5vdEhhcmJlcmdlclNsb3QAAAAhAAAAAAAAAA1IYXJiZXJnZXJTbG90AAAAAAAAIgAAAAAAAAARQXVjdGlvbkluUHJvZ3Jlc3MAAAAAAAAjAAAAAAAAAApTbG90VmFjYW50AAAAAAAkAAAAAAAAAAxQb29sTm90Rm91bmQAAAAlAAAAAAAAABNUb29NYW55Q29udHJpYnV0b3JzAAAAACYAAAAAAAAAEU5vdGhpbmdUb1dpdGhkcmF3AAAAAAAAJwAAAAAAAAAKVXJsVG9vTG9uZwAAAAAAKAAAAAAAAAAQRGlzcGxheVNjaGVkdWxlZAAAACkAAAAAAAAAEkJlbmVmaWNpYXJ5TGVhZGluZwAAAAAAKgAAAAAAAAALUG9vbEF1Y3Rpb24AAAAAKw==",
+        "AAAAAQAAAT5gaGlnaGVzdF9iaWRgIGlzIGluIHVuaXRzIG9mIHRoZSBjb250cmFjdCB0b2tlbjsgdGhlIGxlYWRpbmcgYmlkIHdhcwphY3R1YWxseSBwYWlkIGJ5IGBiaWRfcGF5ZXJgIGFzIGBiaWRfYW1vdW50YCBvZiBgYmlkX3Rva2VuYCwgcmVzZXJ2ZWQKZnJvbSB0aGUgcGF5ZXIncyBkZXBvc2l0IGJhbGFuY2UgaWYgYGJpZF9mcm9tX2JhbGFuY2VgLiBSZWZ1bmRzIGdvIHRvCmBiaWRfcGF5ZXJgOyBgaGlnaGVzdF9iaWRkZXJgIGNvbnRyb2xzIHRoZSBVUkwgdW50aWwgdGhlIGRpc3BsYXkgcmlnaHRzCm9mIGEgc2V0dGxlZCBhdWN0aW9uIGFyZSB0cmFuc2ZlcnJlZC4AAAAAAAAAAAAHQXVjdGlvbgAAAAAOAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAAAAAApiaWRfYW1vdW50AAAAAAALAAAAAAAAABBiaWRfZnJvbV9iYWxhbmNlAAAAAQAAAAAAAAAJYmlkX3BheWVyAAAAAAAAEwAAAAAAAAAJYmlkX3Rva2VuAAAAAAAAEwAAAAAAAAALZW5kaW5nX3RpbWUAAAAABgAAAAAAAAALaGlnaGVzdF9iaWQAAAAACwAAAAAAAAAOaGlnaGVzdF9iaWRkZXIAAAAAABMAAAAAAAAACGlzX2VuZGVkAAAAAQAAAAAAAAAIbWV0YWRhdGEAAAfQAAAAClFSTWV0YWRhdGEAAAAAAAAAAAANcHJlZmVycmVkX3VybAAAAAAAABAAAAAAAAAABHNsb3QAAAARAAAAAAAAAA1zdGFydGluZ190aW1lAAAAAAAABgAAAAAAAAAPdXJsX2V4cGlyeV90aW1lAAAAAAY=",
+        "AAAAAQAAAD9BZCBjYXJkIGRldGFpbHMgYSB3aW5uZXIgYXR0YWNoZXMgdG8gdGhlaXIgVVJMOyBlbXB0eSB1bnRpbCBzZXQAAAAAAAAAAApRUk1ldGFkYXRhAAAAAAAFAAAAAAAAAAphZHZlcnRpc2VyAAAAAAAQAAAAAAAAAAhjYXRlZ29yeQAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAACmltYWdlX2hhc2gAAAAAA+gAAAPuAAAAIAAAAAAAAAAFdGl0bGUAAAAAAAAQ",
+        "AAAAAQAAADhJbmNyZW1lbnQgYXBwbGllZCBvbmNlIHRoZSBoaWdoZXN0IGJpZCByZWFjaGVzIGBtaW5fYmlkYAAAAAAAAAANSW5jcmVtZW50VGllcgAAAAAAAAIAAAAAAAAACWluY3JlbWVudAAAAAAAAAsAAAAAAAAAB21pbl9iaWQAAAAACw==",
+        "AAAAAQAAAMtFc2Nyb3dlZCBwcm94eSBiaWQ7IHRoZSBjZWlsaW5nIGlzIGtlcHQgb3V0IG9mIGBBdWN0aW9uYCBvbiBwdXJwb3NlCgpQb29scyBiaWQgdGhyb3VnaCBhIHByb3h5IHdob3NlIGNlaWxpbmcgaXMgdGhlIHBvb2xlZCBiYWxhbmNlOyB0aGVpcgplc2Nyb3cgc3RheXMgd2l0aCB0aGUgcG9vbCBpbnN0ZWFkIG9mIGJlaW5nIHJlZnVuZGVkIHRvIGBiaWRkZXJgLgAAAAAAAAAACFByb3h5QmlkAAAABAAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAAptYXhfYW1vdW50AAAAAAALAAAAAAAAAAdwb29sX2lkAAAAA+gAAAAG",
+        "AAAAAQAAAXtDcm93ZGZ1bmRlZCBiaWQgb24gb25lIGF1Y3Rpb24sIHBsYWNlZCBieSB0aGUgY29udHJhY3Qgb24gdGhlIHBvb2wncyBiZWhhbGYKClRoZSBjb250cmFjdCBwYXlzIGFzIGBiaWRfcGF5ZXJgIHdoaWxlIGBjcmVhdG9yYCBpcyB0aGUgYmlkZGVyIHdobwpjb250cm9scyB0aGUgVVJMLCBtZXRhZGF0YSBhbmQgZGlzcGxheSByaWdodHMgaWYgdGhlIHBvb2wgd2lucy4KCmBzcGVudGAgaXMgdGhlIHdpbm5pbmcgYmlkIHBhaWQgZnJvbSB0aGUgcG9vbCBhbmQgYHJlZnVuZGVkYCBhbnkKZG93bnRpbWUgcmVmdW5kIGNyZWRpdGVkIGJhY2sgdG8gaXQ7IGNvbnRyaWJ1dG9ycyB3aXRoZHJhdyB0aGUgcmVzdCBwcm8KcmF0YSBvbmNlIHRoZSBhdWN0aW9uIGlzIHNldHRsZWQuAAAAAAAAAAAEUG9vbAAAAAkAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAAAAAAAB2JhbGFuY2UAAAAACwAAAAAAAAAMY29udHJpYnV0b3JzAAAD6gAAABMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAHcG9vbF9pZAAAAAAGAAAAAAAAAAhyZWZ1bmRlZAAAAAsAAAAAAAAABHNsb3QAAAARAAAAAAAAAAVzcGVudAAAAAAAAAsAAAAAAAAAA3VybAAAAAAQ",
+        "AAAAAQAAAB9BIGNvbnRyaWJ1dG9yJ3Mgc3Rha2UgaW4gYSBwb29sAAAAAAAAAAAJUG9vbFNoYXJlAAAAAAAAAgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAl3aXRoZHJhd24AAAAAAAAL",
+        "AAAAAQAAADBTaW5nbGUgZW50cnkgaW4gYW4gYXVjdGlvbidzIG9uLWNoYWluIGJpZCBsYWRkZXIAAAAAAAAACUJpZFJlY29yZAAAAAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAA1wcmVmZXJyZWRfdXJsAAAAAAAAEAAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAAC9MaWZldGltZSBhZ2dyZWdhdGVzIGZvciBhIHNpbmdsZSBiaWRkZXIgYWRkcmVzcwAAAAAAAAAAC0JpZGRlclN0YXRzAAAAAAUAAAAAAAAADGF1Y3Rpb25zX3dvbgAAAAQAAAAAAAAAC2JpZHNfcGxhY2VkAAAAAAQAAAAAAAAAC2xhc3Rfd2luX2lkAAAAAAYAAAAAAAAACnRvdGFsX3BhaWQAAAAAAAsAAAAAAAAADnRvdGFsX3JlZnVuZGVkAAAAAAAL",
+        "AAAAAQAAAAAAAAAAAAAAEExlYWRlcmJvYXJkRW50cnkAAAACAAAAAAAAAAZiaWRkZXIAAAAAABMAAAAAAAAACnRvdGFsX3BhaWQAAAAAAAs=",
+        "AAAAAQAAAD5Pd25lci1tYW5hZ2VkIGZhbGxiYWNrIFVSTCBzaG93biB3aGlsZSBubyB3aW5uZXIgaXMgZGlzcGxheWluZwAAAAAAAAAAAAdIb3VzZUFkAAAAAAMAAAAAAAAAAmlkAAAAAAAEAAAAAAAAAAN1cmwAAAAAEAAAAAAAAAAGd2VpZ2h0AAAAAAAE",
+        "AAAAAQAAAERQYWlkIGRpc3BsYXkgc2xvdDsgd2luZG93cyBhcmUgcXVldWVkIGJhY2stdG8tYmFjayBhbmQgbmV2ZXIgb3ZlcmxhcAAAAAAAAAANRGlzcGxheVdpbmRvdwAAAAAAAAUAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAAAAAAACGVuZF90aW1lAAAABgAAAAAAAAAGaG9sZGVyAAAAAAATAAAAAAAAAApzdGFydF90aW1lAAAAAAAGAAAAAAAAAAN1cmwAAAAAEA==",
+        "AAAAAQAAAJRSZXdhcmQgcGFpZCBmcm9tIHByb2NlZWRzIHRvIHdob2V2ZXIgc2V0dGxlcyBhbiBleHBpcmVkIGF1Y3Rpb24KClRoZSByZXdhcmQgaXMgYGZpeGVkX2Ftb3VudGAgcGx1cyBgYnBzYCBvZiB0aGUgd2lubmluZyBiaWQsIGNhcHBlZCBhdApgbWF4X2Ftb3VudGAuAAAAAAAAAAxLZWVwZXJSZXdhcmQAAAADAAAAAAAAAANicHMAAAAABAAAAAAAAAAMZml4ZWRfYW1vdW50AAAACwAAAAAAAAAKbWF4X2Ftb3VudAAAAAAACw==",
+        "AAAAAQAAAHxBZGRyZXNzIGEgVVJMIGhvbGRlciBoYXMgZGVsZWdhdGVkIFVSTCBhbmQgbWV0YWRhdGEgZWRpdHMgdG8KCk9ubHkgaG9ub3VyZWQgd2hpbGUgYGhvbGRlcmAgc3RpbGwgY29udHJvbHMgdGhlIGF1Y3Rpb24ncyBVUkwuAAAAAAAAAApVcmxNYW5hZ2VyAAAAAAACAAAAAAAAAAZob2xkZXIAAAAAABMAAAAAAAAAB21hbmFnZXIAAAAAEw==",
+        "AAAAAQAAAQ9Ib2xkZXIgb2YgYSBzbG90IGluIEhhcmJlcmdlciBtb2RlCgpUYXggYWNjcnVlcyBldmVyeSBzZWNvbmQgb24gdGhlIHNlbGYtYXNzZXNzZWQgYHByaWNlYCBzaW5jZQpgYXNzZXNzZWRfYXRgLCBhdCB0aGUgc2xvdCdzIGRhaWx5IHJhdGUuIGB0YXhfcGFpZGAgaXMgd2hhdCBoYXMgYWxyZWFkeQpiZWVuIGNvbGxlY3RlZCBmcm9tIGBkZXBvc2l0YCBzaW5jZSB0aGVuOyB0aGUgaG9sZGluZyBsYXBzZXMgb25jZSB0aGUKYWNjcnVlZCB0YXggZXhoYXVzdHMgdGhlIGRlcG9zaXQuAAAAAAAAAAAQSGFyYmVyZ2VySG9sZGluZwAAAAYAAAAAAAAAC2Fzc2Vzc2VkX2F0AAAAAAYAAAAAAAAAB2RlcG9zaXQAAAAACwAAAAAAAAAGaG9sZGVyAAAAAAATAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAACHRheF9wYWlkAAAACwAAAAAAAAADdXJsAAAAABA=",
+        "AAAAAQAAAK5SZW1haW5pbmcgZGlzcGxheSByaWdodHMgb2YgYSBzZXR0bGVkIGF1Y3Rpb24gb2ZmZXJlZCBmb3Igc2FsZQoKYHByaWNlYCBpcyBpbiB1bml0cyBvZiB0aGUgY29udHJhY3QgdG9rZW4uIFRoZSBsaXN0aW5nIGxhcHNlcyB3aGVuIHRoZQp3aW5kb3cgZW5kcyBvciB0aGUgcmlnaHRzIGNoYW5nZSBoYW5kcy4AAAAAAAAAAAAHTGlzdGluZwAAAAAEAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAAAAAAlsaXN0ZWRfYXQAAAAAAAAGAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAABnNlbGxlcgAAAAAAEw==",
+        "AAAAAQAAAHRTY2FucyBhdHRlc3RlZCBieSB0aGUgcmVwb3J0ZXIgZm9yIG9uZSBob3VyIG9mIGEgZGlzcGxheSB3aW5kb3cKCmBidWNrZXRgIGNvdW50cyBob3VycyBmcm9tIHRoZSBzdGFydCBvZiB0aGUgd2luZG93LgAAAAAAAAAKU2NhbkJ1Y2tldAAAAAAAAwAAAAAAAAAGYnVja2V0AAAAAAAEAAAAAAAAAAtyZXBvcnRlZF9hdAAAAAAGAAAAAAAAAAVzY2FucwAAAAAAAAQ=",
+        "AAAAAQAAADlQZXJpb2QgZHVyaW5nIHdoaWNoIGEgc2xvdCdzIHBoeXNpY2FsIGRpc3BsYXkgd2FzIG9mZmxpbmUAAAAAAAAAAAAAEERvd250aW1lSW50ZXJ2YWwAAAACAAAAAAAAAAhlbmRfdGltZQAAAAYAAAAAAAAACnN0YXJ0X3RpbWUAAAAAAAY=",
+        "AAAAAQAAAMtXaW5uaW5nIHByb2NlZWRzIGhlbGQgdW50aWwgdGhlIGF1Y3Rpb24ncyBkaXNwbGF5IHdpbmRvdyBlbmRzCgpXaXRoIGBsaW5lYXJfdmVzdGluZ2AgdGhlIHBsYXRmb3JtIG1heSBjbGFpbSBwcm9jZWVkcyBhcyBkaXNwbGF5IHRpbWUKaXMgZGVsaXZlcmVkOyBvdGhlcndpc2UgZXZlcnl0aGluZyBpcyByZWxlYXNlZCB3aGVuIHRoZSB3aW5kb3cgY2xvc2VzLgAAAAAAAAAADlByb2NlZWRzRXNjcm93AAAAAAAFAAAAAAAAAA5saW5lYXJfdmVzdGluZwAAAAAAAQAAAAAAAAAIcmVmdW5kZWQAAAALAAAAAAAAAAhyZWxlYXNlZAAAAAsAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAFdG90YWwAAAAAAAAL",
+        "AAAAAQAAADdMb2NrZWQgdnMgcmVsZWFzZWQgYWNjb3VudGluZyBmb3IgYW4gYXVjdGlvbidzIHByb2NlZWRzAAAAAAAAAAAOUHJvY2VlZHNTdGF0dXMAAAAAAAUAAAAAAAAACWNsYWltYWJsZQAAAAAAAAsAAAAAAAAABmxvY2tlZAAAAAAACwAAAAAAAAAIcmVmdW5kZWQAAAALAAAAAAAAAAhyZWxlYXNlZAAAAAsAAAAAAAAABXRvdGFsAAAAAAAACw==",
+        "AAAAAQAAAAAAAAAAAAAACFFSU3RhdHVzAAAABAAAAAAAAAALZmFsbGJhY2tfaWQAAAAD6AAAAAQAAAAAAAAACG1ldGFkYXRhAAAH0AAAAApRUk1ldGFkYXRhAAAAAAAAAAAABnNvdXJjZQAAAAAAEAAAAAAAAAAGc3RhdHVzAAAAAAAQ",
+        "AAAAAQAAAAAAAAAAAAAADENvbnRyYWN0SW5mbwAAAAYAAAAAAAAAD2F1Y3Rpb25fY291bnRlcgAAAAAGAAAAAAAAAA1rZWVwZXJfcmV3YXJkAAAAAAAH0AAAAAxLZWVwZXJSZXdhcmQAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAPcGxhdGZvcm1fd2FsbGV0AAAAABMAAAAAAAAABXNsb3RzAAAAAAAD6gAAABEAAAAAAAAADXRva2VuX2FkZHJlc3MAAAAAAAAT",
+        "AAAAAQAAAAAAAAAAAAAAClNsb3RDb25maWcAAAAAAAcAAAAAAAAAD2NvbnRpbnVvdXNfbW9kZQAAAAABAAAAAAAAABFoYXJiZXJnZXJfdGF4X2JwcwAAAAAAA+gAAAAEAAAAAAAAAA9pbmNyZW1lbnRfdGllcnMAAAAD6gAAB9AAAAANSW5jcmVtZW50VGllcgAAAAAAAAAAAAANbWF4X2JpZF9wYXJhbQAAAAAAAAsAAAAAAAAAEW1pbl9iaWRfaW5jcmVtZW50AAAAAAAACwAAAAAAAAAVbWluX2JpZF9pbmNyZW1lbnRfYnBzAAAAAAAABAAAAAAAAAAQbWluX3N0YXJ0aW5nX2JpZAAAAAs=",
         "AAAAAQAAAAAAAAAAAAAADkF1Y3Rpb25TdW1tYXJ5AAAAAAAGAAAAAAAAAA9jdXJyZW50X2F1Y3Rpb24AAAAH0AAAAAdBdWN0aW9uAAAAAAAAAAANaGFzX2FjdGl2ZV9xcgAAAAAAAAEAAAAAAAAACWlzX2FjdGl2ZQAAAAAAAAEAAAAAAAAADGxhc3RfYXVjdGlvbgAAB9AAAAAHQXVjdGlvbgAAAAAAAAAABnFyX3VybAAAAAAAEAAAAAAAAAAOdGltZV9yZW1haW5pbmcAAAAAAAY=",
+        "AAAAAgAAADNBc3NldCBpZGVudGlmaWVyIGluIHRoZSBTRVAtNDAgcHJpY2UgZmVlZCBpbnRlcmZhY2UAAAAAAAAAAAVBc3NldAAAAAAAAAIAAAABAAAAAAAAAAdTdGVsbGFyAAAAAAEAAAATAAAAAQAAAAAAAAAFT3RoZXIAAAAAAAABAAAAEQ==",
+        "AAAAAQAAAC9QcmljZSByZWNvcmQgaW4gdGhlIFNFUC00MCBwcmljZSBmZWVkIGludGVyZmFjZQAAAAAAAAAACVByaWNlRGF0YQAAAAAAAAIAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAAMlTdGFydGluZyBiaWQgYW5kIGZsYXQgaW5jcmVtZW50IGluIHRoZSBwcmljZSBvcmFjbGUncyBiYXNlIGFzc2V0CgpBbW91bnRzIGFyZSBzY2FsZWQgYnkgdGhlIG9yYWNsZSdzIGRlY2ltYWxzIGFuZCBjb252ZXJ0ZWQgdG8gdGhlCmNvbnRyYWN0IHRva2VuIGF0IHRoZSBsYXRlc3QgcHJpY2Ugd2hlbmV2ZXIgYSBtaW5pbXVtIGJpZCBpcyBjb21wdXRlZC4AAAAAAAAAAAAACkZpYXRGbG9vcnMAAAAAAAIAAAAAAAAAEW1pbl9iaWRfaW5jcmVtZW50AAAAAAAACwAAAAAAAAAQbWluX3N0YXJ0aW5nX2JpZAAAAAs=",
         "AAAAAAAAABdJbml0aWFsaXplIHRoZSBjb250cmFjdAAAAAAKaW5pdGlhbGl6ZQAAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAA9wbGF0Zm9ybV93YWxsZXQAAAAAEwAAAAAAAAANdG9rZW5fYWRkcmVzcwAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAACJQbGFjZSBhIGJpZCBvbiB0aGUgY3VycmVudCBhdWN0aW9uAAAAAAAJcGxhY2VfYmlkAAAAAAAAAwAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAADXByZWZlcnJlZF91cmwAAAAAAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAACBTdGFydCBhIG5ldyBhdWN0aW9uIChvbmx5IG93bmVyKQAAAA1zdGFydF9hdWN0aW9uAAAAAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAABdFbmQgdGhlIGN1cnJlbnQgYXVjdGlvbgAAAAALZW5kX2F1Y3Rpb24AAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAABlHZXQgdGhlIFFSIFVSTCB0byBkaXNwbGF5AAAAAAAACmdldF9xcl91cmwAAAAAAAAAAAABAAAAEA==",
-        "AAAAAAAAAChHZXQgY3VycmVudCBhdWN0aW9uIFVSTCAoZHVyaW5nIGJpZGRpbmcpAAAAF2dldF9jdXJyZW50X2F1Y3Rpb25fdXJsAAAAAAAAAAABAAAAEA==",
-        "AAAAAAAAACFDaGVjayBpZiB0aGVyZSdzIGFuIGFjdGl2ZSBRUiBVUkwAAAAAAAARaGFzX2FjdGl2ZV9xcl91cmwAAAAAAAAAAAAAAQAAAAE=",
-        "AAAAAAAAABxHZXQgUVIgVVJMIHN0YXR1cyBhbmQgc291cmNlAAAAEWdldF9xcl91cmxfc3RhdHVzAAAAAAAAAAAAAAEAAAfQAAAACFFSU3RhdHVz",
-        "AAAAAAAAABZHZXQgUVIgVVJMIGV4cGlyeSB0aW1lAAAAAAAWZ2V0X3FyX3VybF9leHBpcnlfdGltZQAAAAAAAAAAAAEAAAAG",
-        "AAAAAAAAACVHZXQgdGltZSByZW1haW5pbmcgaW4gY3VycmVudCBhdWN0aW9uAAAAAAAAEmdldF90aW1lX3JlbWFpbmluZwAAAAAAAAAAAAEAAAAG",
-        "AAAAAAAAACRDaGVjayBpZiBhdWN0aW9uIGlzIGN1cnJlbnRseSBhY3RpdmUAAAARaXNfYXVjdGlvbl9hY3RpdmUAAAAAAAAAAAAAAQAAAAE=",
-        "AAAAAAAAABtHZXQgY3VycmVudCBhdWN0aW9uIGRldGFpbHMAAAAAE2dldF9jdXJyZW50X2F1Y3Rpb24AAAAAAAAAAAEAAAfQAAAAB0F1Y3Rpb24A",
-        "AAAAAAAAABpHZXQgbGFzdCBjb21wbGV0ZWQgYXVjdGlvbgAAAAAAEGdldF9sYXN0X2F1Y3Rpb24AAAAAAAAAAQAAB9AAAAAHQXVjdGlvbgA=",
-        "AAAAAAAAAE1HZXQgc3BlY2lmaWMgYXVjdGlvbiBieSBJRCAobGltaXRlZCBpbXBsZW1lbnRhdGlvbiBkdWUgdG8gZm9ybWF0ISBjb25zdHJhaW50KQAAAAAAAAtnZXRfYXVjdGlvbgAAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+gAAAfQAAAAB0F1Y3Rpb24A",
+        "AAAAAAAAAD9SZWdpc3RlciBhIG5ldyBRUiBzbG90LCBvbmUgcGVyIHBoeXNpY2FsIHBsYWNlbWVudCAob25seSBvd25lcikAAAAAC2NyZWF0ZV9zbG90AAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAABlMaXN0IGFsbCByZWdpc3RlcmVkIHNsb3RzAAAAAAAACmxpc3Rfc2xvdHMAAAAAAAAAAAABAAAD6gAAABE=",
+        "AAAAAAAAASlQbGFjZSBhIGJpZCBvbiB0aGUgY3VycmVudCBhdWN0aW9uCgpJZiBhbm90aGVyIGJpZGRlciBob2xkcyBhIHByb3h5IGJpZCB3aXRoIGEgY2VpbGluZyBhdCBvciBhYm92ZQpgYW1vdW50YCwgdGhlIHByb3h5IGNvdW50ZXJzIGF1dG9tYXRpY2FsbHk6IHRoZSB2aXNpYmxlIHByaWNlIHJpc2VzCmJ1dCB0aGUgcHJveHkgYmlkZGVyIHN0YXlzIGluIHRoZSBsZWFkIGFuZCBub3RoaW5nIGlzIHRha2VuIGZyb20KYGJpZGRlcmAsIHdobyBtdXN0IHN0aWxsIGhvbGQgYGFtb3VudGAgZm9yIHRoZSBjb3VudGVyIHRvIGhhcHBlbi4AAAAAAAAJcGxhY2VfYmlkAAAAAAAABAAAAAAAAAAEc2xvdAAAABEAAAAAAAAABmJpZGRlcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAA1wcmVmZXJyZWRfdXJsAAAAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAOBQbGFjZSBhIGJpZCBwYWlkIGJ5IGBwYXllcmAgb24gYmVoYWxmIG9mIGBiZW5lZmljaWFyeWAKCkxldHMgYSBjb21wYW55IGJ1eSBhIHNsb3QgZm9yIHNvbWVvbmUgZWxzZTogYGJlbmVmaWNpYXJ5YCBjb250cm9scwp0aGUgVVJMIGFuZCBpdHMgbWV0YWRhdGEsIHdoaWxlIHJlZnVuZHMgZ28gYmFjayB0byBgcGF5ZXJgLgpHaWZ0aW5nIHRvIHRoZSBjdXJyZW50IGxlYWRlciBpcyByZWZ1c2VkLgAAAA5wbGFjZV9naWZ0X2JpZAAAAAAABQAAAAAAAAAEc2xvdAAAABEAAAAAAAAABXBheWVyAAAAAAAAEwAAAAAAAAALYmVuZWZpY2lhcnkAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAA1wcmVmZXJyZWRfdXJsAAAAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAARVQbGFjZSBhIGJpZCBmdW5kZWQgZnJvbSBgcGF5ZXJgJ3MgdG9rZW4gYWxsb3dhbmNlIHRvIGBzcGVuZGVyYAoKTGV0cyBhbiBhZ2VuY3kgb3IgcmVsYXllciBiaWQgb24gYSBjbGllbnQncyBiZWhhbGY6IGBiZW5lZmljaWFyeWAKY29udHJvbHMgdGhlIFVSTCBpZiB0aGUgYmlkIHdpbnMsIGFuZCBgcGF5ZXJgIGlzIHJlZnVuZGVkIGlmIGl0IGlzCm91dGJpZC4gQmlkZGluZyBmb3IgdGhlIGN1cnJlbnQgbGVhZGVyIHdpdGggc29tZW9uZSBlbHNlJ3MKYWxsb3dhbmNlIGlzIHJlZnVzZWQuAAAAAAAADnBsYWNlX2JpZF9mcm9tAAAAAAAGAAAAAAAAAARzbG90AAAAEQAAAAAAAAAHc3BlbmRlcgAAAAATAAAAAAAAAAVwYXllcgAAAAAAABMAAAAAAAAAC2JlbmVmaWNpYXJ5AAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAANcHJlZmVycmVkX3VybAAAAAAAABAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAANZQbGFjZSBhIGJpZCBwYWlkIGluIG9uZSBvZiB0aGUgYWNjZXB0ZWQgdG9rZW5zCgpgYW1vdW50YCBpcyBpbiB1bml0cyBvZiBgdG9rZW5gOyBpdCBpcyBjb252ZXJ0ZWQgdG8gdGhlIGNvbnRyYWN0CnRva2VuJ3MgdW5pdHMgdGhyb3VnaCB0aGUgcHJpY2Ugb3JhY2xlIHRvIHJhbmsgYWdhaW5zdCBvdGhlciBiaWRzLAphbmQgcmVmdW5kZWQgaW4gYHRva2VuYCBpZiBvdXRiaWQuAAAAAAAPcGxhY2VfdG9rZW5fYmlkAAAAAAUAAAAAAAAABHNsb3QAAAARAAAAAAAAAAZiaWRkZXIAAAAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAA1wcmVmZXJyZWRfdXJsAAAAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAATxQbGFjZSBhIHByb3h5IChtYXhpbXVtKSBiaWQgb24gdGhlIGN1cnJlbnQgYXVjdGlvbgoKVGhlIGZ1bGwgYG1heF9hbW91bnRgIGlzIGVzY3Jvd2VkIGFuZCB0aGUgY29udHJhY3QgYmlkcyBvbiB0aGUKYmlkZGVyJ3MgYmVoYWxmIHVwIHRvIHRoYXQgY2VpbGluZywgb25seSBldmVyIHBheWluZyBvbmUgaW5jcmVtZW50Cm92ZXIgdGhlIG5leHQgYmVzdCBiaWQuIFRoZSBjZWlsaW5nIGl0c2VsZiBpcyBuZXZlciBleHBvc2VkIHRocm91Z2gKYEF1Y3Rpb25gOyB1bnVzZWQgZXNjcm93IGlzIHJlZnVuZGVkIGF0IHNldHRsZW1lbnQgb3Igd2hlbiBvdXRiaWQuAAAAD3BsYWNlX3Byb3h5X2JpZAAAAAAEAAAAAAAAAARzbG90AAAAEQAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAAptYXhfYW1vdW50AAAAAAALAAAAAAAAAA1wcmVmZXJyZWRfdXJsAAAAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAMxPcGVuIGEgY3Jvd2RmdW5kZWQgcG9vbCBiaWRkaW5nIGZvciBgdXJsYCBvbiBhIHNsb3QncyBjdXJyZW50IGF1Y3Rpb24KClRoZSBVUkwgaXMgZml4ZWQ6IHdoaWxlIHRoZSBwb29sIGxlYWRzIGFuZCBvbmNlIGl0IHdpbnMsIGl0cyBVUkwsCm1ldGFkYXRhIGFuZCBkaXNwbGF5IHJpZ2h0cyBjYW5ub3QgYmUgY2hhbmdlZCwgdHJhbnNmZXJyZWQgb3Igc29sZC4AAAAJb3Blbl9wb29sAAAAAAAAAwAAAAAAAAAEc2xvdAAAABEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAADdXJsAAAAABAAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAQlDb250cmlidXRlIGNvbnRyYWN0IHRva2VucyB0byBhIHBvb2wKClRoZSBwb29sIGJpZHMgYXMgYSBzaW5nbGUgYmlkZGVyLCBkZWZlbmRpbmcgaXRzIGxlYWQgd2l0aCB0aGUgcG9vbGVkCmJhbGFuY2UgYXMgaXRzIGNlaWxpbmcgYW5kIG9ubHkgcGF5aW5nIG9uZSBpbmNyZW1lbnQgb3ZlciB0aGUgbmV4dApiZXN0IGJpZC4gRWFjaCBjb250cmlidXRpb24gcmFpc2VzIHRoZSBjZWlsaW5nIGFuZCwgaWYgdGhlIHBvb2wgaXMKbm90IGxlYWRpbmcsIGJpZHMgYWdhaW4uAAAAAAAACmNvbnRyaWJ1dGUAAAAAAAMAAAAAAAAAB3Bvb2xfaWQAAAAABgAAAAAAAAALY29udHJpYnV0b3IAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAARBXaXRoZHJhdyBhIGNvbnRyaWJ1dG9yJ3MgcHJvIHJhdGEgc2hhcmUgb2Ygd2hhdCBhIHBvb2wgZGlkIG5vdCBzcGVuZAoKQXZhaWxhYmxlIG9uY2UgdGhlIHBvb2wncyBhdWN0aW9uIGlzIHNldHRsZWQ6IHRoZSBmdWxsIGNvbnRyaWJ1dGlvbgppZiB0aGUgcG9vbCBsb3N0LCB0aGUgdW5zcGVudCByZW1haW5kZXIgaWYgaXQgd29uLiBEb3dudGltZSByZWZ1bmRzCm9uIGEgd2lubmluZyBwb29sJ3MgZGlzcGxheSBjYW4gYmUgd2l0aGRyYXduIGFzIHRoZXkgYXJlIGNyZWRpdGVkLgAAABJ3aXRoZHJhd19mcm9tX3Bvb2wAAAAAAAIAAAAAAAAAB3Bvb2xfaWQAAAAABgAAAAAAAAALY29udHJpYnV0b3IAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAASBBdHRhY2ggYWQgY2FyZCBtZXRhZGF0YSB0byBhbiBhdWN0aW9uJ3MgVVJMCgpDYWxsYWJsZSBieSB0aGUgbGVhZGluZyBiaWRkZXIgd2hpbGUgYmlkZGluZyBpcyBvcGVuIGFuZCBieSB0aGUKd2lubmVyIHVudGlsIHRoZWlyIGRpc3BsYXkgd2luZG93IGVuZHMsIG9yIGJ5IHRoZWlyIFVSTCBtYW5hZ2VyLgpNZXRhZGF0YSBpcyBjbGVhcmVkIHdoZW5ldmVyIGEgZGlmZmVyZW50IGJpZGRlciB0YWtlcyB0aGUgbGVhZC4KQSBwb29sJ3MgYmlkIGtlZXBzIHRoZSBjb250ZW50IGl0IHdhcyBvcGVuZWQgd2l0aC4AAAAPc2V0X3FyX21ldGFkYXRhAAAAAAQAAAAAAAAABHNsb3QAAAARAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACG1ldGFkYXRhAAAH0AAAAApRUk1ldGFkYXRhAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAGhDaGFuZ2UgdGhlIFVSTCBvZiBhIGxlYWRpbmcgYmlkIG9yIG9mIGEgd2lubmVyJ3MgZGlzcGxheSB3aW5kb3cKClNhbWUgYWNjZXNzIHJ1bGVzIGFzIGBzZXRfcXJfbWV0YWRhdGFgLgAAAAp1cGRhdGVfdXJsAAAAAAAEAAAAAAAAAARzbG90AAAAEQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAN1cmwAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAIVMZXQgYG1hbmFnZXJgIGVkaXQgdGhlIFVSTCBhbmQgbWV0YWRhdGEgb2YgYW4gYXVjdGlvbiB0aGUgY2FsbGVyCmxlYWRzIG9yIGhvbGRzIHRoZSBkaXNwbGF5IHJpZ2h0cyBvZiwgcmVwbGFjaW5nIGFueSBwcmV2aW91cyBtYW5hZ2VyAAAAAAAAD2FwcG9pbnRfbWFuYWdlcgAAAAADAAAAAAAAAARzbG90AAAAEQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAHbWFuYWdlcgAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAFRSZXZva2UgdGhlIFVSTCBtYW5hZ2VyIG9mIGFuIGF1Y3Rpb24gdGhlIGNhbGxlciBsZWFkcyBvciBob2xkcyB0aGUKZGlzcGxheSByaWdodHMgb2YAAAAOcmV2b2tlX21hbmFnZXIAAAAAAAIAAAAAAAAABHNsb3QAAAARAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAARNUcmFuc2ZlciB0aGUgcmVtYWluaW5nIGRpc3BsYXkgcmlnaHRzIG9mIGEgc2V0dGxlZCBhdWN0aW9uCgpUaGUgbmV3IGhvbGRlciBjb250cm9scyB0aGUgVVJMIGFuZCBtZXRhZGF0YSB1bnRpbCB0aGUgd2luZG93IGVuZHM7CmFueSBtYW5hZ2VyIGFwcG9pbnRlZCBieSB0aGUgcHJldmlvdXMgaG9sZGVyIGxvc2VzIGFjY2Vzcy4gUmVmdW5kcwphbmQgcHJvY2VlZHMgYXJlIHVuYWZmZWN0ZWQuIEEgcG9vbCdzIGRpc3BsYXkgcmlnaHRzIHN0YXkgd2l0aCBpdHMKY29udHJpYnV0b3JzLgAAAAAQdHJhbnNmZXJfZGlzcGxheQAAAAIAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAAAAAAAAnRvAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAKVMaXN0IHRoZSByZW1haW5pbmcgZGlzcGxheSByaWdodHMgb2YgYSBzZXR0bGVkIGF1Y3Rpb24gZm9yIHNhbGUgYXQKYHByaWNlYCBjb250cmFjdCB0b2tlbnMsIHJlcGxhY2luZyBhbnkgcHJldmlvdXMgbGlzdGluZwoKTm90IGF2YWlsYWJsZSBmb3IgYXVjdGlvbnMgd29uIGJ5IGEgcG9vbC4AAAAAAAAMbGlzdF9kaXNwbGF5AAAAAgAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAC9XaXRoZHJhdyBhIGRpc3BsYXkgcmlnaHRzIGxpc3RpbmcgKG9ubHkgc2VsbGVyKQAAAAAOY2FuY2VsX2xpc3RpbmcAAAAAAAEAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAI5CdXkgbGlzdGVkIGRpc3BsYXkgcmlnaHRzIGF0IHRoZSBhc2sgcHJpY2UKClRoZSBzZWxsZXIgcmVjZWl2ZXMgdGhlIHByaWNlIG1pbnVzIHRoZSBwbGF0Zm9ybSByb3lhbHR5IGFuZCB0aGUKYnV5ZXIgYmVjb21lcyB0aGUgZGlzcGxheSBob2xkZXIuAAAAAAALYnV5X2xpc3RpbmcAAAAAAgAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAP5UYWtlIG92ZXIgYSBIYXJiZXJnZXIgc2xvdAoKVGhlIGJ1eWVyIHBheXMgdGhlIGN1cnJlbnQgaG9sZGVyIHRoZWlyIHNlbGYtYXNzZXNzZWQgcHJpY2UsIGFuZCB0aGUKaG9sZGVyJ3MgdW51c2VkIHRheCBkZXBvc2l0IGlzIHJlZnVuZGVkIHRvIHRoZW0uIEEgdmFjYW50IHNsb3QgaXMKZnJlZSB0byBjbGFpbS4gVGhlIGJ1eWVyIHNldHMgdGhlaXIgb3duIHByaWNlIGFuZCBwcmVwYXlzIGBkZXBvc2l0YApjb250cmFjdCB0b2tlbnMgb2YgdGF4LgAAAAAACmNsYWltX3Nsb3QAAAAAAAUAAAAAAAAABHNsb3QAAAARAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAADdXJsAAAAABAAAAAAAAAAB2RlcG9zaXQAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAADpTZWxmLWFzc2VzcyBhIG5ldyBwcmljZSBmb3IgYSBIYXJiZXJnZXIgc2xvdCAob25seSBob2xkZXIpAAAAAAATc2V0X2hhcmJlcmdlcl9wcmljZQAAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADhBZGQgdG8gdGhlIHRheCBkZXBvc2l0IG9mIGEgSGFyYmVyZ2VyIHNsb3QgKG9ubHkgaG9sZGVyKQAAAAp0b3BfdXBfdGF4AAAAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAINQYXkgdGhlIHRheCBhY2NydWVkIG9uIGEgSGFyYmVyZ2VyIHNsb3QgdG8gdGhlIHBsYXRmb3JtIHdhbGxldCwKdmFjYXRpbmcgdGhlIHNsb3QgaWYgdGhlIGRlcG9zaXQgaGFzIHJ1biBvdXQuIEFueW9uZSBtYXkgY2FsbCB0aGlzLgAAAAALY29sbGVjdF90YXgAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAL9SZXBvcnQgdGhlIHNjYW5zIGNvdW50ZWQgZHVyaW5nIG9uZSBob3VyIG9mIGEgc2V0dGxlZCBhdWN0aW9uJ3MKZGlzcGxheSB3aW5kb3cgKG9ubHkgc2NhbiByZXBvcnRlcikKCkJ1Y2tldHMgbXVzdCBiZSByZXBvcnRlZCBpbiBpbmNyZWFzaW5nIG9yZGVyIGFuZCBvbmx5IG9uY2UgdGhlIGhvdXIKdGhleSBjb3ZlciBoYXMgcGFzc2VkLgAAAAAMcmVwb3J0X3NjYW5zAAAAAwAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAGYnVja2V0AAAAAAAEAAAAAAAAAAVzY2FucwAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAL9SZWNvcmQgYSBwZXJpb2QgdGhlIHNsb3QncyBkaXNwbGF5IHdhcyBvZmZsaW5lIGR1cmluZyBhIHNldHRsZWQKYXVjdGlvbidzIHdpbmRvdyAob25seSBkZXZpY2UgcmVwb3J0ZXIpCgpJbnRlcnZhbHMgYXJlIGNsaXBwZWQgdG8gdGhlIHdpbmRvdyBhbmQgbXVzdCBiZSByZXBvcnRlZCBpbiBvcmRlcgp3aXRob3V0IG92ZXJsYXBwaW5nLgAAAAAPcmVwb3J0X2Rvd250aW1lAAAAAAMAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAAAAAAACnN0YXJ0X3RpbWUAAAAAAAYAAAAAAAAACGVuZF90aW1lAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAOlSZWxlYXNlIGFuIGF1Y3Rpb24ncyBlc2Nyb3dlZCBwcm9jZWVkcyBvbmNlIGl0cyBkaXNwbGF5IHdpbmRvdyBlbmRzCgpUaGUgd2lubmVyIGlzIHJlZnVuZGVkIHBybyByYXRhIGZvciByZXBvcnRlZCBkb3dudGltZSwgbGVzcyBhbnl0aGluZwphbHJlYWR5IHZlc3RlZCB0byB0aGUgcGxhdGZvcm0sIGFuZCB0aGUgcmVzdCBnb2VzIHRvIHRoZSBwbGF0Zm9ybQp3YWxsZXQuIEFueW9uZSBtYXkgY2FsbCB0aGlzLgAAAAAAABRjbG9zZV9kaXNwbGF5X3dpbmRvdwAAAAEAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAQNQYXkgdGhlIHBsYXRmb3JtIHdoYXRldmVyIGhhcyB2ZXN0ZWQgb2YgYW4gYXVjdGlvbidzIHByb2NlZWRzCgpPbmx5IGxpbmVhcmx5IHZlc3RpbmcgZXNjcm93cyByZWxlYXNlIGFueXRoaW5nIGJlZm9yZSB0aGUgd2luZG93CmVuZHM7IGFmdGVyIHRoYXQgdGhpcyBjbG9zZXMgdGhlIHdpbmRvdyBsaWtlIGBjbG9zZV9kaXNwbGF5X3dpbmRvd2AuCkFueW9uZSBtYXkgY2FsbCB0aGlzLiBSZXR1cm5zIHRoZSBhbW91bnQgcGFpZCB0byB0aGUgcGxhdGZvcm0uAAAAAA5jbGFpbV9wcm9jZWVkcwAAAAAAAQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAKxEZXBvc2l0IGNvbnRyYWN0IHRva2VucyBpbnRvIHRoZSBjYWxsZXIncyBpbnRlcm5hbCBiYWxhbmNlCgpCaWRzIGNvdmVyZWQgYnkgdGhlIGJhbGFuY2UgYXJlIHJlc2VydmVkIGZyb20gaXQgaW5zdGVhZCBvZiBiZWluZwp0cmFuc2ZlcnJlZCwgYW5kIHJlZnVuZGVkIGludG8gaXQgd2hlbiBvdXRiaWQuAAAAB2RlcG9zaXQAAAAAAgAAAAAAAAAEZnJvbQAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAADtXaXRoZHJhdyBjb250cmFjdCB0b2tlbnMgZnJvbSB0aGUgY2FsbGVyJ3MgaW50ZXJuYWwgYmFsYW5jZQAAAAAId2l0aGRyYXcAAAACAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAACBTdGFydCBhIG5ldyBhdWN0aW9uIChvbmx5IG93bmVyKQAAAA1zdGFydF9hdWN0aW9uAAAAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAM9FbmQgdGhlIGN1cnJlbnQgYXVjdGlvbgoKQW55b25lIG1heSBzZXR0bGUgYW4gZXhwaXJlZCBhdWN0aW9uLiBUaGUgYGtlZXBlcmAgcmVjZWl2ZXMgdGhlCmNvbmZpZ3VyZWQga2VlcGVyIHJld2FyZCBvdXQgb2YgdGhlIHdpbm5pbmcgYmlkIGFuZCwgaW4gY29udGludW91cwptb2RlLCB0aGUgbmV4dCBhdWN0aW9uIGlzIHN0YXJ0ZWQgaW4gdGhlIHNhbWUgY2FsbC4AAAAAC2VuZF9hdWN0aW9uAAAAAAIAAAAAAAAABHNsb3QAAAARAAAAAAAAAAZrZWVwZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAIpHZXQgdGhlIFFSIFVSTCB0byBkaXNwbGF5CgpGYWxscyBiYWNrIHRvIHRoZSBIYXJiZXJnZXIgaG9sZGVyJ3MgVVJMLCB0aGVuIHRvIHRoZQpvd25lci1jb25maWd1cmVkIGhvdXNlIGFkcyB3aGlsZSBubyB3aW5uZXIgaXMgZGlzcGxheWluZy4AAAAAAApnZXRfcXJfdXJsAAAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAAQ",
+        "AAAAAAAAAChHZXQgY3VycmVudCBhdWN0aW9uIFVSTCAoZHVyaW5nIGJpZGRpbmcpAAAAF2dldF9jdXJyZW50X2F1Y3Rpb25fdXJsAAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAABA=",
+        "AAAAAAAAADxDaGVjayBpZiB0aGVyZSdzIGFuIGFjdGl2ZSB3aW5uZXIgb3IgSGFyYmVyZ2VyIGhvbGRlciBRUiBVUkwAAAARaGFzX2FjdGl2ZV9xcl91cmwAAAAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAAB",
+        "AAAAAAAAABxHZXQgUVIgVVJMIHN0YXR1cyBhbmQgc291cmNlAAAAEWdldF9xcl91cmxfc3RhdHVzAAAAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAH0AAAAAhRUlN0YXR1cw==",
+        "AAAAAAAAABZHZXQgUVIgVVJMIGV4cGlyeSB0aW1lAAAAAAAWZ2V0X3FyX3VybF9leHBpcnlfdGltZQAAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAABg==",
+        "AAAAAAAAAH9HZXQgdGhlIGRpc3BsYXkgd2luZG93IGN1cnJlbnRseSBiZWluZyBzZXJ2ZWQsIGlmIGFueQoKSW5jbHVkZXMgdGhlIHdpbmRvdyBvZiBhbiBleHBpcmVkIGF1Y3Rpb24gdGhhdCBoYXMgbm90IGJlZW4gc2V0dGxlZCB5ZXQuAAAAABNnZXRfY3VycmVudF9kaXNwbGF5AAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+gAAAfQAAAADURpc3BsYXlXaW5kb3cAAAA=",
+        "AAAAAAAAADVHZXQgdGhlIGN1cnJlbnQgYW5kIHVwY29taW5nIGRpc3BsYXkgd2luZG93cyBpbiBvcmRlcgAAAAAAABRnZXRfZGlzcGxheV9zY2hlZHVsZQAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+oAAAfQAAAADURpc3BsYXlXaW5kb3cAAAA=",
+        "AAAAAAAAACVHZXQgdGltZSByZW1haW5pbmcgaW4gY3VycmVudCBhdWN0aW9uAAAAAAAAEmdldF90aW1lX3JlbWFpbmluZwAAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAABg==",
+        "AAAAAAAAACRDaGVjayBpZiBhdWN0aW9uIGlzIGN1cnJlbnRseSBhY3RpdmUAAAARaXNfYXVjdGlvbl9hY3RpdmUAAAAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAAB",
+        "AAAAAAAAABtHZXQgY3VycmVudCBhdWN0aW9uIGRldGFpbHMAAAAAE2dldF9jdXJyZW50X2F1Y3Rpb24AAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAH0AAAAAdBdWN0aW9uAA==",
+        "AAAAAAAAABpHZXQgbGFzdCBjb21wbGV0ZWQgYXVjdGlvbgAAAAAAEGdldF9sYXN0X2F1Y3Rpb24AAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAfQAAAAB0F1Y3Rpb24A",
+        "AAAAAAAAAB1HZXQgYSBjb21wbGV0ZWQgYXVjdGlvbiBieSBJRAAAAAAAAAtnZXRfYXVjdGlvbgAAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+gAAAfQAAAAB0F1Y3Rpb24A",
         "AAAAAAAAABNHZXQgYXVjdGlvbiBjb3VudGVyAAAAABNnZXRfYXVjdGlvbl9jb3VudGVyAAAAAAAAAAABAAAABg==",
         "AAAAAAAAABhHZXQgY29udHJhY3QgaW5mb3JtYXRpb24AAAARZ2V0X2NvbnRyYWN0X2luZm8AAAAAAAAAAAAAAQAAB9AAAAAMQ29udHJhY3RJbmZv",
-        "AAAAAAAAAC9HZXQgYXVjdGlvbiBzdW1tYXJ5IChldmVyeXRoaW5nIGZyb250ZW5kIG5lZWRzKQAAAAATZ2V0X2F1Y3Rpb25fc3VtbWFyeQAAAAAAAAAAAQAAB9AAAAAOQXVjdGlvblN1bW1hcnkAAA==",
-        "AAAAAAAAAClDYWxjdWxhdGUgbWluaW11bSBiaWQgZm9yIGN1cnJlbnQgYXVjdGlvbgAAAAAAAA9nZXRfbWluaW11bV9iaWQAAAAAAAAAAAEAAAAL",
-        "AAAAAAAAADpHZXQgYXVjdGlvbiBoaXN0b3J5IChzaW1wbGlmaWVkIC0gcmV0dXJucyBsYXN0IDUgYXVjdGlvbnMpAAAAAAATZ2V0X2F1Y3Rpb25faGlzdG9yeQAAAAAAAAAAAQAAA+oAAAfQAAAAB0F1Y3Rpb24A",
-        "AAAAAAAAACZTZXQgbWluaW11bSBiaWQgaW5jcmVtZW50IChvbmx5IG93bmVyKQAAAAAAFXNldF9taW5fYmlkX2luY3JlbWVudAAAAAAAAAEAAAAAAAAADW5ld19pbmNyZW1lbnQAAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAACVTZXQgbWluaW11bSBzdGFydGluZyBiaWQgKG9ubHkgb3duZXIpAAAAAAAAFHNldF9taW5fc3RhcnRpbmdfYmlkAAAAAQAAAAAAAAAQbmV3X3N0YXJ0aW5nX2JpZAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAChHZXQgYSBzbG90J3MgYmlkIHBhcmFtZXRlcnMgYW5kIHNldHRpbmdzAAAAD2dldF9zbG90X2NvbmZpZwAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAfQAAAAClNsb3RDb25maWcAAA==",
+        "AAAAAAAAAC9HZXQgYXVjdGlvbiBzdW1tYXJ5IChldmVyeXRoaW5nIGZyb250ZW5kIG5lZWRzKQAAAAATZ2V0X2F1Y3Rpb25fc3VtbWFyeQAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAfQAAAADkF1Y3Rpb25TdW1tYXJ5AAA=",
+        "AAAAAAAAAJlDYWxjdWxhdGUgbWluaW11bSBiaWQgZm9yIGN1cnJlbnQgYXVjdGlvbgoKRmFpbHMgd2l0aCBgT3JhY2xlU3RhbGVgIGlmIHRoZSBzbG90J3MgZmxvb3JzIGFyZSBzZXQgaW4gZmlhdCBhbmQKdGhlIG9yYWNsZSBwcmljZSBpcyB0b28gb2xkIHRvIGNvbnZlcnQgdGhlbS4AAAAAAAAPZ2V0X21pbmltdW1fYmlkAAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAACpHZXQgdGhlIHRpZXJlZCBtaW5pbXVtIGJpZCBpbmNyZW1lbnQgdGFibGUAAAAAABNnZXRfaW5jcmVtZW50X3RpZXJzAAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+oAAAfQAAAADUluY3JlbWVudFRpZXIAAAA=",
+        "AAAAAAAAARBHZXQgYSBwYWdlIG9mIGFuIGF1Y3Rpb24ncyBiaWQgbGFkZGVyLCBvbGRlc3QgZmlyc3QKCmBjdXJzb3JgIGlzIHRoZSBpbmRleCBvZiB0aGUgZmlyc3QgYmlkIHRvIHJldHVybiBhbW9uZyBldmVyeSBiaWQKZXZlciBwbGFjZWQgb24gdGhlIGF1Y3Rpb24sIHNvIGl0IHN0YXlzIHZhbGlkIGFzIG9sZCBiaWRzIGFyZQpkcm9wcGVkLiBBIGN1cnNvciBiZWZvcmUgdGhlIG9sZGVzdCBrZXB0IGJpZCBzdGFydHMgYXQgdGhhdCBiaWQKKHNlZSBgZ2V0X2ZpcnN0X2JpZF9pbmRleGApLgAAAAhnZXRfYmlkcwAAAAMAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAAAAAAABmN1cnNvcgAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAACUJpZFJlY29yZAAAAA==",
+        "AAAAAAAAACxHZXQgdGhlIG51bWJlciBvZiBiaWRzIHN0b3JlZCBmb3IgYW4gYXVjdGlvbgAAAA1nZXRfYmlkX2NvdW50AAAAAAAAAQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAEAAAAE",
+        "AAAAAAAAAGdHZXQgdGhlIGluZGV4IG9mIHRoZSBvbGRlc3QgYmlkIHN0aWxsIHN0b3JlZCBmb3IgYW4gYXVjdGlvbiwgdGhlCmN1cnNvciB0byBzdGFydCBwYWdpbmcgYGdldF9iaWRzYCBmcm9tAAAAABNnZXRfZmlyc3RfYmlkX2luZGV4AAAAAAEAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAABAAAABA==",
+        "AAAAAAAAACRHZXQgbGlmZXRpbWUgc3RhdGlzdGljcyBmb3IgYSBiaWRkZXIAAAAQZ2V0X2JpZGRlcl9zdGF0cwAAAAEAAAAAAAAABmJpZGRlcgAAAAAAEwAAAAEAAAfQAAAAC0JpZGRlclN0YXRzAA==",
+        "AAAAAAAAADlHZXQgdGhlIHRvcCBiaWRkZXJzIGJ5IHRvdGFsIGFtb3VudCBwYWlkIGZvciB3b24gYXVjdGlvbnMAAAAAAAAPZ2V0X2xlYWRlcmJvYXJkAAAAAAAAAAABAAAD6gAAB9AAAAAQTGVhZGVyYm9hcmRFbnRyeQ==",
+        "AAAAAAAAACVHZXQgdGhlIGNvbmZpZ3VyZWQgZmFsbGJhY2sgaG91c2UgYWRzAAAAAAAADWdldF9ob3VzZV9hZHMAAAAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAPqAAAH0AAAAAdIb3VzZUFkAA==",
+        "AAAAAAAAADlHZXQgYSBzbG90J3MgbW9zdCByZWNlbnQgY29tcGxldGVkIGF1Y3Rpb25zLCBvbGRlc3QgZmlyc3QAAAAAAAATZ2V0X2F1Y3Rpb25faGlzdG9yeQAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAPqAAAH0AAAAAdBdWN0aW9uAA==",
+        "AAAAAAAAAD5HZXQgdGhlIHNjYW4gcmVwb3J0cyBzdWJtaXR0ZWQgZm9yIGFuIGF1Y3Rpb24sIGluIGJ1Y2tldCBvcmRlcgAAAAAAEGdldF9zY2FuX3JlcG9ydHMAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+oAAAfQAAAAClNjYW5CdWNrZXQAAA==",
+        "AAAAAAAAACtHZXQgdGhlIHRvdGFsIGF0dGVzdGVkIHNjYW5zIGZvciBhbiBhdWN0aW9uAAAAAA9nZXRfdG90YWxfc2NhbnMAAAAAAQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAEAAAAG",
+        "AAAAAAAAAEJHZXQgdGhlIHdpbm5pbmcgYmlkIGRpdmlkZWQgYnkgYXR0ZXN0ZWQgc2NhbnMgKDAgYmVmb3JlIGFueSBzY2FucykAAAAAABFnZXRfY29zdF9wZXJfc2NhbgAAAAAAAAEAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAABAAAACw==",
+        "AAAAAAAAADxHZXQgYW4gYWRkcmVzcydzIGludGVybmFsIGRlcG9zaXQgYmFsYW5jZSBpbiBjb250cmFjdCB0b2tlbnMAAAALZ2V0X2JhbGFuY2UAAAAAAQAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAERHZXQgdGhlIGFkZHJlc3MgY3VycmVudGx5IGFsbG93ZWQgdG8gbWFuYWdlIGFuIGF1Y3Rpb24ncyBVUkwsIGlmIGFueQAAAA9nZXRfdXJsX21hbmFnZXIAAAAAAgAAAAAAAAAEc2xvdAAAABEAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAABAAAD6AAAABM=",
+        "AAAAAAAAADpHZXQgdGhlIGFkZHJlc3MgaG9sZGluZyBhIHNldHRsZWQgYXVjdGlvbidzIGRpc3BsYXkgcmlnaHRzAAAAAAASZ2V0X2Rpc3BsYXlfaG9sZGVyAAAAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAABxHZXQgYSBjcm93ZGZ1bmRlZCBwb29sIGJ5IGlkAAAACGdldF9wb29sAAAAAQAAAAAAAAAHcG9vbF9pZAAAAAAGAAAAAQAAA+gAAAfQAAAABFBvb2w=",
+        "AAAAAAAAADFHZXQgaG93IG11Y2ggYW4gYWRkcmVzcyBoYXMgY29udHJpYnV0ZWQgdG8gYSBwb29sAAAAAAAAFWdldF9wb29sX2NvbnRyaWJ1dGlvbgAAAAAAAAIAAAAAAAAAB3Bvb2xfaWQAAAAABgAAAAAAAAALY29udHJpYnV0b3IAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAEhHZXQgYSBIYXJiZXJnZXIgc2xvdCdzIGhvbGRlciwgcHJpY2UsIFVSTCBhbmQgcmVtYWluaW5nIGRlcG9zaXQsIGlmIGhlbGQAAAAVZ2V0X2hhcmJlcmdlcl9ob2xkaW5nAAAAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAD6AAAB9AAAAAQSGFyYmVyZ2VySG9sZGluZw==",
+        "AAAAAAAAAD9HZXQgdGhlIHNlY29uZHMgdW50aWwgYSBIYXJiZXJnZXIgaG9sZGVyJ3MgdGF4IGRlcG9zaXQgcnVucyBvdXQAAAAADmdldF90YXhfcnVud2F5AAAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAAG",
+        "AAAAAAAAADxHZXQgdGhlIG9wZW4gbGlzdGluZyBmb3IgYW4gYXVjdGlvbidzIGRpc3BsYXkgcmlnaHRzLCBpZiBhbnkAAAALZ2V0X2xpc3RpbmcAAAAAAQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAEAAAPoAAAH0AAAAAdMaXN0aW5nAA==",
+        "AAAAAAAAAEFHZXQgdGhlIHBsYXRmb3JtIHJveWFsdHkgb24gZGlzcGxheSByaWdodHMgc2FsZXMsIGluIGJhc2lzIHBvaW50cwAAAAAAABNnZXRfbGlzdGluZ19yb3lhbHR5AAAAAAAAAAABAAAABA==",
+        "AAAAAAAAADtHZXQgdGhlIHRva2VucyBhY2NlcHRlZCBmb3IgYmlkcyBiZXNpZGVzIHRoZSBjb250cmFjdCB0b2tlbgAAAAATZ2V0X2FjY2VwdGVkX3Rva2VucwAAAAAAAAAAAQAAA+oAAAAT",
+        "AAAAAAAAAEJHZXQgdGhlIHByaWNlIG9yYWNsZSB1c2VkIHRvIHZhbHVlIGJpZHMgaW4gYWNjZXB0ZWQgdG9rZW5zLCBpZiBhbnkAAAAAABBnZXRfcHJpY2Vfb3JhY2xlAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAD9HZXQgdGhlIG1pbmltdW0gYmlkIGZvciB0aGUgY3VycmVudCBhdWN0aW9uIGluIHVuaXRzIG9mIGB0b2tlbmAAAAAAFWdldF9taW5pbXVtX3Rva2VuX2JpZAAAAAAAAAIAAAAAAAAABHNsb3QAAAARAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAADdHZXQgYSBzbG90J3MgZmlhdC1kZW5vbWluYXRlZCBiaWQgZmxvb3JzLCBpZiBjb25maWd1cmVkAAAAAA9nZXRfZmlhdF9mbG9vcnMAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAD6AAAB9AAAAAKRmlhdEZsb29ycwAA",
+        "AAAAAAAAAERHZXQgdGhlIG1heGltdW0gYWdlIGluIHNlY29uZHMgb2YgYW4gb3JhY2xlIHByaWNlIGJlZm9yZSBpdCBpcyBzdGFsZQAAABJnZXRfb3JhY2xlX21heF9hZ2UAAAAAAAAAAAABAAAABg==",
+        "AAAAAAAAAENHZXQgdGhlIGRvd250aW1lIGludGVydmFscyByZXBvcnRlZCBmb3IgYW4gYXVjdGlvbidzIGRpc3BsYXkgd2luZG93AAAAAAxnZXRfZG93bnRpbWUAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+oAAAfQAAAAEERvd250aW1lSW50ZXJ2YWw=",
+        "AAAAAAAAADlHZXQgdGhlIHRvdGFsIHNlY29uZHMgb2YgcmVwb3J0ZWQgZG93bnRpbWUgZm9yIGFuIGF1Y3Rpb24AAAAAAAASZ2V0X3RvdGFsX2Rvd250aW1lAAAAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAAAY=",
+        "AAAAAAAAADtHZXQgdGhlIHByb2NlZWRzIHN0aWxsIGhlbGQgZm9yIGFuIGF1Y3Rpb24ncyBkaXNwbGF5IHdpbmRvdwAAAAAVZ2V0X2VzY3Jvd2VkX3Byb2NlZWRzAAAAAAAAAQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAEAAAAL",
+        "AAAAAAAAAERHZXQgbG9ja2VkLCBjbGFpbWFibGUsIHJlbGVhc2VkIGFuZCByZWZ1bmRlZCBwcm9jZWVkcyBmb3IgYW4gYXVjdGlvbgAAAAxnZXRfcHJvY2VlZHMAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAB9AAAAAOUHJvY2VlZHNTdGF0dXMAAA==",
+        "AAAAAAAAADlHZXQgdGhlIGFkZHJlc3MgYWxsb3dlZCB0byByZXBvcnQgZGV2aWNlIGRvd250aW1lLCBpZiBhbnkAAAAAAAATZ2V0X2RldmljZV9yZXBvcnRlcgAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAC9HZXQgdGhlIGFkZHJlc3MgYWxsb3dlZCB0byByZXBvcnQgc2NhbnMsIGlmIGFueQAAAAARZ2V0X3NjYW5fcmVwb3J0ZXIAAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAACZTZXQgbWluaW11bSBiaWQgaW5jcmVtZW50IChvbmx5IG93bmVyKQAAAAAAFXNldF9taW5fYmlkX2luY3JlbWVudAAAAAAAAAIAAAAAAAAABHNsb3QAAAARAAAAAAAAAA1uZXdfaW5jcmVtZW50AAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAACVTZXQgbWluaW11bSBzdGFydGluZyBiaWQgKG9ubHkgb3duZXIpAAAAAAAAFHNldF9taW5fc3RhcnRpbmdfYmlkAAAAAgAAAAAAAAAEc2xvdAAAABEAAAAAAAAAEG5ld19zdGFydGluZ19iaWQAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAMZTZXQgcGVyY2VudGFnZS1iYXNlZCBtaW5pbXVtIGJpZCBpbmNyZW1lbnQgaW4gYmFzaXMgcG9pbnRzIChvbmx5IG93bmVyKQoKVGhlIGVmZmVjdGl2ZSBpbmNyZW1lbnQgaXMgdGhlIGxhcmdlciBvZiB0aGUgZmxhdCBpbmNyZW1lbnQgYW5kIHRoaXMKcGVyY2VudGFnZSBvZiB0aGUgY3VycmVudCBoaWdoZXN0IGJpZC4gWmVybyBkaXNhYmxlcyBpdC4AAAAAABlzZXRfbWluX2JpZF9pbmNyZW1lbnRfYnBzAAAAAAAAAgAAAAAAAAAEc2xvdAAAABEAAAAAAAAAB25ld19icHMAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAOFTZXQgdGhlIHRpZXJlZCBtaW5pbXVtIGJpZCBpbmNyZW1lbnQgdGFibGUgKG9ubHkgb3duZXIpCgpUaWVycyBtdXN0IGJlIHNvcnRlZCBieSBzdHJpY3RseSBhc2NlbmRpbmcgYG1pbl9iaWRgLiBXaGlsZSBhIHRpZXIKbWF0Y2hlcyB0aGUgY3VycmVudCBoaWdoZXN0IGJpZCBpdHMgaW5jcmVtZW50IHJlcGxhY2VzIHRoZSBmbGF0IG9uZS4KQW4gZW1wdHkgdGFibGUgZGlzYWJsZXMgdGllcmluZy4AAAAAAAATc2V0X2luY3JlbWVudF90aWVycwAAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAAFdGllcnMAAAAAAAPqAAAH0AAAAA1JbmNyZW1lbnRUaWVyAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAElTZXQgYSBzaW5nbGUgZmFsbGJhY2sgVVJMIHNob3duIHdoZW4gbm8gd2lubmVyIGlzIGRpc3BsYXlpbmcgKG9ubHkgb3duZXIpAAAAAAAAEHNldF9mYWxsYmFja191cmwAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAADdXJsAAAAABAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAANNTZXQgdGhlIHdlaWdodGVkIGhvdXNlIGFkIHJvdGF0aW9uIHVzZWQgYXMgZmFsbGJhY2sgKG9ubHkgb3duZXIpCgpFbnRyaWVzIHJvdGF0ZSBldmVyeSBgSE9VU0VfQURfUk9UQVRJT05fUEVSSU9EYCwgZWFjaCByZWNlaXZpbmcgYQpzaGFyZSBvZiBwZXJpb2RzIHByb3BvcnRpb25hbCB0byBpdHMgd2VpZ2h0LiBBbiBlbXB0eSBsaXN0IGNsZWFycyB0aGUKZmFsbGJhY2suAAAAAA1zZXRfaG91c2VfYWRzAAAAAAAAAgAAAAAAAAAEc2xvdAAAABEAAAAAAAAACWhvdXNlX2FkcwAAAAAAA+oAAAfQAAAAB0hvdXNlQWQAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAKZTZXQgdGhlIHRva2VucyBhY2NlcHRlZCBmb3IgYmlkcyBiZXNpZGVzIHRoZSBjb250cmFjdCB0b2tlbiAob25seSBvd25lcikKCkxlYWRpbmcgYmlkcyBhbHJlYWR5IHBsYWNlZCBpbiBhIHJlbW92ZWQgdG9rZW4gYXJlIHN0aWxsIHJlZnVuZGVkCmFuZCBzZXR0bGVkIGluIHRoYXQgdG9rZW4uAAAAAAATc2V0X2FjY2VwdGVkX3Rva2VucwAAAAABAAAAAAAAAAZ0b2tlbnMAAAAAA+oAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADtTZXQgdGhlIFNFUC00MCBwcmljZSBvcmFjbGUgdXNlZCB0byB2YWx1ZSBiaWRzIChvbmx5IG93bmVyKQAAAAAQc2V0X3ByaWNlX29yYWNsZQAAAAEAAAAAAAAABm9yYWNsZQAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEZTZXQgdGhlIG1heGltdW0gYWdlIG9mIGFuIG9yYWNsZSBwcmljZSBiZWZvcmUgaXQgaXMgc3RhbGUgKG9ubHkgb3duZXIpAAAAAAASc2V0X29yYWNsZV9tYXhfYWdlAAAAAAABAAAAAAAAAAdtYXhfYWdlAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAH9EZW5vbWluYXRlIGEgc2xvdCdzIHN0YXJ0aW5nIGJpZCBhbmQgZmxhdCBpbmNyZW1lbnQgaW4gdGhlIHByaWNlCm9yYWNsZSdzIGJhc2UgYXNzZXQgaW5zdGVhZCBvZiB0aGUgY29udHJhY3QgdG9rZW4gKG9ubHkgb3duZXIpAAAAAA9zZXRfZmlhdF9mbG9vcnMAAAAAAwAAAAAAAAAEc2xvdAAAABEAAAAAAAAAEG1pbl9zdGFydGluZ19iaWQAAAALAAAAAAAAABFtaW5fYmlkX2luY3JlbWVudAAAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEFHbyBiYWNrIHRvIHRoZSBzbG90J3MgZmxvb3JzIGluIGNvbnRyYWN0IHRva2VuIHVuaXRzIChvbmx5IG93bmVyKQAAAAAAABFjbGVhcl9maWF0X2Zsb29ycwAAAAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEVTZXQgdGhlIHJld2FyZCBwYWlkIHRvIGtlZXBlcnMgc2V0dGxpbmcgZXhwaXJlZCBhdWN0aW9ucyAob25seSBvd25lcikAAAAAAAARc2V0X2tlZXBlcl9yZXdhcmQAAAAAAAADAAAAAAAAAAxmaXhlZF9hbW91bnQAAAALAAAAAAAAAANicHMAAAAABAAAAAAAAAAKbWF4X2Ftb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAD1TZXQgdGhlIHRydXN0ZWQgYWRkcmVzcyB0aGF0IGF0dGVzdHMgc2NhbiBjb3VudHMgKG9ubHkgb3duZXIpAAAAAAAAEXNldF9zY2FuX3JlcG9ydGVyAAAAAAAAAQAAAAAAAAAIcmVwb3J0ZXIAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEJTZXQgdGhlIHRydXN0ZWQgYWRkcmVzcyB0aGF0IHJlcG9ydHMgZGlzcGxheSBkb3dudGltZSAob25seSBvd25lcikAAAAAABNzZXRfZGV2aWNlX3JlcG9ydGVyAAAAAAEAAAAAAAAACHJlcG9ydGVyAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAHRWZXN0IHByb2NlZWRzIGxpbmVhcmx5IG92ZXIgZGlzcGxheSB3aW5kb3dzIHNldHRsZWQgZnJvbSBub3cgb24KaW5zdGVhZCBvZiByZWxlYXNpbmcgdGhlbSBhdCB3aW5kb3cgZW5kIChvbmx5IG93bmVyKQAAABJzZXRfbGluZWFyX3Zlc3RpbmcAAAAAAAEAAAAAAAAAB2VuYWJsZWQAAAAAAQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAANtTd2l0Y2ggYSBzbG90IHRvIEhhcmJlcmdlciBvd25lcnNoaXAgaW5zdGVhZCBvZiBkYWlseSBhdWN0aW9ucywgb3IKY2hhbmdlIGl0cyBkYWlseSB0YXggcmF0ZSBpbiBiYXNpcyBwb2ludHMgb2YgdGhlIHByaWNlIChvbmx5IG93bmVyKQoKT25seSBwb3NzaWJsZSBvbmNlIG5vIGF1Y3Rpb24gaXMgcnVubmluZyBhbmQgZXZlcnkgd2lubmVyIGRpc3BsYXkKd2luZG93IGhhcyBlbmRlZC4AAAAAEnNldF9oYXJiZXJnZXJfbW9kZQAAAAAAAgAAAAAAAAAEc2xvdAAAABEAAAAAAAAAB3RheF9icHMAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAGFSZXR1cm4gYSBIYXJiZXJnZXIgc2xvdCB0byBkYWlseSBhdWN0aW9ucywgcmVmdW5kaW5nIHRoZSBob2xkZXIncwp1bnVzZWQgdGF4IGRlcG9zaXQgKG9ubHkgb3duZXIpAAAAAAAAFmRpc2FibGVfaGFyYmVyZ2VyX21vZGUAAAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAFZTZXQgdGhlIHBsYXRmb3JtIHJveWFsdHkgdGFrZW4gZnJvbSBkaXNwbGF5IHJpZ2h0cyBzYWxlcywgaW4gYmFzaXMKcG9pbnRzIChvbmx5IG93bmVyKQAAAAAAE3NldF9saXN0aW5nX3JveWFsdHkAAAAAAQAAAAAAAAADYnBzAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEZFbmFibGUgb3IgZGlzYWJsZSBzdGFydGluZyB0aGUgbmV4dCBhdWN0aW9uIG9uIHNldHRsZW1lbnQgKG9ubHkgb3duZXIpAAAAAAATc2V0X2NvbnRpbnVvdXNfbW9kZQAAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAC9TZXQgdGhlIGNlaWxpbmcgZm9yIGJpZCBwYXJhbWV0ZXJzIChvbmx5IG93bmVyKQAAAAARc2V0X21heF9iaWRfcGFyYW0AAAAAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAALbmV3X2NlaWxpbmcAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
         "AAAAAAAAACNVcGRhdGUgcGxhdGZvcm0gd2FsbGV0IChvbmx5IG93bmVyKQAAAAATc2V0X3BsYXRmb3JtX3dhbGxldAAAAAABAAAAAAAAAApuZXdfd2FsbGV0AAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEpEZWxlZ2F0ZSBjb250cmFjdCB1cGdyYWRlcyB0byBhbm90aGVyIGFkZHJlc3MsIGUuZy4gYSBmYWN0b3J5IChvbmx5IG93bmVyKQAAAAAADHNldF91cGdyYWRlcgAAAAEAAAAAAAAACHVwZ3JhZGVyAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAADxSZXBsYWNlIHRoZSBjb250cmFjdCBjb2RlIChvbmx5IHVwZ3JhZGVyLCBkZWZhdWx0cyB0byBvd25lcikAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
         "AAAAAAAAAB9UcmFuc2ZlciBvd25lcnNoaXAgKG9ubHkgb3duZXIpAAAAABJ0cmFuc2Zlcl9vd25lcnNoaXAAAAAAAAEAAAAAAAAACW5ld19vd25lcgAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
       ]),
       options
@@ -73,7 +209,34 @@ export class Client extends ContractClient {
   }
   fromJSON = {
     initialize: this.txFromJSON,
+    create_slot: this.txFromJSON,
+    list_slots: this.txFromJSON,
     place_bid: this.txFromJSON,
+    place_gift_bid: this.txFromJSON,
+    place_bid_from: this.txFromJSON,
+    place_token_bid: this.txFromJSON,
+    place_proxy_bid: this.txFromJSON,
+    open_pool: this.txFromJSON,
+    contribute: this.txFromJSON,
+    withdraw_from_pool: this.txFromJSON,
+    set_qr_metadata: this.txFromJSON,
+    update_url: this.txFromJSON,
+    appoint_manager: this.txFromJSON,
+    revoke_manager: this.txFromJSON,
+    transfer_display: this.txFromJSON,
+    list_display: this.txFromJSON,
+    cancel_listing: this.txFromJSON,
+    buy_listing: this.txFromJSON,
+    claim_slot: this.txFromJSON,
+    set_harberger_price: this.txFromJSON,
+    top_up_tax: this.txFromJSON,
+    collect_tax: this.txFromJSON,
+    report_scans: this.txFromJSON,
+    report_downtime: this.txFromJSON,
+    close_display_window: this.txFromJSON,
+    claim_proceeds: this.txFromJSON,
+    deposit: this.txFromJSON,
+    withdraw: this.txFromJSON,
     start_auction: this.txFromJSON,
     end_auction: this.txFromJSON,
     get_qr_url: this.txFromJSON,
@@ -81,6 +244,8 @@ export class Client extends ContractClient {
     has_active_qr_url: this.txFromJSON,
     get_qr_url_status: this.txFromJSON,
     get_qr_url_expiry_time: this.txFromJSON,
+    get_current_display: this.txFromJSON,
+    get_display_schedule: this.txFromJSON,
     get_time_remaining: this.txFromJSON,
     is_auction_active: this.txFromJSON,
     get_current_auction: this.txFromJSON,
@@ -88,12 +253,63 @@ export class Client extends ContractClient {
     get_auction: this.txFromJSON,
     get_auction_counter: this.txFromJSON,
     get_contract_info: this.txFromJSON,
+    get_slot_config: this.txFromJSON,
     get_auction_summary: this.txFromJSON,
     get_minimum_bid: this.txFromJSON,
+    get_increment_tiers: this.txFromJSON,
+    get_bids: this.txFromJSON,
+    get_bid_count: this.txFromJSON,
+    get_first_bid_index: this.txFromJSON,
+    get_bidder_stats: this.txFromJSON,
+    get_leaderboard: this.txFromJSON,
+    get_house_ads: this.txFromJSON,
     get_auction_history: this.txFromJSON,
+    get_scan_reports: this.txFromJSON,
+    get_total_scans: this.txFromJSON,
+    get_cost_per_scan: this.txFromJSON,
+    get_balance: this.txFromJSON,
+    get_url_manager: this.txFromJSON,
+    get_display_holder: this.txFromJSON,
+    get_pool: this.txFromJSON,
+    get_pool_contribution: this.txFromJSON,
+    get_harberger_holding: this.txFromJSON,
+    get_tax_runway: this.txFromJSON,
+    get_listing: this.txFromJSON,
+    get_listing_royalty: this.txFromJSON,
+    get_accepted_tokens: this.txFromJSON,
+    get_price_oracle: this.txFromJSON,
+    get_minimum_token_bid: this.txFromJSON,
+    get_fiat_floors: this.txFromJSON,
+    get_oracle_max_age: this.txFromJSON,
+    get_downtime: this.txFromJSON,
+    get_total_downtime: this.txFromJSON,
+    get_escrowed_proceeds: this.txFromJSON,
+    get_proceeds: this.txFromJSON,
+    get_device_reporter: this.txFromJSON,
+    get_scan_reporter: this.txFromJSON,
     set_min_bid_increment: this.txFromJSON,
     set_min_starting_bid: this.txFromJSON,
+    set_min_bid_increment_bps: this.txFromJSON,
+    set_increment_tiers: this.txFromJSON,
+    set_fallback_url: this.txFromJSON,
+    set_house_ads: this.txFromJSON,
+    set_accepted_tokens: this.txFromJSON,
+    set_price_oracle: this.txFromJSON,
+    set_oracle_max_age: this.txFromJSON,
+    set_fiat_floors: this.txFromJSON,
+    clear_fiat_floors: this.txFromJSON,
+    set_keeper_reward: this.txFromJSON,
+    set_scan_reporter: this.txFromJSON,
+    set_device_reporter: this.txFromJSON,
+    set_linear_vesting: this.txFromJSON,
+    set_harberger_mode: this.txFromJSON,
+    disable_harberger_mode: this.txFromJSON,
+    set_listing_royalty: this.txFromJSON,
+    set_continuous_mode: this.txFromJSON,
+    set_max_bid_param: this.txFromJSON,
     set_platform_wallet: this.txFromJSON,
+    set_upgrader: this.txFromJSON,
+    upgrade: this.txFromJSON,
     transfer_ownership: this.txFromJSON,
   };
 }
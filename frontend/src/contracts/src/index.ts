@@ -47,23 +47,294 @@ export const Errors = {
   6: {message:"AuctionNotEnded"},
   7: {message:"AlreadyEnded"},
   8: {message:"Unauthorized"},
-  9: {message:"AlreadyInitialized"}
+  9: {message:"AlreadyInitialized"},
+  10: {message:"InvalidAmount"},
+  11: {message:"AmountTooHigh"},
+  12: {message:"IncrementTooHigh"},
+  13: {message:"InvalidBasisPoints"},
+  14: {message:"InvalidTiers"},
+  15: {message:"InvalidHouseAds"},
+  16: {message:"InvalidKeeperReward"},
+  17: {message:"SlotNotFound"},
+  18: {message:"SlotExists"},
+  19: {message:"TooManySlots"},
+  20: {message:"InvalidMetadata"},
+  21: {message:"AuctionNotFound"},
+  22: {message:"DisplayEnded"},
+  23: {message:"InvalidScanBucket"},
+  24: {message:"InvalidDowntime"},
+  25: {message:"DisplayNotEnded"},
+  26: {message:"NothingEscrowed"},
+  27: {message:"TokenNotAccepted"},
+  28: {message:"PriceUnavailable"},
+  29: {message:"InvalidTokens"},
+  30: {message:"OracleStale"},
+  31: {message:"InsufficientBalance"},
+  32: {message:"ListingNotFound"},
+  33: {message:"NotHarbergerSlot"},
+  34: {message:"HarbergerSlot"},
+  35: {message:"AuctionInProgress"},
+  36: {message:"SlotVacant"},
+  37: {message:"PoolNotFound"},
+  38: {message:"TooManyContributors"},
+  39: {message:"NothingToWithdraw"},
+  40: {message:"UrlTooLong"},
+  41: {message:"DisplayScheduled"},
+  42: {message:"BeneficiaryLeading"},
+  43: {message:"PoolAuction"}
 }
 
 
+/**
+ * `highest_bid` is in units of the contract token; the leading bid was
+ * actually paid by `bid_payer` as `bid_amount` of `bid_token`, reserved
+ * from the payer's deposit balance if `bid_from_balance`. Refunds go to
+ * `bid_payer`; `highest_bidder` controls the URL until the display rights
+ * of a settled auction are transferred.
+ */
 export interface Auction {
   auction_id: u64;
+  bid_amount: i128;
+  bid_from_balance: boolean;
+  bid_payer: string;
+  bid_token: string;
   ending_time: u64;
   highest_bid: i128;
   highest_bidder: string;
   is_ended: boolean;
+  metadata: QRMetadata;
   preferred_url: string;
+  slot: string;
   starting_time: u64;
   url_expiry_time: u64;
 }
 
 
+/**
+ * Ad card details a winner attaches to their URL; empty until set
+ */
+export interface QRMetadata {
+  advertiser: string;
+  category: string;
+  description: string;
+  image_hash: Option<Buffer>;
+  title: string;
+}
+
+
+/**
+ * Increment applied once the highest bid reaches `min_bid`
+ */
+export interface IncrementTier {
+  increment: i128;
+  min_bid: i128;
+}
+
+
+/**
+ * Escrowed proxy bid; the ceiling is kept out of `Auction` on purpose
+ *
+ * Pools bid through a proxy whose ceiling is the pooled balance; their
+ * escrow stays with the pool instead of being refunded to `bidder`.
+ */
+export interface ProxyBid {
+  auction_id: u64;
+  bidder: string;
+  max_amount: i128;
+  pool_id: Option<u64>;
+}
+
+
+/**
+ * Crowdfunded bid on one auction, placed by the contract on the pool's behalf
+ *
+ * The contract pays as `bid_payer` while `creator` is the bidder who
+ * controls the URL, metadata and display rights if the pool wins.
+ *
+ * `spent` is the winning bid paid from the pool and `refunded` any
+ * downtime refund credited back to it; contributors withdraw the rest pro
+ * rata once the auction is settled.
+ */
+export interface Pool {
+  auction_id: u64;
+  balance: i128;
+  contributors: Array<string>;
+  creator: string;
+  pool_id: u64;
+  refunded: i128;
+  slot: string;
+  spent: i128;
+  url: string;
+}
+
+
+/**
+ * A contributor's stake in a pool
+ */
+export interface PoolShare {
+  amount: i128;
+  withdrawn: i128;
+}
+
+
+/**
+ * Single entry in an auction's on-chain bid ladder
+ */
+export interface BidRecord {
+  amount: i128;
+  bidder: string;
+  preferred_url: string;
+  timestamp: u64;
+}
+
+
+/**
+ * Lifetime aggregates for a single bidder address
+ */
+export interface BidderStats {
+  auctions_won: u32;
+  bids_placed: u32;
+  last_win_id: u64;
+  total_paid: i128;
+  total_refunded: i128;
+}
+
+
+export interface LeaderboardEntry {
+  bidder: string;
+  total_paid: i128;
+}
+
+
+/**
+ * Owner-managed fallback URL shown while no winner is displaying
+ */
+export interface HouseAd {
+  id: u32;
+  url: string;
+  weight: u32;
+}
+
+
+/**
+ * Paid display slot; windows are queued back-to-back and never overlap
+ */
+export interface DisplayWindow {
+  auction_id: u64;
+  end_time: u64;
+  holder: string;
+  start_time: u64;
+  url: string;
+}
+
+
+/**
+ * Reward paid from proceeds to whoever settles an expired auction
+ *
+ * The reward is `fixed_amount` plus `bps` of the winning bid, capped at
+ * `max_amount`.
+ */
+export interface KeeperReward {
+  bps: u32;
+  fixed_amount: i128;
+  max_amount: i128;
+}
+
+
+/**
+ * Address a URL holder has delegated URL and metadata edits to
+ *
+ * Only honoured while `holder` still controls the auction's URL.
+ */
+export interface UrlManager {
+  holder: string;
+  manager: string;
+}
+
+
+/**
+ * Holder of a slot in Harberger mode
+ *
+ * Tax accrues every second on the self-assessed `price` since
+ * `assessed_at`, at the slot's daily rate. `tax_paid` is what has already
+ * been collected from `deposit` since then; the holding lapses once the
+ * accrued tax exhausts the deposit.
+ */
+export interface HarbergerHolding {
+  assessed_at: u64;
+  deposit: i128;
+  holder: string;
+  price: i128;
+  tax_paid: i128;
+  url: string;
+}
+
+
+/**
+ * Remaining display rights of a settled auction offered for sale
+ *
+ * `price` is in units of the contract token. The listing lapses when the
+ * window ends or the rights change hands.
+ */
+export interface Listing {
+  auction_id: u64;
+  listed_at: u64;
+  price: i128;
+  seller: string;
+}
+
+
+/**
+ * Scans attested by the reporter for one hour of a display window
+ *
+ * `bucket` counts hours from the start of the window.
+ */
+export interface ScanBucket {
+  bucket: u32;
+  reported_at: u64;
+  scans: u32;
+}
+
+
+/**
+ * Period during which a slot's physical display was offline
+ */
+export interface DowntimeInterval {
+  end_time: u64;
+  start_time: u64;
+}
+
+
+/**
+ * Winning proceeds held until the auction's display window ends
+ *
+ * With `linear_vesting` the platform may claim proceeds as display time
+ * is delivered; otherwise everything is released when the window closes.
+ */
+export interface ProceedsEscrow {
+  linear_vesting: boolean;
+  refunded: i128;
+  released: i128;
+  token: string;
+  total: i128;
+}
+
+
+/**
+ * Locked vs released accounting for an auction's proceeds
+ */
+export interface ProceedsStatus {
+  claimable: i128;
+  locked: i128;
+  refunded: i128;
+  released: i128;
+  total: i128;
+}
+
+
 export interface QRStatus {
+  fallback_id: Option<u32>;
+  metadata: QRMetadata;
   source: string;
   status: string;
 }
@@ -71,14 +342,25 @@ export interface QRStatus {
 
 export interface ContractInfo {
   auction_counter: u64;
-  min_bid_increment: i128;
-  min_starting_bid: i128;
+  keeper_reward: KeeperReward;
   owner: string;
   platform_wallet: string;
+  slots: Array<string>;
   token_address: string;
 }
 
 
+export interface SlotConfig {
+  continuous_mode: boolean;
+  harberger_tax_bps: Option<u32>;
+  increment_tiers: Array<IncrementTier>;
+  max_bid_param: i128;
+  min_bid_increment: i128;
+  min_bid_increment_bps: u32;
+  min_starting_bid: i128;
+}
+
+
 export interface AuctionSummary {
   current_auction: Auction;
   has_active_qr: boolean;
@@ -88,6 +370,33 @@ export interface AuctionSummary {
   time_remaining: u64;
 }
 
+
+/**
+ * Asset identifier in the SEP-40 price feed interface
+ */
+export type Asset = {tag: "Stellar", values: readonly [string]} | {tag: "Other", values: readonly [string]};
+
+
+/**
+ * Price record in the SEP-40 price feed interface
+ */
+export interface PriceData {
+  price: i128;
+  timestamp: u64;
+}
+
+
+/**
+ * Starting bid and flat increment in the price oracle's base asset
+ *
+ * Amounts are scaled by the oracle's decimals and converted to the
+ * contract token at the latest price whenever a minimum bid is computed.
+ */
+export interface FiatFloors {
+  min_bid_increment: i128;
+  min_starting_bid: i128;
+}
+
 export interface Client {
   /**
    * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -110,11 +419,58 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a create_slot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register a new QR slot, one per physical placement (only owner)
+   */
+  create_slot: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a list_slots transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * List all registered slots
+   */
+  list_slots: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>
+
   /**
    * Construct and simulate a place_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Place a bid on the current auction
+   *
+   * If another bidder holds a proxy bid with a ceiling at or above
+   * `amount`, the proxy counters automatically: the visible price rises
+   * but the proxy bidder stays in the lead and nothing is taken from
+   * `bidder`, who must still hold `amount` for the counter to happen.
    */
-  place_bid: ({bidder, amount, preferred_url}: {bidder: string, amount: i128, preferred_url: string}, options?: {
+  place_bid: ({slot, bidder, amount, preferred_url}: {slot: string, bidder: string, amount: i128, preferred_url: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -132,10 +488,14 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a start_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new auction (only owner)
+   * Construct and simulate a place_gift_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Place a bid paid by `payer` on behalf of `beneficiary`
+   *
+   * Lets a company buy a slot for someone else: `beneficiary` controls
+   * the URL and its metadata, while refunds go back to `payer`.
+   * Gifting to the current leader is refused.
    */
-  start_auction: (options?: {
+  place_gift_bid: ({slot, payer, beneficiary, amount, preferred_url}: {slot: string, payer: string, beneficiary: string, amount: i128, preferred_url: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -153,10 +513,15 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a end_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * End the current auction
+   * Construct and simulate a place_bid_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Place a bid funded from `payer`'s token allowance to `spender`
+   *
+   * Lets an agency or relayer bid on a client's behalf: `beneficiary`
+   * controls the URL if the bid wins, and `payer` is refunded if it is
+   * outbid. Bidding for the current leader with someone else's
+   * allowance is refused.
    */
-  end_auction: (options?: {
+  place_bid_from: ({slot, spender, payer, beneficiary, amount, preferred_url}: {slot: string, spender: string, payer: string, beneficiary: string, amount: i128, preferred_url: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -174,10 +539,14 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_qr_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the QR URL to display
+   * Construct and simulate a place_token_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Place a bid paid in one of the accepted tokens
+   *
+   * `amount` is in units of `token`; it is converted to the contract
+   * token's units through the price oracle to rank against other bids,
+   * and refunded in `token` if outbid.
    */
-  get_qr_url: (options?: {
+  place_token_bid: ({slot, bidder, token, amount, preferred_url}: {slot: string, bidder: string, token: string, amount: i128, preferred_url: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -192,13 +561,18 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_current_auction_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get current auction URL (during bidding)
+   * Construct and simulate a place_proxy_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Place a proxy (maximum) bid on the current auction
+   *
+   * The full `max_amount` is escrowed and the contract bids on the
+   * bidder's behalf up to that ceiling, only ever paying one increment
+   * over the next best bid. The ceiling itself is never exposed through
+   * `Auction`; unused escrow is refunded at settlement or when outbid.
    */
-  get_current_auction_url: (options?: {
+  place_proxy_bid: ({slot, bidder, max_amount, preferred_url}: {slot: string, bidder: string, max_amount: i128, preferred_url: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -213,13 +587,16 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a has_active_qr_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Check if there's an active QR URL
+   * Construct and simulate a open_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Open a crowdfunded pool bidding for `url` on a slot's current auction
+   *
+   * The URL is fixed: while the pool leads and once it wins, its URL,
+   * metadata and display rights cannot be changed, transferred or sold.
    */
-  has_active_qr_url: (options?: {
+  open_pool: ({slot, creator, url}: {slot: string, creator: string, url: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -234,13 +611,18 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>
+  }) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
-   * Construct and simulate a get_qr_url_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get QR URL status and source
+   * Construct and simulate a contribute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Contribute contract tokens to a pool
+   *
+   * The pool bids as a single bidder, defending its lead with the pooled
+   * balance as its ceiling and only paying one increment over the next
+   * best bid. Each contribution raises the ceiling and, if the pool is
+   * not leading, bids again.
    */
-  get_qr_url_status: (options?: {
+  contribute: ({pool_id, contributor, amount}: {pool_id: u64, contributor: string, amount: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -255,13 +637,17 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<QRStatus>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_qr_url_expiry_time transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get QR URL expiry time
+   * Construct and simulate a withdraw_from_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a contributor's pro rata share of what a pool did not spend
+   *
+   * Available once the pool's auction is settled: the full contribution
+   * if the pool lost, the unspent remainder if it won. Downtime refunds
+   * on a winning pool's display can be withdrawn as they are credited.
    */
-  get_qr_url_expiry_time: (options?: {
+  withdraw_from_pool: ({pool_id, contributor}: {pool_id: u64, contributor: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -276,13 +662,18 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a get_time_remaining transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get time remaining in current auction
+   * Construct and simulate a set_qr_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Attach ad card metadata to an auction's URL
+   *
+   * Callable by the leading bidder while bidding is open and by the
+   * winner until their display window ends, or by their URL manager.
+   * Metadata is cleared whenever a different bidder takes the lead.
+   * A pool's bid keeps the content it was opened with.
    */
-  get_time_remaining: (options?: {
+  set_qr_metadata: ({slot, auction_id, caller, metadata}: {slot: string, auction_id: u64, caller: string, metadata: QRMetadata}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -297,13 +688,15 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a is_auction_active transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Check if auction is currently active
+   * Construct and simulate a update_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Change the URL of a leading bid or of a winner's display window
+   *
+   * Same access rules as `set_qr_metadata`.
    */
-  is_auction_active: (options?: {
+  update_url: ({slot, auction_id, caller, url}: {slot: string, auction_id: u64, caller: string, url: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -318,13 +711,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_current_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get current auction details
+   * Construct and simulate a appoint_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Let `manager` edit the URL and metadata of an auction the caller
+   * leads or holds the display rights of, replacing any previous manager
    */
-  get_current_auction: (options?: {
+  appoint_manager: ({slot, auction_id, manager}: {slot: string, auction_id: u64, manager: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -339,13 +733,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Auction>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_last_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get last completed auction
+   * Construct and simulate a revoke_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke the URL manager of an auction the caller leads or holds the
+   * display rights of
    */
-  get_last_auction: (options?: {
+  revoke_manager: ({slot, auction_id}: {slot: string, auction_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -360,13 +755,18 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Auction>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get specific auction by ID (limited implementation due to format! constraint)
+   * Construct and simulate a transfer_display transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Transfer the remaining display rights of a settled auction
+   *
+   * The new holder controls the URL and metadata until the window ends;
+   * any manager appointed by the previous holder loses access. Refunds
+   * and proceeds are unaffected. A pool's display rights stay with its
+   * contributors.
    */
-  get_auction: ({auction_id}: {auction_id: u64}, options?: {
+  transfer_display: ({auction_id, to}: {auction_id: u64, to: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -381,13 +781,16 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<Auction>>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_auction_counter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get auction counter
+   * Construct and simulate a list_display transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * List the remaining display rights of a settled auction for sale at
+   * `price` contract tokens, replacing any previous listing
+   *
+   * Not available for auctions won by a pool.
    */
-  get_auction_counter: (options?: {
+  list_display: ({auction_id, price}: {auction_id: u64, price: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -402,13 +805,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_contract_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get contract information
+   * Construct and simulate a cancel_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a display rights listing (only seller)
    */
-  get_contract_info: (options?: {
+  cancel_listing: ({auction_id}: {auction_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -423,13 +826,16 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<ContractInfo>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_auction_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get auction summary (everything frontend needs)
+   * Construct and simulate a buy_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Buy listed display rights at the ask price
+   *
+   * The seller receives the price minus the platform royalty and the
+   * buyer becomes the display holder.
    */
-  get_auction_summary: (options?: {
+  buy_listing: ({auction_id, buyer}: {auction_id: u64, buyer: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -444,13 +850,18 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<AuctionSummary>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_minimum_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Calculate minimum bid for current auction
+   * Construct and simulate a claim_slot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take over a Harberger slot
+   *
+   * The buyer pays the current holder their self-assessed price, and the
+   * holder's unused tax deposit is refunded to them. A vacant slot is
+   * free to claim. The buyer sets their own price and prepays `deposit`
+   * contract tokens of tax.
    */
-  get_minimum_bid: (options?: {
+  claim_slot: ({slot, buyer, price, url, deposit}: {slot: string, buyer: string, price: i128, url: string, deposit: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -465,13 +876,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_auction_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get auction history (simplified - returns last 5 auctions)
+   * Construct and simulate a set_harberger_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Self-assess a new price for a Harberger slot (only holder)
    */
-  get_auction_history: (options?: {
+  set_harberger_price: ({slot, price}: {slot: string, price: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -486,13 +897,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<Auction>>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_min_bid_increment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set minimum bid increment (only owner)
+   * Construct and simulate a top_up_tax transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add to the tax deposit of a Harberger slot (only holder)
    */
-  set_min_bid_increment: ({new_increment}: {new_increment: i128}, options?: {
+  top_up_tax: ({slot, amount}: {slot: string, amount: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -510,10 +921,11 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_min_starting_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set minimum starting bid (only owner)
+   * Construct and simulate a collect_tax transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay the tax accrued on a Harberger slot to the platform wallet,
+   * vacating the slot if the deposit has run out. Anyone may call this.
    */
-  set_min_starting_bid: ({new_starting_bid}: {new_starting_bid: i128}, options?: {
+  collect_tax: ({slot}: {slot: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -531,10 +943,1707 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_platform_wallet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update platform wallet (only owner)
+   * Construct and simulate a report_scans transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Report the scans counted during one hour of a settled auction's
+   * display window (only scan reporter)
+   *
+   * Buckets must be reported in increasing order and only once the hour
+   * they cover has passed.
    */
-  set_platform_wallet: ({new_wallet}: {new_wallet: string}, options?: {
+  report_scans: ({auction_id, bucket, scans}: {auction_id: u64, bucket: u32, scans: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a report_downtime transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Record a period the slot's display was offline during a settled
+   * auction's window (only device reporter)
+   *
+   * Intervals are clipped to the window and must be reported in order
+   * without overlapping.
+   */
+  report_downtime: ({auction_id, start_time, end_time}: {auction_id: u64, start_time: u64, end_time: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a close_display_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Release an auction's escrowed proceeds once its display window ends
+   *
+   * The winner is refunded pro rata for reported downtime, less anything
+   * already vested to the platform, and the rest goes to the platform
+   * wallet. Anyone may call this.
+   */
+  close_display_window: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_proceeds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay the platform whatever has vested of an auction's proceeds
+   *
+   * Only linearly vesting escrows release anything before the window
+   * ends; after that this closes the window like `close_display_window`.
+   * Anyone may call this. Returns the amount paid to the platform.
+   */
+  claim_proceeds: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a deposit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deposit contract tokens into the caller's internal balance
+   *
+   * Bids covered by the balance are reserved from it instead of being
+   * transferred, and refunded into it when outbid.
+   */
+  deposit: ({from, amount}: {from: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a withdraw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw contract tokens from the caller's internal balance
+   */
+  withdraw: ({to, amount}: {to: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new auction (only owner)
+   */
+  start_auction: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a end_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End the current auction
+   *
+   * Anyone may settle an expired auction. The `keeper` receives the
+   * configured keeper reward out of the winning bid and, in continuous
+   * mode, the next auction is started in the same call.
+   */
+  end_auction: ({slot, keeper}: {slot: string, keeper: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_qr_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the QR URL to display
+   *
+   * Falls back to the Harberger holder's URL, then to the
+   * owner-configured house ads while no winner is displaying.
+   */
+  get_qr_url: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a get_current_auction_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get current auction URL (during bidding)
+   */
+  get_current_auction_url: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a has_active_qr_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check if there's an active winner or Harberger holder QR URL
+   */
+  has_active_qr_url: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_qr_url_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get QR URL status and source
+   */
+  get_qr_url_status: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<QRStatus>>
+
+  /**
+   * Construct and simulate a get_qr_url_expiry_time transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get QR URL expiry time
+   */
+  get_qr_url_expiry_time: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_current_display transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the display window currently being served, if any
+   *
+   * Includes the window of an expired auction that has not been settled yet.
+   */
+  get_current_display: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<DisplayWindow>>>
+
+  /**
+   * Construct and simulate a get_display_schedule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current and upcoming display windows in order
+   */
+  get_display_schedule: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<DisplayWindow>>>
+
+  /**
+   * Construct and simulate a get_time_remaining transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get time remaining in current auction
+   */
+  get_time_remaining: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a is_auction_active transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check if auction is currently active
+   */
+  is_auction_active: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_current_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get current auction details
+   */
+  get_current_auction: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Auction>>
+
+  /**
+   * Construct and simulate a get_last_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get last completed auction
+   */
+  get_last_auction: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Auction>>
+
+  /**
+   * Construct and simulate a get_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a completed auction by ID
+   */
+  get_auction: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Auction>>>
+
+  /**
+   * Construct and simulate a get_auction_counter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get auction counter
+   */
+  get_auction_counter: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_contract_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get contract information
+   */
+  get_contract_info: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<ContractInfo>>
+
+  /**
+   * Construct and simulate a get_slot_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a slot's bid parameters and settings
+   */
+  get_slot_config: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<SlotConfig>>
+
+  /**
+   * Construct and simulate a get_auction_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get auction summary (everything frontend needs)
+   */
+  get_auction_summary: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<AuctionSummary>>
+
+  /**
+   * Construct and simulate a get_minimum_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Calculate minimum bid for current auction
+   *
+   * Fails with `OracleStale` if the slot's floors are set in fiat and
+   * the oracle price is too old to convert them.
+   */
+  get_minimum_bid: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_increment_tiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the tiered minimum bid increment table
+   */
+  get_increment_tiers: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<IncrementTier>>>
+
+  /**
+   * Construct and simulate a get_bids transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of an auction's bid ladder, oldest first
+   *
+   * `cursor` is the index of the first bid to return among every bid
+   * ever placed on the auction, so it stays valid as old bids are
+   * dropped. A cursor before the oldest kept bid starts at that bid
+   * (see `get_first_bid_index`).
+   */
+  get_bids: ({auction_id, cursor, limit}: {auction_id: u64, cursor: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<BidRecord>>>
+
+  /**
+   * Construct and simulate a get_bid_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of bids stored for an auction
+   */
+  get_bid_count: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_first_bid_index transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the index of the oldest bid still stored for an auction, the
+   * cursor to start paging `get_bids` from
+   */
+  get_first_bid_index: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_bidder_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get lifetime statistics for a bidder
+   */
+  get_bidder_stats: ({bidder}: {bidder: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<BidderStats>>
+
+  /**
+   * Construct and simulate a get_leaderboard transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the top bidders by total amount paid for won auctions
+   */
+  get_leaderboard: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<LeaderboardEntry>>>
+
+  /**
+   * Construct and simulate a get_house_ads transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the configured fallback house ads
+   */
+  get_house_ads: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<HouseAd>>>
+
+  /**
+   * Construct and simulate a get_auction_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a slot's most recent completed auctions, oldest first
+   */
+  get_auction_history: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Auction>>>
+
+  /**
+   * Construct and simulate a get_scan_reports transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the scan reports submitted for an auction, in bucket order
+   */
+  get_scan_reports: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<ScanBucket>>>
+
+  /**
+   * Construct and simulate a get_total_scans transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the total attested scans for an auction
+   */
+  get_total_scans: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_cost_per_scan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the winning bid divided by attested scans (0 before any scans)
+   */
+  get_cost_per_scan: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get an address's internal deposit balance in contract tokens
+   */
+  get_balance: ({address}: {address: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_url_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the address currently allowed to manage an auction's URL, if any
+   */
+  get_url_manager: ({slot, auction_id}: {slot: string, auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_display_holder transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the address holding a settled auction's display rights
+   */
+  get_display_holder: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a crowdfunded pool by id
+   */
+  get_pool: ({pool_id}: {pool_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Pool>>>
+
+  /**
+   * Construct and simulate a get_pool_contribution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get how much an address has contributed to a pool
+   */
+  get_pool_contribution: ({pool_id, contributor}: {pool_id: u64, contributor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_harberger_holding transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a Harberger slot's holder, price, URL and remaining deposit, if held
+   */
+  get_harberger_holding: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<HarbergerHolding>>>
+
+  /**
+   * Construct and simulate a get_tax_runway transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the seconds until a Harberger holder's tax deposit runs out
+   */
+  get_tax_runway: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the open listing for an auction's display rights, if any
+   */
+  get_listing: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Listing>>>
+
+  /**
+   * Construct and simulate a get_listing_royalty transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the platform royalty on display rights sales, in basis points
+   */
+  get_listing_royalty: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_accepted_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the tokens accepted for bids besides the contract token
+   */
+  get_accepted_tokens: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a get_price_oracle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the price oracle used to value bids in accepted tokens, if any
+   */
+  get_price_oracle: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_minimum_token_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the minimum bid for the current auction in units of `token`
+   */
+  get_minimum_token_bid: ({slot, token}: {slot: string, token: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_fiat_floors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a slot's fiat-denominated bid floors, if configured
+   */
+  get_fiat_floors: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<FiatFloors>>>
+
+  /**
+   * Construct and simulate a get_oracle_max_age transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the maximum age in seconds of an oracle price before it is stale
+   */
+  get_oracle_max_age: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_downtime transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the downtime intervals reported for an auction's display window
+   */
+  get_downtime: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<DowntimeInterval>>>
+
+  /**
+   * Construct and simulate a get_total_downtime transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the total seconds of reported downtime for an auction
+   */
+  get_total_downtime: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_escrowed_proceeds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the proceeds still held for an auction's display window
+   */
+  get_escrowed_proceeds: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_proceeds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get locked, claimable, released and refunded proceeds for an auction
+   */
+  get_proceeds: ({auction_id}: {auction_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<ProceedsStatus>>
+
+  /**
+   * Construct and simulate a get_device_reporter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the address allowed to report device downtime, if any
+   */
+  get_device_reporter: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_scan_reporter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the address allowed to report scans, if any
+   */
+  get_scan_reporter: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_min_bid_increment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set minimum bid increment (only owner)
+   */
+  set_min_bid_increment: ({slot, new_increment}: {slot: string, new_increment: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_min_starting_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set minimum starting bid (only owner)
+   */
+  set_min_starting_bid: ({slot, new_starting_bid}: {slot: string, new_starting_bid: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_min_bid_increment_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set percentage-based minimum bid increment in basis points (only owner)
+   *
+   * The effective increment is the larger of the flat increment and this
+   * percentage of the current highest bid. Zero disables it.
+   */
+  set_min_bid_increment_bps: ({slot, new_bps}: {slot: string, new_bps: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_increment_tiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the tiered minimum bid increment table (only owner)
+   *
+   * Tiers must be sorted by strictly ascending `min_bid`. While a tier
+   * matches the current highest bid its increment replaces the flat one.
+   * An empty table disables tiering.
+   */
+  set_increment_tiers: ({slot, tiers}: {slot: string, tiers: Array<IncrementTier>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_fallback_url transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a single fallback URL shown when no winner is displaying (only owner)
+   */
+  set_fallback_url: ({slot, url}: {slot: string, url: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_house_ads transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the weighted house ad rotation used as fallback (only owner)
+   *
+   * Entries rotate every `HOUSE_AD_ROTATION_PERIOD`, each receiving a
+   * share of periods proportional to its weight. An empty list clears the
+   * fallback.
+   */
+  set_house_ads: ({slot, house_ads}: {slot: string, house_ads: Array<HouseAd>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_accepted_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the tokens accepted for bids besides the contract token (only owner)
+   *
+   * Leading bids already placed in a removed token are still refunded
+   * and settled in that token.
+   */
+  set_accepted_tokens: ({tokens}: {tokens: Array<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_price_oracle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the SEP-40 price oracle used to value bids (only owner)
+   */
+  set_price_oracle: ({oracle}: {oracle: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_oracle_max_age transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the maximum age of an oracle price before it is stale (only owner)
+   */
+  set_oracle_max_age: ({max_age}: {max_age: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_fiat_floors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Denominate a slot's starting bid and flat increment in the price
+   * oracle's base asset instead of the contract token (only owner)
+   */
+  set_fiat_floors: ({slot, min_starting_bid, min_bid_increment}: {slot: string, min_starting_bid: i128, min_bid_increment: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a clear_fiat_floors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Go back to the slot's floors in contract token units (only owner)
+   */
+  clear_fiat_floors: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_keeper_reward transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the reward paid to keepers settling expired auctions (only owner)
+   */
+  set_keeper_reward: ({fixed_amount, bps, max_amount}: {fixed_amount: i128, bps: u32, max_amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_scan_reporter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the trusted address that attests scan counts (only owner)
+   */
+  set_scan_reporter: ({reporter}: {reporter: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_device_reporter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the trusted address that reports display downtime (only owner)
+   */
+  set_device_reporter: ({reporter}: {reporter: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_linear_vesting transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Vest proceeds linearly over display windows settled from now on
+   * instead of releasing them at window end (only owner)
+   */
+  set_linear_vesting: ({enabled}: {enabled: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_harberger_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Switch a slot to Harberger ownership instead of daily auctions, or
+   * change its daily tax rate in basis points of the price (only owner)
+   *
+   * Only possible once no auction is running and every winner display
+   * window has ended.
+   */
+  set_harberger_mode: ({slot, tax_bps}: {slot: string, tax_bps: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a disable_harberger_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Return a Harberger slot to daily auctions, refunding the holder's
+   * unused tax deposit (only owner)
+   */
+  disable_harberger_mode: ({slot}: {slot: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_listing_royalty transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the platform royalty taken from display rights sales, in basis
+   * points (only owner)
+   */
+  set_listing_royalty: ({bps}: {bps: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_continuous_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Enable or disable starting the next auction on settlement (only owner)
+   */
+  set_continuous_mode: ({slot, enabled}: {slot: string, enabled: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_max_bid_param transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the ceiling for bid parameters (only owner)
+   */
+  set_max_bid_param: ({slot, new_ceiling}: {slot: string, new_ceiling: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_platform_wallet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update platform wallet (only owner)
+   */
+  set_platform_wallet: ({new_wallet}: {new_wallet: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_upgrader transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Delegate contract upgrades to another address, e.g. a factory (only owner)
+   */
+  set_upgrader: ({upgrader}: {upgrader: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the contract code (only upgrader, defaults to owner)
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -590,40 +2699,169 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACQAAAAAAAAAMQXVjdGlvbkVuZGVkAAAAAQAAAAAAAAAPTm9BY3RpdmVBdWN0aW9uAAAAAAIAAAAAAAAACUJpZFRvb0xvdwAAAAAAAAMAAAAAAAAACEVtcHR5VXJsAAAABAAAAAAAAAAOTm9BdWN0aW9uVG9FbmQAAAAAAAUAAAAAAAAAD0F1Y3Rpb25Ob3RFbmRlZAAAAAAGAAAAAAAAAAxBbHJlYWR5RW5kZWQAAAAHAAAAAAAAAAxVbmF1dGhvcml6ZWQAAAAIAAAAAAAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAAk=",
-        "AAAAAQAAAAAAAAAAAAAAB0F1Y3Rpb24AAAAACAAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAALZW5kaW5nX3RpbWUAAAAABgAAAAAAAAALaGlnaGVzdF9iaWQAAAAACwAAAAAAAAAOaGlnaGVzdF9iaWRkZXIAAAAAABMAAAAAAAAACGlzX2VuZGVkAAAAAQAAAAAAAAANcHJlZmVycmVkX3VybAAAAAAAABAAAAAAAAAADXN0YXJ0aW5nX3RpbWUAAAAAAAAGAAAAAAAAAA91cmxfZXhwaXJ5X3RpbWUAAAAABg==",
-        "AAAAAQAAAAAAAAAAAAAACFFSU3RhdHVzAAAAAgAAAAAAAAAGc291cmNlAAAAAAAQAAAAAAAAAAZzdGF0dXMAAAAAABA=",
-        "AAAAAQAAAAAAAAAAAAAADENvbnRyYWN0SW5mbwAAAAYAAAAAAAAAD2F1Y3Rpb25fY291bnRlcgAAAAAGAAAAAAAAABFtaW5fYmlkX2luY3JlbWVudAAAAAAAAAsAAAAAAAAAEG1pbl9zdGFydGluZ19iaWQAAAALAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAD3BsYXRmb3JtX3dhbGxldAAAAAATAAAAAAAAAA10b2tlbl9hZGRyZXNzAAAAAAAAEw==",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAKwAAAAAAAAAMQXVjdGlvbkVuZGVkAAAAAQAAAAAAAAAPTm9BY3RpdmVBdWN0aW9uAAAAAAIAAAAAAAAACUJpZFRvb0xvdwAAAAAAAAMAAAAAAAAACEVtcHR5VXJsAAAABAAAAAAAAAAOTm9BdWN0aW9uVG9FbmQAAAAAAAUAAAAAAAAAD0F1Y3Rpb25Ob3RFbmRlZAAAAAAGAAAAAAAAAAxBbHJlYWR5RW5kZWQAAAAHAAAAAAAAAAxVbmF1dGhvcml6ZWQAAAAIAAAAAAAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAAkAAAAAAAAADUludmFsaWRBbW91bnQAAAAAAAAKAAAAAAAAAA1BbW91bnRUb29IaWdoAAAAAAAACwAAAAAAAAAQSW5jcmVtZW50VG9vSGlnaAAAAAwAAAAAAAAAEkludmFsaWRCYXNpc1BvaW50cwAAAAAADQAAAAAAAAAMSW52YWxpZFRpZXJzAAAADgAAAAAAAAAPSW52YWxpZEhvdXNlQWRzAAAAAA8AAAAAAAAAE0ludmFsaWRLZWVwZXJSZXdhcmQAAAAAEAAAAAAAAAAMU2xvdE5vdEZvdW5kAAAAEQAAAAAAAAAKU2xvdEV4aXN0cwAAAAAAEgAAAAAAAAAMVG9vTWFueVNsb3RzAAAAEwAAAAAAAAAPSW52YWxpZE1ldGFkYXRhAAAAABQAAAAAAAAAD0F1Y3Rpb25Ob3RGb3VuZAAAAAAVAAAAAAAAAAxEaXNwbGF5RW5kZWQAAAAWAAAAAAAAABFJbnZhbGlkU2NhbkJ1Y2tldAAAAAAAABcAAAAAAAAAD0ludmFsaWREb3dudGltZQAAAAAYAAAAAAAAAA9EaXNwbGF5Tm90RW5kZWQAAAAAGQAAAAAAAAAPTm90aGluZ0VzY3Jvd2VkAAAAABoAAAAAAAAAEFRva2VuTm90QWNjZXB0ZWQAAAAbAAAAAAAAABBQcmljZVVuYXZhaWxhYmxlAAAAHAAAAAAAAAANSW52YWxpZFRva2VucwAAAAAAAB0AAAAAAAAAC09yYWNsZVN0YWxlAAAAAB4AAAAAAAAAE0luc3VmZmljaWVudEJhbGFuY2UAAAAAHwAAAAAAAAAPTGlzdGluZ05vdEZvdW5kAAAAACAAAAAAAAAAEE5vdEhhcmJlcmdlclNsb3QAAAAhAAAAAAAAAA1IYXJiZXJnZXJTbG90AAAAAAAAIgAAAAAAAAARQXVjdGlvbkluUHJvZ3Jlc3MAAAAAAAAjAAAAAAAAAApTbG90VmFjYW50AAAAAAAkAAAAAAAAAAxQb29sTm90Rm91bmQAAAAlAAAAAAAAABNUb29NYW55Q29udHJpYnV0b3JzAAAAACYAAAAAAAAAEU5vdGhpbmdUb1dpdGhkcmF3AAAAAAAAJwAAAAAAAAAKVXJsVG9vTG9uZwAAAAAAKAAAAAAAAAAQRGlzcGxheVNjaGVkdWxlZAAAACkAAAAAAAAAEkJlbmVmaWNpYXJ5TGVhZGluZwAAAAAAKgAAAAAAAAALUG9vbEF1Y3Rpb24AAAAAKw==",
+        "AAAAAQAAAT5gaGlnaGVzdF9iaWRgIGlzIGluIHVuaXRzIG9mIHRoZSBjb250cmFjdCB0b2tlbjsgdGhlIGxlYWRpbmcgYmlkIHdhcwphY3R1YWxseSBwYWlkIGJ5IGBiaWRfcGF5ZXJgIGFzIGBiaWRfYW1vdW50YCBvZiBgYmlkX3Rva2VuYCwgcmVzZXJ2ZWQKZnJvbSB0aGUgcGF5ZXIncyBkZXBvc2l0IGJhbGFuY2UgaWYgYGJpZF9mcm9tX2JhbGFuY2VgLiBSZWZ1bmRzIGdvIHRvCmBiaWRfcGF5ZXJgOyBgaGlnaGVzdF9iaWRkZXJgIGNvbnRyb2xzIHRoZSBVUkwgdW50aWwgdGhlIGRpc3BsYXkgcmlnaHRzCm9mIGEgc2V0dGxlZCBhdWN0aW9uIGFyZSB0cmFuc2ZlcnJlZC4AAAAAAAAAAAAHQXVjdGlvbgAAAAAOAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAAAAAApiaWRfYW1vdW50AAAAAAALAAAAAAAAABBiaWRfZnJvbV9iYWxhbmNlAAAAAQAAAAAAAAAJYmlkX3BheWVyAAAAAAAAEwAAAAAAAAAJYmlkX3Rva2VuAAAAAAAAEwAAAAAAAAALZW5kaW5nX3RpbWUAAAAABgAAAAAAAAALaGlnaGVzdF9iaWQAAAAACwAAAAAAAAAOaGlnaGVzdF9iaWRkZXIAAAAAABMAAAAAAAAACGlzX2VuZGVkAAAAAQAAAAAAAAAIbWV0YWRhdGEAAAfQAAAAClFSTWV0YWRhdGEAAAAAAAAAAAANcHJlZmVycmVkX3VybAAAAAAAABAAAAAAAAAABHNsb3QAAAARAAAAAAAAAA1zdGFydGluZ190aW1lAAAAAAAABgAAAAAAAAAPdXJsX2V4cGlyeV90aW1lAAAAAAY=",
+        "AAAAAQAAAD9BZCBjYXJkIGRldGFpbHMgYSB3aW5uZXIgYXR0YWNoZXMgdG8gdGhlaXIgVVJMOyBlbXB0eSB1bnRpbCBzZXQAAAAAAAAAAApRUk1ldGFkYXRhAAAAAAAFAAAAAAAAAAphZHZlcnRpc2VyAAAAAAAQAAAAAAAAAAhjYXRlZ29yeQAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAACmltYWdlX2hhc2gAAAAAA+gAAAPuAAAAIAAAAAAAAAAFdGl0bGUAAAAAAAAQ",
+        "AAAAAQAAADhJbmNyZW1lbnQgYXBwbGllZCBvbmNlIHRoZSBoaWdoZXN0IGJpZCByZWFjaGVzIGBtaW5fYmlkYAAAAAAAAAANSW5jcmVtZW50VGllcgAAAAAAAAIAAAAAAAAACWluY3JlbWVudAAAAAAAAAsAAAAAAAAAB21pbl9iaWQAAAAACw==",
+        "AAAAAQAAAMtFc2Nyb3dlZCBwcm94eSBiaWQ7IHRoZSBjZWlsaW5nIGlzIGtlcHQgb3V0IG9mIGBBdWN0aW9uYCBvbiBwdXJwb3NlCgpQb29scyBiaWQgdGhyb3VnaCBhIHByb3h5IHdob3NlIGNlaWxpbmcgaXMgdGhlIHBvb2xlZCBiYWxhbmNlOyB0aGVpcgplc2Nyb3cgc3RheXMgd2l0aCB0aGUgcG9vbCBpbnN0ZWFkIG9mIGJlaW5nIHJlZnVuZGVkIHRvIGBiaWRkZXJgLgAAAAAAAAAACFByb3h5QmlkAAAABAAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAAptYXhfYW1vdW50AAAAAAALAAAAAAAAAAdwb29sX2lkAAAAA+gAAAAG",
+        "AAAAAQAAAXtDcm93ZGZ1bmRlZCBiaWQgb24gb25lIGF1Y3Rpb24sIHBsYWNlZCBieSB0aGUgY29udHJhY3Qgb24gdGhlIHBvb2wncyBiZWhhbGYKClRoZSBjb250cmFjdCBwYXlzIGFzIGBiaWRfcGF5ZXJgIHdoaWxlIGBjcmVhdG9yYCBpcyB0aGUgYmlkZGVyIHdobwpjb250cm9scyB0aGUgVVJMLCBtZXRhZGF0YSBhbmQgZGlzcGxheSByaWdodHMgaWYgdGhlIHBvb2wgd2lucy4KCmBzcGVudGAgaXMgdGhlIHdpbm5pbmcgYmlkIHBhaWQgZnJvbSB0aGUgcG9vbCBhbmQgYHJlZnVuZGVkYCBhbnkKZG93bnRpbWUgcmVmdW5kIGNyZWRpdGVkIGJhY2sgdG8gaXQ7IGNvbnRyaWJ1dG9ycyB3aXRoZHJhdyB0aGUgcmVzdCBwcm8KcmF0YSBvbmNlIHRoZSBhdWN0aW9uIGlzIHNldHRsZWQuAAAAAAAAAAAEUG9vbAAAAAkAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAAAAAAAB2JhbGFuY2UAAAAACwAAAAAAAAAMY29udHJpYnV0b3JzAAAD6gAAABMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAHcG9vbF9pZAAAAAAGAAAAAAAAAAhyZWZ1bmRlZAAAAAsAAAAAAAAABHNsb3QAAAARAAAAAAAAAAVzcGVudAAAAAAAAAsAAAAAAAAAA3VybAAAAAAQ",
+        "AAAAAQAAAB9BIGNvbnRyaWJ1dG9yJ3Mgc3Rha2UgaW4gYSBwb29sAAAAAAAAAAAJUG9vbFNoYXJlAAAAAAAAAgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAl3aXRoZHJhd24AAAAAAAAL",
+        "AAAAAQAAADBTaW5nbGUgZW50cnkgaW4gYW4gYXVjdGlvbidzIG9uLWNoYWluIGJpZCBsYWRkZXIAAAAAAAAACUJpZFJlY29yZAAAAAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAA1wcmVmZXJyZWRfdXJsAAAAAAAAEAAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAAC9MaWZldGltZSBhZ2dyZWdhdGVzIGZvciBhIHNpbmdsZSBiaWRkZXIgYWRkcmVzcwAAAAAAAAAAC0JpZGRlclN0YXRzAAAAAAUAAAAAAAAADGF1Y3Rpb25zX3dvbgAAAAQAAAAAAAAAC2JpZHNfcGxhY2VkAAAAAAQAAAAAAAAAC2xhc3Rfd2luX2lkAAAAAAYAAAAAAAAACnRvdGFsX3BhaWQAAAAAAAsAAAAAAAAADnRvdGFsX3JlZnVuZGVkAAAAAAAL",
+        "AAAAAQAAAAAAAAAAAAAAEExlYWRlcmJvYXJkRW50cnkAAAACAAAAAAAAAAZiaWRkZXIAAAAAABMAAAAAAAAACnRvdGFsX3BhaWQAAAAAAAs=",
+        "AAAAAQAAAD5Pd25lci1tYW5hZ2VkIGZhbGxiYWNrIFVSTCBzaG93biB3aGlsZSBubyB3aW5uZXIgaXMgZGlzcGxheWluZwAAAAAAAAAAAAdIb3VzZUFkAAAAAAMAAAAAAAAAAmlkAAAAAAAEAAAAAAAAAAN1cmwAAAAAEAAAAAAAAAAGd2VpZ2h0AAAAAAAE",
+        "AAAAAQAAAERQYWlkIGRpc3BsYXkgc2xvdDsgd2luZG93cyBhcmUgcXVldWVkIGJhY2stdG8tYmFjayBhbmQgbmV2ZXIgb3ZlcmxhcAAAAAAAAAANRGlzcGxheVdpbmRvdwAAAAAAAAUAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAAAAAAACGVuZF90aW1lAAAABgAAAAAAAAAGaG9sZGVyAAAAAAATAAAAAAAAAApzdGFydF90aW1lAAAAAAAGAAAAAAAAAAN1cmwAAAAAEA==",
+        "AAAAAQAAAJRSZXdhcmQgcGFpZCBmcm9tIHByb2NlZWRzIHRvIHdob2V2ZXIgc2V0dGxlcyBhbiBleHBpcmVkIGF1Y3Rpb24KClRoZSByZXdhcmQgaXMgYGZpeGVkX2Ftb3VudGAgcGx1cyBgYnBzYCBvZiB0aGUgd2lubmluZyBiaWQsIGNhcHBlZCBhdApgbWF4X2Ftb3VudGAuAAAAAAAAAAxLZWVwZXJSZXdhcmQAAAADAAAAAAAAAANicHMAAAAABAAAAAAAAAAMZml4ZWRfYW1vdW50AAAACwAAAAAAAAAKbWF4X2Ftb3VudAAAAAAACw==",
+        "AAAAAQAAAHxBZGRyZXNzIGEgVVJMIGhvbGRlciBoYXMgZGVsZWdhdGVkIFVSTCBhbmQgbWV0YWRhdGEgZWRpdHMgdG8KCk9ubHkgaG9ub3VyZWQgd2hpbGUgYGhvbGRlcmAgc3RpbGwgY29udHJvbHMgdGhlIGF1Y3Rpb24ncyBVUkwuAAAAAAAAAApVcmxNYW5hZ2VyAAAAAAACAAAAAAAAAAZob2xkZXIAAAAAABMAAAAAAAAAB21hbmFnZXIAAAAAEw==",
+        "AAAAAQAAAQ9Ib2xkZXIgb2YgYSBzbG90IGluIEhhcmJlcmdlciBtb2RlCgpUYXggYWNjcnVlcyBldmVyeSBzZWNvbmQgb24gdGhlIHNlbGYtYXNzZXNzZWQgYHByaWNlYCBzaW5jZQpgYXNzZXNzZWRfYXRgLCBhdCB0aGUgc2xvdCdzIGRhaWx5IHJhdGUuIGB0YXhfcGFpZGAgaXMgd2hhdCBoYXMgYWxyZWFkeQpiZWVuIGNvbGxlY3RlZCBmcm9tIGBkZXBvc2l0YCBzaW5jZSB0aGVuOyB0aGUgaG9sZGluZyBsYXBzZXMgb25jZSB0aGUKYWNjcnVlZCB0YXggZXhoYXVzdHMgdGhlIGRlcG9zaXQuAAAAAAAAAAAQSGFyYmVyZ2VySG9sZGluZwAAAAYAAAAAAAAAC2Fzc2Vzc2VkX2F0AAAAAAYAAAAAAAAAB2RlcG9zaXQAAAAACwAAAAAAAAAGaG9sZGVyAAAAAAATAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAACHRheF9wYWlkAAAACwAAAAAAAAADdXJsAAAAABA=",
+        "AAAAAQAAAK5SZW1haW5pbmcgZGlzcGxheSByaWdodHMgb2YgYSBzZXR0bGVkIGF1Y3Rpb24gb2ZmZXJlZCBmb3Igc2FsZQoKYHByaWNlYCBpcyBpbiB1bml0cyBvZiB0aGUgY29udHJhY3QgdG9rZW4uIFRoZSBsaXN0aW5nIGxhcHNlcyB3aGVuIHRoZQp3aW5kb3cgZW5kcyBvciB0aGUgcmlnaHRzIGNoYW5nZSBoYW5kcy4AAAAAAAAAAAAHTGlzdGluZwAAAAAEAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAAAAAAlsaXN0ZWRfYXQAAAAAAAAGAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAABnNlbGxlcgAAAAAAEw==",
+        "AAAAAQAAAHRTY2FucyBhdHRlc3RlZCBieSB0aGUgcmVwb3J0ZXIgZm9yIG9uZSBob3VyIG9mIGEgZGlzcGxheSB3aW5kb3cKCmBidWNrZXRgIGNvdW50cyBob3VycyBmcm9tIHRoZSBzdGFydCBvZiB0aGUgd2luZG93LgAAAAAAAAAKU2NhbkJ1Y2tldAAAAAAAAwAAAAAAAAAGYnVja2V0AAAAAAAEAAAAAAAAAAtyZXBvcnRlZF9hdAAAAAAGAAAAAAAAAAVzY2FucwAAAAAAAAQ=",
+        "AAAAAQAAADlQZXJpb2QgZHVyaW5nIHdoaWNoIGEgc2xvdCdzIHBoeXNpY2FsIGRpc3BsYXkgd2FzIG9mZmxpbmUAAAAAAAAAAAAAEERvd250aW1lSW50ZXJ2YWwAAAACAAAAAAAAAAhlbmRfdGltZQAAAAYAAAAAAAAACnN0YXJ0X3RpbWUAAAAAAAY=",
+        "AAAAAQAAAMtXaW5uaW5nIHByb2NlZWRzIGhlbGQgdW50aWwgdGhlIGF1Y3Rpb24ncyBkaXNwbGF5IHdpbmRvdyBlbmRzCgpXaXRoIGBsaW5lYXJfdmVzdGluZ2AgdGhlIHBsYXRmb3JtIG1heSBjbGFpbSBwcm9jZWVkcyBhcyBkaXNwbGF5IHRpbWUKaXMgZGVsaXZlcmVkOyBvdGhlcndpc2UgZXZlcnl0aGluZyBpcyByZWxlYXNlZCB3aGVuIHRoZSB3aW5kb3cgY2xvc2VzLgAAAAAAAAAADlByb2NlZWRzRXNjcm93AAAAAAAFAAAAAAAAAA5saW5lYXJfdmVzdGluZwAAAAAAAQAAAAAAAAAIcmVmdW5kZWQAAAALAAAAAAAAAAhyZWxlYXNlZAAAAAsAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAFdG90YWwAAAAAAAAL",
+        "AAAAAQAAADdMb2NrZWQgdnMgcmVsZWFzZWQgYWNjb3VudGluZyBmb3IgYW4gYXVjdGlvbidzIHByb2NlZWRzAAAAAAAAAAAOUHJvY2VlZHNTdGF0dXMAAAAAAAUAAAAAAAAACWNsYWltYWJsZQAAAAAAAAsAAAAAAAAABmxvY2tlZAAAAAAACwAAAAAAAAAIcmVmdW5kZWQAAAALAAAAAAAAAAhyZWxlYXNlZAAAAAsAAAAAAAAABXRvdGFsAAAAAAAACw==",
+        "AAAAAQAAAAAAAAAAAAAACFFSU3RhdHVzAAAABAAAAAAAAAALZmFsbGJhY2tfaWQAAAAD6AAAAAQAAAAAAAAACG1ldGFkYXRhAAAH0AAAAApRUk1ldGFkYXRhAAAAAAAAAAAABnNvdXJjZQAAAAAAEAAAAAAAAAAGc3RhdHVzAAAAAAAQ",
+        "AAAAAQAAAAAAAAAAAAAADENvbnRyYWN0SW5mbwAAAAYAAAAAAAAAD2F1Y3Rpb25fY291bnRlcgAAAAAGAAAAAAAAAA1rZWVwZXJfcmV3YXJkAAAAAAAH0AAAAAxLZWVwZXJSZXdhcmQAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAPcGxhdGZvcm1fd2FsbGV0AAAAABMAAAAAAAAABXNsb3RzAAAAAAAD6gAAABEAAAAAAAAADXRva2VuX2FkZHJlc3MAAAAAAAAT",
+        "AAAAAQAAAAAAAAAAAAAAClNsb3RDb25maWcAAAAAAAcAAAAAAAAAD2NvbnRpbnVvdXNfbW9kZQAAAAABAAAAAAAAABFoYXJiZXJnZXJfdGF4X2JwcwAAAAAAA+gAAAAEAAAAAAAAAA9pbmNyZW1lbnRfdGllcnMAAAAD6gAAB9AAAAANSW5jcmVtZW50VGllcgAAAAAAAAAAAAANbWF4X2JpZF9wYXJhbQAAAAAAAAsAAAAAAAAAEW1pbl9iaWRfaW5jcmVtZW50AAAAAAAACwAAAAAAAAAVbWluX2JpZF9pbmNyZW1lbnRfYnBzAAAAAAAABAAAAAAAAAAQbWluX3N0YXJ0aW5nX2JpZAAAAAs=",
         "AAAAAQAAAAAAAAAAAAAADkF1Y3Rpb25TdW1tYXJ5AAAAAAAGAAAAAAAAAA9jdXJyZW50X2F1Y3Rpb24AAAAH0AAAAAdBdWN0aW9uAAAAAAAAAAANaGFzX2FjdGl2ZV9xcgAAAAAAAAEAAAAAAAAACWlzX2FjdGl2ZQAAAAAAAAEAAAAAAAAADGxhc3RfYXVjdGlvbgAAB9AAAAAHQXVjdGlvbgAAAAAAAAAABnFyX3VybAAAAAAAEAAAAAAAAAAOdGltZV9yZW1haW5pbmcAAAAAAAY=",
+        "AAAAAgAAADNBc3NldCBpZGVudGlmaWVyIGluIHRoZSBTRVAtNDAgcHJpY2UgZmVlZCBpbnRlcmZhY2UAAAAAAAAAAAVBc3NldAAAAAAAAAIAAAABAAAAAAAAAAdTdGVsbGFyAAAAAAEAAAATAAAAAQAAAAAAAAAFT3RoZXIAAAAAAAABAAAAEQ==",
+        "AAAAAQAAAC9QcmljZSByZWNvcmQgaW4gdGhlIFNFUC00MCBwcmljZSBmZWVkIGludGVyZmFjZQAAAAAAAAAACVByaWNlRGF0YQAAAAAAAAIAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAAMlTdGFydGluZyBiaWQgYW5kIGZsYXQgaW5jcmVtZW50IGluIHRoZSBwcmljZSBvcmFjbGUncyBiYXNlIGFzc2V0CgpBbW91bnRzIGFyZSBzY2FsZWQgYnkgdGhlIG9yYWNsZSdzIGRlY2ltYWxzIGFuZCBjb252ZXJ0ZWQgdG8gdGhlCmNvbnRyYWN0IHRva2VuIGF0IHRoZSBsYXRlc3QgcHJpY2Ugd2hlbmV2ZXIgYSBtaW5pbXVtIGJpZCBpcyBjb21wdXRlZC4AAAAAAAAAAAAACkZpYXRGbG9vcnMAAAAAAAIAAAAAAAAAEW1pbl9iaWRfaW5jcmVtZW50AAAAAAAACwAAAAAAAAAQbWluX3N0YXJ0aW5nX2JpZAAAAAs=",
         "AAAAAAAAABdJbml0aWFsaXplIHRoZSBjb250cmFjdAAAAAAKaW5pdGlhbGl6ZQAAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAA9wbGF0Zm9ybV93YWxsZXQAAAAAEwAAAAAAAAANdG9rZW5fYWRkcmVzcwAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAACJQbGFjZSBhIGJpZCBvbiB0aGUgY3VycmVudCBhdWN0aW9uAAAAAAAJcGxhY2VfYmlkAAAAAAAAAwAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAADXByZWZlcnJlZF91cmwAAAAAAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAACBTdGFydCBhIG5ldyBhdWN0aW9uIChvbmx5IG93bmVyKQAAAA1zdGFydF9hdWN0aW9uAAAAAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAABdFbmQgdGhlIGN1cnJlbnQgYXVjdGlvbgAAAAALZW5kX2F1Y3Rpb24AAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAABlHZXQgdGhlIFFSIFVSTCB0byBkaXNwbGF5AAAAAAAACmdldF9xcl91cmwAAAAAAAAAAAABAAAAEA==",
-        "AAAAAAAAAChHZXQgY3VycmVudCBhdWN0aW9uIFVSTCAoZHVyaW5nIGJpZGRpbmcpAAAAF2dldF9jdXJyZW50X2F1Y3Rpb25fdXJsAAAAAAAAAAABAAAAEA==",
-        "AAAAAAAAACFDaGVjayBpZiB0aGVyZSdzIGFuIGFjdGl2ZSBRUiBVUkwAAAAAAAARaGFzX2FjdGl2ZV9xcl91cmwAAAAAAAAAAAAAAQAAAAE=",
-        "AAAAAAAAABxHZXQgUVIgVVJMIHN0YXR1cyBhbmQgc291cmNlAAAAEWdldF9xcl91cmxfc3RhdHVzAAAAAAAAAAAAAAEAAAfQAAAACFFSU3RhdHVz",
-        "AAAAAAAAABZHZXQgUVIgVVJMIGV4cGlyeSB0aW1lAAAAAAAWZ2V0X3FyX3VybF9leHBpcnlfdGltZQAAAAAAAAAAAAEAAAAG",
-        "AAAAAAAAACVHZXQgdGltZSByZW1haW5pbmcgaW4gY3VycmVudCBhdWN0aW9uAAAAAAAAEmdldF90aW1lX3JlbWFpbmluZwAAAAAAAAAAAAEAAAAG",
-        "AAAAAAAAACRDaGVjayBpZiBhdWN0aW9uIGlzIGN1cnJlbnRseSBhY3RpdmUAAAARaXNfYXVjdGlvbl9hY3RpdmUAAAAAAAAAAAAAAQAAAAE=",
-        "AAAAAAAAABtHZXQgY3VycmVudCBhdWN0aW9uIGRldGFpbHMAAAAAE2dldF9jdXJyZW50X2F1Y3Rpb24AAAAAAAAAAAEAAAfQAAAAB0F1Y3Rpb24A",
-        "AAAAAAAAABpHZXQgbGFzdCBjb21wbGV0ZWQgYXVjdGlvbgAAAAAAEGdldF9sYXN0X2F1Y3Rpb24AAAAAAAAAAQAAB9AAAAAHQXVjdGlvbgA=",
-        "AAAAAAAAAE1HZXQgc3BlY2lmaWMgYXVjdGlvbiBieSBJRCAobGltaXRlZCBpbXBsZW1lbnRhdGlvbiBkdWUgdG8gZm9ybWF0ISBjb25zdHJhaW50KQAAAAAAAAtnZXRfYXVjdGlvbgAAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+gAAAfQAAAAB0F1Y3Rpb24A",
+        "AAAAAAAAAD9SZWdpc3RlciBhIG5ldyBRUiBzbG90LCBvbmUgcGVyIHBoeXNpY2FsIHBsYWNlbWVudCAob25seSBvd25lcikAAAAAC2NyZWF0ZV9zbG90AAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAABlMaXN0IGFsbCByZWdpc3RlcmVkIHNsb3RzAAAAAAAACmxpc3Rfc2xvdHMAAAAAAAAAAAABAAAD6gAAABE=",
+        "AAAAAAAAASlQbGFjZSBhIGJpZCBvbiB0aGUgY3VycmVudCBhdWN0aW9uCgpJZiBhbm90aGVyIGJpZGRlciBob2xkcyBhIHByb3h5IGJpZCB3aXRoIGEgY2VpbGluZyBhdCBvciBhYm92ZQpgYW1vdW50YCwgdGhlIHByb3h5IGNvdW50ZXJzIGF1dG9tYXRpY2FsbHk6IHRoZSB2aXNpYmxlIHByaWNlIHJpc2VzCmJ1dCB0aGUgcHJveHkgYmlkZGVyIHN0YXlzIGluIHRoZSBsZWFkIGFuZCBub3RoaW5nIGlzIHRha2VuIGZyb20KYGJpZGRlcmAsIHdobyBtdXN0IHN0aWxsIGhvbGQgYGFtb3VudGAgZm9yIHRoZSBjb3VudGVyIHRvIGhhcHBlbi4AAAAAAAAJcGxhY2VfYmlkAAAAAAAABAAAAAAAAAAEc2xvdAAAABEAAAAAAAAABmJpZGRlcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAA1wcmVmZXJyZWRfdXJsAAAAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAOBQbGFjZSBhIGJpZCBwYWlkIGJ5IGBwYXllcmAgb24gYmVoYWxmIG9mIGBiZW5lZmljaWFyeWAKCkxldHMgYSBjb21wYW55IGJ1eSBhIHNsb3QgZm9yIHNvbWVvbmUgZWxzZTogYGJlbmVmaWNpYXJ5YCBjb250cm9scwp0aGUgVVJMIGFuZCBpdHMgbWV0YWRhdGEsIHdoaWxlIHJlZnVuZHMgZ28gYmFjayB0byBgcGF5ZXJgLgpHaWZ0aW5nIHRvIHRoZSBjdXJyZW50IGxlYWRlciBpcyByZWZ1c2VkLgAAAA5wbGFjZV9naWZ0X2JpZAAAAAAABQAAAAAAAAAEc2xvdAAAABEAAAAAAAAABXBheWVyAAAAAAAAEwAAAAAAAAALYmVuZWZpY2lhcnkAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAA1wcmVmZXJyZWRfdXJsAAAAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAARVQbGFjZSBhIGJpZCBmdW5kZWQgZnJvbSBgcGF5ZXJgJ3MgdG9rZW4gYWxsb3dhbmNlIHRvIGBzcGVuZGVyYAoKTGV0cyBhbiBhZ2VuY3kgb3IgcmVsYXllciBiaWQgb24gYSBjbGllbnQncyBiZWhhbGY6IGBiZW5lZmljaWFyeWAKY29udHJvbHMgdGhlIFVSTCBpZiB0aGUgYmlkIHdpbnMsIGFuZCBgcGF5ZXJgIGlzIHJlZnVuZGVkIGlmIGl0IGlzCm91dGJpZC4gQmlkZGluZyBmb3IgdGhlIGN1cnJlbnQgbGVhZGVyIHdpdGggc29tZW9uZSBlbHNlJ3MKYWxsb3dhbmNlIGlzIHJlZnVzZWQuAAAAAAAADnBsYWNlX2JpZF9mcm9tAAAAAAAGAAAAAAAAAARzbG90AAAAEQAAAAAAAAAHc3BlbmRlcgAAAAATAAAAAAAAAAVwYXllcgAAAAAAABMAAAAAAAAAC2JlbmVmaWNpYXJ5AAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAANcHJlZmVycmVkX3VybAAAAAAAABAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAANZQbGFjZSBhIGJpZCBwYWlkIGluIG9uZSBvZiB0aGUgYWNjZXB0ZWQgdG9rZW5zCgpgYW1vdW50YCBpcyBpbiB1bml0cyBvZiBgdG9rZW5gOyBpdCBpcyBjb252ZXJ0ZWQgdG8gdGhlIGNvbnRyYWN0CnRva2VuJ3MgdW5pdHMgdGhyb3VnaCB0aGUgcHJpY2Ugb3JhY2xlIHRvIHJhbmsgYWdhaW5zdCBvdGhlciBiaWRzLAphbmQgcmVmdW5kZWQgaW4gYHRva2VuYCBpZiBvdXRiaWQuAAAAAAAPcGxhY2VfdG9rZW5fYmlkAAAAAAUAAAAAAAAABHNsb3QAAAARAAAAAAAAAAZiaWRkZXIAAAAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAA1wcmVmZXJyZWRfdXJsAAAAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAATxQbGFjZSBhIHByb3h5IChtYXhpbXVtKSBiaWQgb24gdGhlIGN1cnJlbnQgYXVjdGlvbgoKVGhlIGZ1bGwgYG1heF9hbW91bnRgIGlzIGVzY3Jvd2VkIGFuZCB0aGUgY29udHJhY3QgYmlkcyBvbiB0aGUKYmlkZGVyJ3MgYmVoYWxmIHVwIHRvIHRoYXQgY2VpbGluZywgb25seSBldmVyIHBheWluZyBvbmUgaW5jcmVtZW50Cm92ZXIgdGhlIG5leHQgYmVzdCBiaWQuIFRoZSBjZWlsaW5nIGl0c2VsZiBpcyBuZXZlciBleHBvc2VkIHRocm91Z2gKYEF1Y3Rpb25gOyB1bnVzZWQgZXNjcm93IGlzIHJlZnVuZGVkIGF0IHNldHRsZW1lbnQgb3Igd2hlbiBvdXRiaWQuAAAAD3BsYWNlX3Byb3h5X2JpZAAAAAAEAAAAAAAAAARzbG90AAAAEQAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAAptYXhfYW1vdW50AAAAAAALAAAAAAAAAA1wcmVmZXJyZWRfdXJsAAAAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAMxPcGVuIGEgY3Jvd2RmdW5kZWQgcG9vbCBiaWRkaW5nIGZvciBgdXJsYCBvbiBhIHNsb3QncyBjdXJyZW50IGF1Y3Rpb24KClRoZSBVUkwgaXMgZml4ZWQ6IHdoaWxlIHRoZSBwb29sIGxlYWRzIGFuZCBvbmNlIGl0IHdpbnMsIGl0cyBVUkwsCm1ldGFkYXRhIGFuZCBkaXNwbGF5IHJpZ2h0cyBjYW5ub3QgYmUgY2hhbmdlZCwgdHJhbnNmZXJyZWQgb3Igc29sZC4AAAAJb3Blbl9wb29sAAAAAAAAAwAAAAAAAAAEc2xvdAAAABEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAADdXJsAAAAABAAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAQlDb250cmlidXRlIGNvbnRyYWN0IHRva2VucyB0byBhIHBvb2wKClRoZSBwb29sIGJpZHMgYXMgYSBzaW5nbGUgYmlkZGVyLCBkZWZlbmRpbmcgaXRzIGxlYWQgd2l0aCB0aGUgcG9vbGVkCmJhbGFuY2UgYXMgaXRzIGNlaWxpbmcgYW5kIG9ubHkgcGF5aW5nIG9uZSBpbmNyZW1lbnQgb3ZlciB0aGUgbmV4dApiZXN0IGJpZC4gRWFjaCBjb250cmlidXRpb24gcmFpc2VzIHRoZSBjZWlsaW5nIGFuZCwgaWYgdGhlIHBvb2wgaXMKbm90IGxlYWRpbmcsIGJpZHMgYWdhaW4uAAAAAAAACmNvbnRyaWJ1dGUAAAAAAAMAAAAAAAAAB3Bvb2xfaWQAAAAABgAAAAAAAAALY29udHJpYnV0b3IAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAARBXaXRoZHJhdyBhIGNvbnRyaWJ1dG9yJ3MgcHJvIHJhdGEgc2hhcmUgb2Ygd2hhdCBhIHBvb2wgZGlkIG5vdCBzcGVuZAoKQXZhaWxhYmxlIG9uY2UgdGhlIHBvb2wncyBhdWN0aW9uIGlzIHNldHRsZWQ6IHRoZSBmdWxsIGNvbnRyaWJ1dGlvbgppZiB0aGUgcG9vbCBsb3N0LCB0aGUgdW5zcGVudCByZW1haW5kZXIgaWYgaXQgd29uLiBEb3dudGltZSByZWZ1bmRzCm9uIGEgd2lubmluZyBwb29sJ3MgZGlzcGxheSBjYW4gYmUgd2l0aGRyYXduIGFzIHRoZXkgYXJlIGNyZWRpdGVkLgAAABJ3aXRoZHJhd19mcm9tX3Bvb2wAAAAAAAIAAAAAAAAAB3Bvb2xfaWQAAAAABgAAAAAAAAALY29udHJpYnV0b3IAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAASBBdHRhY2ggYWQgY2FyZCBtZXRhZGF0YSB0byBhbiBhdWN0aW9uJ3MgVVJMCgpDYWxsYWJsZSBieSB0aGUgbGVhZGluZyBiaWRkZXIgd2hpbGUgYmlkZGluZyBpcyBvcGVuIGFuZCBieSB0aGUKd2lubmVyIHVudGlsIHRoZWlyIGRpc3BsYXkgd2luZG93IGVuZHMsIG9yIGJ5IHRoZWlyIFVSTCBtYW5hZ2VyLgpNZXRhZGF0YSBpcyBjbGVhcmVkIHdoZW5ldmVyIGEgZGlmZmVyZW50IGJpZGRlciB0YWtlcyB0aGUgbGVhZC4KQSBwb29sJ3MgYmlkIGtlZXBzIHRoZSBjb250ZW50IGl0IHdhcyBvcGVuZWQgd2l0aC4AAAAPc2V0X3FyX21ldGFkYXRhAAAAAAQAAAAAAAAABHNsb3QAAAARAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACG1ldGFkYXRhAAAH0AAAAApRUk1ldGFkYXRhAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAGhDaGFuZ2UgdGhlIFVSTCBvZiBhIGxlYWRpbmcgYmlkIG9yIG9mIGEgd2lubmVyJ3MgZGlzcGxheSB3aW5kb3cKClNhbWUgYWNjZXNzIHJ1bGVzIGFzIGBzZXRfcXJfbWV0YWRhdGFgLgAAAAp1cGRhdGVfdXJsAAAAAAAEAAAAAAAAAARzbG90AAAAEQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAN1cmwAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAIVMZXQgYG1hbmFnZXJgIGVkaXQgdGhlIFVSTCBhbmQgbWV0YWRhdGEgb2YgYW4gYXVjdGlvbiB0aGUgY2FsbGVyCmxlYWRzIG9yIGhvbGRzIHRoZSBkaXNwbGF5IHJpZ2h0cyBvZiwgcmVwbGFjaW5nIGFueSBwcmV2aW91cyBtYW5hZ2VyAAAAAAAAD2FwcG9pbnRfbWFuYWdlcgAAAAADAAAAAAAAAARzbG90AAAAEQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAHbWFuYWdlcgAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAFRSZXZva2UgdGhlIFVSTCBtYW5hZ2VyIG9mIGFuIGF1Y3Rpb24gdGhlIGNhbGxlciBsZWFkcyBvciBob2xkcyB0aGUKZGlzcGxheSByaWdodHMgb2YAAAAOcmV2b2tlX21hbmFnZXIAAAAAAAIAAAAAAAAABHNsb3QAAAARAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAARNUcmFuc2ZlciB0aGUgcmVtYWluaW5nIGRpc3BsYXkgcmlnaHRzIG9mIGEgc2V0dGxlZCBhdWN0aW9uCgpUaGUgbmV3IGhvbGRlciBjb250cm9scyB0aGUgVVJMIGFuZCBtZXRhZGF0YSB1bnRpbCB0aGUgd2luZG93IGVuZHM7CmFueSBtYW5hZ2VyIGFwcG9pbnRlZCBieSB0aGUgcHJldmlvdXMgaG9sZGVyIGxvc2VzIGFjY2Vzcy4gUmVmdW5kcwphbmQgcHJvY2VlZHMgYXJlIHVuYWZmZWN0ZWQuIEEgcG9vbCdzIGRpc3BsYXkgcmlnaHRzIHN0YXkgd2l0aCBpdHMKY29udHJpYnV0b3JzLgAAAAAQdHJhbnNmZXJfZGlzcGxheQAAAAIAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAAAAAAAAnRvAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAKVMaXN0IHRoZSByZW1haW5pbmcgZGlzcGxheSByaWdodHMgb2YgYSBzZXR0bGVkIGF1Y3Rpb24gZm9yIHNhbGUgYXQKYHByaWNlYCBjb250cmFjdCB0b2tlbnMsIHJlcGxhY2luZyBhbnkgcHJldmlvdXMgbGlzdGluZwoKTm90IGF2YWlsYWJsZSBmb3IgYXVjdGlvbnMgd29uIGJ5IGEgcG9vbC4AAAAAAAAMbGlzdF9kaXNwbGF5AAAAAgAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAC9XaXRoZHJhdyBhIGRpc3BsYXkgcmlnaHRzIGxpc3RpbmcgKG9ubHkgc2VsbGVyKQAAAAAOY2FuY2VsX2xpc3RpbmcAAAAAAAEAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAI5CdXkgbGlzdGVkIGRpc3BsYXkgcmlnaHRzIGF0IHRoZSBhc2sgcHJpY2UKClRoZSBzZWxsZXIgcmVjZWl2ZXMgdGhlIHByaWNlIG1pbnVzIHRoZSBwbGF0Zm9ybSByb3lhbHR5IGFuZCB0aGUKYnV5ZXIgYmVjb21lcyB0aGUgZGlzcGxheSBob2xkZXIuAAAAAAALYnV5X2xpc3RpbmcAAAAAAgAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAP5UYWtlIG92ZXIgYSBIYXJiZXJnZXIgc2xvdAoKVGhlIGJ1eWVyIHBheXMgdGhlIGN1cnJlbnQgaG9sZGVyIHRoZWlyIHNlbGYtYXNzZXNzZWQgcHJpY2UsIGFuZCB0aGUKaG9sZGVyJ3MgdW51c2VkIHRheCBkZXBvc2l0IGlzIHJlZnVuZGVkIHRvIHRoZW0uIEEgdmFjYW50IHNsb3QgaXMKZnJlZSB0byBjbGFpbS4gVGhlIGJ1eWVyIHNldHMgdGhlaXIgb3duIHByaWNlIGFuZCBwcmVwYXlzIGBkZXBvc2l0YApjb250cmFjdCB0b2tlbnMgb2YgdGF4LgAAAAAACmNsYWltX3Nsb3QAAAAAAAUAAAAAAAAABHNsb3QAAAARAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAADdXJsAAAAABAAAAAAAAAAB2RlcG9zaXQAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAADpTZWxmLWFzc2VzcyBhIG5ldyBwcmljZSBmb3IgYSBIYXJiZXJnZXIgc2xvdCAob25seSBob2xkZXIpAAAAAAATc2V0X2hhcmJlcmdlcl9wcmljZQAAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADhBZGQgdG8gdGhlIHRheCBkZXBvc2l0IG9mIGEgSGFyYmVyZ2VyIHNsb3QgKG9ubHkgaG9sZGVyKQAAAAp0b3BfdXBfdGF4AAAAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAINQYXkgdGhlIHRheCBhY2NydWVkIG9uIGEgSGFyYmVyZ2VyIHNsb3QgdG8gdGhlIHBsYXRmb3JtIHdhbGxldCwKdmFjYXRpbmcgdGhlIHNsb3QgaWYgdGhlIGRlcG9zaXQgaGFzIHJ1biBvdXQuIEFueW9uZSBtYXkgY2FsbCB0aGlzLgAAAAALY29sbGVjdF90YXgAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAL9SZXBvcnQgdGhlIHNjYW5zIGNvdW50ZWQgZHVyaW5nIG9uZSBob3VyIG9mIGEgc2V0dGxlZCBhdWN0aW9uJ3MKZGlzcGxheSB3aW5kb3cgKG9ubHkgc2NhbiByZXBvcnRlcikKCkJ1Y2tldHMgbXVzdCBiZSByZXBvcnRlZCBpbiBpbmNyZWFzaW5nIG9yZGVyIGFuZCBvbmx5IG9uY2UgdGhlIGhvdXIKdGhleSBjb3ZlciBoYXMgcGFzc2VkLgAAAAAMcmVwb3J0X3NjYW5zAAAAAwAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAAAAAAGYnVja2V0AAAAAAAEAAAAAAAAAAVzY2FucwAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAL9SZWNvcmQgYSBwZXJpb2QgdGhlIHNsb3QncyBkaXNwbGF5IHdhcyBvZmZsaW5lIGR1cmluZyBhIHNldHRsZWQKYXVjdGlvbidzIHdpbmRvdyAob25seSBkZXZpY2UgcmVwb3J0ZXIpCgpJbnRlcnZhbHMgYXJlIGNsaXBwZWQgdG8gdGhlIHdpbmRvdyBhbmQgbXVzdCBiZSByZXBvcnRlZCBpbiBvcmRlcgp3aXRob3V0IG92ZXJsYXBwaW5nLgAAAAAPcmVwb3J0X2Rvd250aW1lAAAAAAMAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAAAAAAACnN0YXJ0X3RpbWUAAAAAAAYAAAAAAAAACGVuZF90aW1lAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAOlSZWxlYXNlIGFuIGF1Y3Rpb24ncyBlc2Nyb3dlZCBwcm9jZWVkcyBvbmNlIGl0cyBkaXNwbGF5IHdpbmRvdyBlbmRzCgpUaGUgd2lubmVyIGlzIHJlZnVuZGVkIHBybyByYXRhIGZvciByZXBvcnRlZCBkb3dudGltZSwgbGVzcyBhbnl0aGluZwphbHJlYWR5IHZlc3RlZCB0byB0aGUgcGxhdGZvcm0sIGFuZCB0aGUgcmVzdCBnb2VzIHRvIHRoZSBwbGF0Zm9ybQp3YWxsZXQuIEFueW9uZSBtYXkgY2FsbCB0aGlzLgAAAAAAABRjbG9zZV9kaXNwbGF5X3dpbmRvdwAAAAEAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAQNQYXkgdGhlIHBsYXRmb3JtIHdoYXRldmVyIGhhcyB2ZXN0ZWQgb2YgYW4gYXVjdGlvbidzIHByb2NlZWRzCgpPbmx5IGxpbmVhcmx5IHZlc3RpbmcgZXNjcm93cyByZWxlYXNlIGFueXRoaW5nIGJlZm9yZSB0aGUgd2luZG93CmVuZHM7IGFmdGVyIHRoYXQgdGhpcyBjbG9zZXMgdGhlIHdpbmRvdyBsaWtlIGBjbG9zZV9kaXNwbGF5X3dpbmRvd2AuCkFueW9uZSBtYXkgY2FsbCB0aGlzLiBSZXR1cm5zIHRoZSBhbW91bnQgcGFpZCB0byB0aGUgcGxhdGZvcm0uAAAAAA5jbGFpbV9wcm9jZWVkcwAAAAAAAQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAKxEZXBvc2l0IGNvbnRyYWN0IHRva2VucyBpbnRvIHRoZSBjYWxsZXIncyBpbnRlcm5hbCBiYWxhbmNlCgpCaWRzIGNvdmVyZWQgYnkgdGhlIGJhbGFuY2UgYXJlIHJlc2VydmVkIGZyb20gaXQgaW5zdGVhZCBvZiBiZWluZwp0cmFuc2ZlcnJlZCwgYW5kIHJlZnVuZGVkIGludG8gaXQgd2hlbiBvdXRiaWQuAAAAB2RlcG9zaXQAAAAAAgAAAAAAAAAEZnJvbQAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAADtXaXRoZHJhdyBjb250cmFjdCB0b2tlbnMgZnJvbSB0aGUgY2FsbGVyJ3MgaW50ZXJuYWwgYmFsYW5jZQAAAAAId2l0aGRyYXcAAAACAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAACBTdGFydCBhIG5ldyBhdWN0aW9uIChvbmx5IG93bmVyKQAAAA1zdGFydF9hdWN0aW9uAAAAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAM9FbmQgdGhlIGN1cnJlbnQgYXVjdGlvbgoKQW55b25lIG1heSBzZXR0bGUgYW4gZXhwaXJlZCBhdWN0aW9uLiBUaGUgYGtlZXBlcmAgcmVjZWl2ZXMgdGhlCmNvbmZpZ3VyZWQga2VlcGVyIHJld2FyZCBvdXQgb2YgdGhlIHdpbm5pbmcgYmlkIGFuZCwgaW4gY29udGludW91cwptb2RlLCB0aGUgbmV4dCBhdWN0aW9uIGlzIHN0YXJ0ZWQgaW4gdGhlIHNhbWUgY2FsbC4AAAAAC2VuZF9hdWN0aW9uAAAAAAIAAAAAAAAABHNsb3QAAAARAAAAAAAAAAZrZWVwZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAIpHZXQgdGhlIFFSIFVSTCB0byBkaXNwbGF5CgpGYWxscyBiYWNrIHRvIHRoZSBIYXJiZXJnZXIgaG9sZGVyJ3MgVVJMLCB0aGVuIHRvIHRoZQpvd25lci1jb25maWd1cmVkIGhvdXNlIGFkcyB3aGlsZSBubyB3aW5uZXIgaXMgZGlzcGxheWluZy4AAAAAAApnZXRfcXJfdXJsAAAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAAQ",
+        "AAAAAAAAAChHZXQgY3VycmVudCBhdWN0aW9uIFVSTCAoZHVyaW5nIGJpZGRpbmcpAAAAF2dldF9jdXJyZW50X2F1Y3Rpb25fdXJsAAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAABA=",
+        "AAAAAAAAADxDaGVjayBpZiB0aGVyZSdzIGFuIGFjdGl2ZSB3aW5uZXIgb3IgSGFyYmVyZ2VyIGhvbGRlciBRUiBVUkwAAAARaGFzX2FjdGl2ZV9xcl91cmwAAAAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAAB",
+        "AAAAAAAAABxHZXQgUVIgVVJMIHN0YXR1cyBhbmQgc291cmNlAAAAEWdldF9xcl91cmxfc3RhdHVzAAAAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAH0AAAAAhRUlN0YXR1cw==",
+        "AAAAAAAAABZHZXQgUVIgVVJMIGV4cGlyeSB0aW1lAAAAAAAWZ2V0X3FyX3VybF9leHBpcnlfdGltZQAAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAABg==",
+        "AAAAAAAAAH9HZXQgdGhlIGRpc3BsYXkgd2luZG93IGN1cnJlbnRseSBiZWluZyBzZXJ2ZWQsIGlmIGFueQoKSW5jbHVkZXMgdGhlIHdpbmRvdyBvZiBhbiBleHBpcmVkIGF1Y3Rpb24gdGhhdCBoYXMgbm90IGJlZW4gc2V0dGxlZCB5ZXQuAAAAABNnZXRfY3VycmVudF9kaXNwbGF5AAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+gAAAfQAAAADURpc3BsYXlXaW5kb3cAAAA=",
+        "AAAAAAAAADVHZXQgdGhlIGN1cnJlbnQgYW5kIHVwY29taW5nIGRpc3BsYXkgd2luZG93cyBpbiBvcmRlcgAAAAAAABRnZXRfZGlzcGxheV9zY2hlZHVsZQAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+oAAAfQAAAADURpc3BsYXlXaW5kb3cAAAA=",
+        "AAAAAAAAACVHZXQgdGltZSByZW1haW5pbmcgaW4gY3VycmVudCBhdWN0aW9uAAAAAAAAEmdldF90aW1lX3JlbWFpbmluZwAAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAABg==",
+        "AAAAAAAAACRDaGVjayBpZiBhdWN0aW9uIGlzIGN1cnJlbnRseSBhY3RpdmUAAAARaXNfYXVjdGlvbl9hY3RpdmUAAAAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAAB",
+        "AAAAAAAAABtHZXQgY3VycmVudCBhdWN0aW9uIGRldGFpbHMAAAAAE2dldF9jdXJyZW50X2F1Y3Rpb24AAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAH0AAAAAdBdWN0aW9uAA==",
+        "AAAAAAAAABpHZXQgbGFzdCBjb21wbGV0ZWQgYXVjdGlvbgAAAAAAEGdldF9sYXN0X2F1Y3Rpb24AAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAfQAAAAB0F1Y3Rpb24A",
+        "AAAAAAAAAB1HZXQgYSBjb21wbGV0ZWQgYXVjdGlvbiBieSBJRAAAAAAAAAtnZXRfYXVjdGlvbgAAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+gAAAfQAAAAB0F1Y3Rpb24A",
         "AAAAAAAAABNHZXQgYXVjdGlvbiBjb3VudGVyAAAAABNnZXRfYXVjdGlvbl9jb3VudGVyAAAAAAAAAAABAAAABg==",
         "AAAAAAAAABhHZXQgY29udHJhY3QgaW5mb3JtYXRpb24AAAARZ2V0X2NvbnRyYWN0X2luZm8AAAAAAAAAAAAAAQAAB9AAAAAMQ29udHJhY3RJbmZv",
-        "AAAAAAAAAC9HZXQgYXVjdGlvbiBzdW1tYXJ5IChldmVyeXRoaW5nIGZyb250ZW5kIG5lZWRzKQAAAAATZ2V0X2F1Y3Rpb25fc3VtbWFyeQAAAAAAAAAAAQAAB9AAAAAOQXVjdGlvblN1bW1hcnkAAA==",
-        "AAAAAAAAAClDYWxjdWxhdGUgbWluaW11bSBiaWQgZm9yIGN1cnJlbnQgYXVjdGlvbgAAAAAAAA9nZXRfbWluaW11bV9iaWQAAAAAAAAAAAEAAAAL",
-        "AAAAAAAAADpHZXQgYXVjdGlvbiBoaXN0b3J5IChzaW1wbGlmaWVkIC0gcmV0dXJucyBsYXN0IDUgYXVjdGlvbnMpAAAAAAATZ2V0X2F1Y3Rpb25faGlzdG9yeQAAAAAAAAAAAQAAA+oAAAfQAAAAB0F1Y3Rpb24A",
-        "AAAAAAAAACZTZXQgbWluaW11bSBiaWQgaW5jcmVtZW50IChvbmx5IG93bmVyKQAAAAAAFXNldF9taW5fYmlkX2luY3JlbWVudAAAAAAAAAEAAAAAAAAADW5ld19pbmNyZW1lbnQAAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAACVTZXQgbWluaW11bSBzdGFydGluZyBiaWQgKG9ubHkgb3duZXIpAAAAAAAAFHNldF9taW5fc3RhcnRpbmdfYmlkAAAAAQAAAAAAAAAQbmV3X3N0YXJ0aW5nX2JpZAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAChHZXQgYSBzbG90J3MgYmlkIHBhcmFtZXRlcnMgYW5kIHNldHRpbmdzAAAAD2dldF9zbG90X2NvbmZpZwAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAfQAAAAClNsb3RDb25maWcAAA==",
+        "AAAAAAAAAC9HZXQgYXVjdGlvbiBzdW1tYXJ5IChldmVyeXRoaW5nIGZyb250ZW5kIG5lZWRzKQAAAAATZ2V0X2F1Y3Rpb25fc3VtbWFyeQAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAfQAAAADkF1Y3Rpb25TdW1tYXJ5AAA=",
+        "AAAAAAAAAJlDYWxjdWxhdGUgbWluaW11bSBiaWQgZm9yIGN1cnJlbnQgYXVjdGlvbgoKRmFpbHMgd2l0aCBgT3JhY2xlU3RhbGVgIGlmIHRoZSBzbG90J3MgZmxvb3JzIGFyZSBzZXQgaW4gZmlhdCBhbmQKdGhlIG9yYWNsZSBwcmljZSBpcyB0b28gb2xkIHRvIGNvbnZlcnQgdGhlbS4AAAAAAAAPZ2V0X21pbmltdW1fYmlkAAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAACpHZXQgdGhlIHRpZXJlZCBtaW5pbXVtIGJpZCBpbmNyZW1lbnQgdGFibGUAAAAAABNnZXRfaW5jcmVtZW50X3RpZXJzAAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+oAAAfQAAAADUluY3JlbWVudFRpZXIAAAA=",
+        "AAAAAAAAARBHZXQgYSBwYWdlIG9mIGFuIGF1Y3Rpb24ncyBiaWQgbGFkZGVyLCBvbGRlc3QgZmlyc3QKCmBjdXJzb3JgIGlzIHRoZSBpbmRleCBvZiB0aGUgZmlyc3QgYmlkIHRvIHJldHVybiBhbW9uZyBldmVyeSBiaWQKZXZlciBwbGFjZWQgb24gdGhlIGF1Y3Rpb24sIHNvIGl0IHN0YXlzIHZhbGlkIGFzIG9sZCBiaWRzIGFyZQpkcm9wcGVkLiBBIGN1cnNvciBiZWZvcmUgdGhlIG9sZGVzdCBrZXB0IGJpZCBzdGFydHMgYXQgdGhhdCBiaWQKKHNlZSBgZ2V0X2ZpcnN0X2JpZF9pbmRleGApLgAAAAhnZXRfYmlkcwAAAAMAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAAAAAAABmN1cnNvcgAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAACUJpZFJlY29yZAAAAA==",
+        "AAAAAAAAACxHZXQgdGhlIG51bWJlciBvZiBiaWRzIHN0b3JlZCBmb3IgYW4gYXVjdGlvbgAAAA1nZXRfYmlkX2NvdW50AAAAAAAAAQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAEAAAAE",
+        "AAAAAAAAAGdHZXQgdGhlIGluZGV4IG9mIHRoZSBvbGRlc3QgYmlkIHN0aWxsIHN0b3JlZCBmb3IgYW4gYXVjdGlvbiwgdGhlCmN1cnNvciB0byBzdGFydCBwYWdpbmcgYGdldF9iaWRzYCBmcm9tAAAAABNnZXRfZmlyc3RfYmlkX2luZGV4AAAAAAEAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAABAAAABA==",
+        "AAAAAAAAACRHZXQgbGlmZXRpbWUgc3RhdGlzdGljcyBmb3IgYSBiaWRkZXIAAAAQZ2V0X2JpZGRlcl9zdGF0cwAAAAEAAAAAAAAABmJpZGRlcgAAAAAAEwAAAAEAAAfQAAAAC0JpZGRlclN0YXRzAA==",
+        "AAAAAAAAADlHZXQgdGhlIHRvcCBiaWRkZXJzIGJ5IHRvdGFsIGFtb3VudCBwYWlkIGZvciB3b24gYXVjdGlvbnMAAAAAAAAPZ2V0X2xlYWRlcmJvYXJkAAAAAAAAAAABAAAD6gAAB9AAAAAQTGVhZGVyYm9hcmRFbnRyeQ==",
+        "AAAAAAAAACVHZXQgdGhlIGNvbmZpZ3VyZWQgZmFsbGJhY2sgaG91c2UgYWRzAAAAAAAADWdldF9ob3VzZV9hZHMAAAAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAPqAAAH0AAAAAdIb3VzZUFkAA==",
+        "AAAAAAAAADlHZXQgYSBzbG90J3MgbW9zdCByZWNlbnQgY29tcGxldGVkIGF1Y3Rpb25zLCBvbGRlc3QgZmlyc3QAAAAAAAATZ2V0X2F1Y3Rpb25faGlzdG9yeQAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAPqAAAH0AAAAAdBdWN0aW9uAA==",
+        "AAAAAAAAAD5HZXQgdGhlIHNjYW4gcmVwb3J0cyBzdWJtaXR0ZWQgZm9yIGFuIGF1Y3Rpb24sIGluIGJ1Y2tldCBvcmRlcgAAAAAAEGdldF9zY2FuX3JlcG9ydHMAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+oAAAfQAAAAClNjYW5CdWNrZXQAAA==",
+        "AAAAAAAAACtHZXQgdGhlIHRvdGFsIGF0dGVzdGVkIHNjYW5zIGZvciBhbiBhdWN0aW9uAAAAAA9nZXRfdG90YWxfc2NhbnMAAAAAAQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAEAAAAG",
+        "AAAAAAAAAEJHZXQgdGhlIHdpbm5pbmcgYmlkIGRpdmlkZWQgYnkgYXR0ZXN0ZWQgc2NhbnMgKDAgYmVmb3JlIGFueSBzY2FucykAAAAAABFnZXRfY29zdF9wZXJfc2NhbgAAAAAAAAEAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAABAAAACw==",
+        "AAAAAAAAADxHZXQgYW4gYWRkcmVzcydzIGludGVybmFsIGRlcG9zaXQgYmFsYW5jZSBpbiBjb250cmFjdCB0b2tlbnMAAAALZ2V0X2JhbGFuY2UAAAAAAQAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAERHZXQgdGhlIGFkZHJlc3MgY3VycmVudGx5IGFsbG93ZWQgdG8gbWFuYWdlIGFuIGF1Y3Rpb24ncyBVUkwsIGlmIGFueQAAAA9nZXRfdXJsX21hbmFnZXIAAAAAAgAAAAAAAAAEc2xvdAAAABEAAAAAAAAACmF1Y3Rpb25faWQAAAAAAAYAAAABAAAD6AAAABM=",
+        "AAAAAAAAADpHZXQgdGhlIGFkZHJlc3MgaG9sZGluZyBhIHNldHRsZWQgYXVjdGlvbidzIGRpc3BsYXkgcmlnaHRzAAAAAAASZ2V0X2Rpc3BsYXlfaG9sZGVyAAAAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAABxHZXQgYSBjcm93ZGZ1bmRlZCBwb29sIGJ5IGlkAAAACGdldF9wb29sAAAAAQAAAAAAAAAHcG9vbF9pZAAAAAAGAAAAAQAAA+gAAAfQAAAABFBvb2w=",
+        "AAAAAAAAADFHZXQgaG93IG11Y2ggYW4gYWRkcmVzcyBoYXMgY29udHJpYnV0ZWQgdG8gYSBwb29sAAAAAAAAFWdldF9wb29sX2NvbnRyaWJ1dGlvbgAAAAAAAAIAAAAAAAAAB3Bvb2xfaWQAAAAABgAAAAAAAAALY29udHJpYnV0b3IAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAEhHZXQgYSBIYXJiZXJnZXIgc2xvdCdzIGhvbGRlciwgcHJpY2UsIFVSTCBhbmQgcmVtYWluaW5nIGRlcG9zaXQsIGlmIGhlbGQAAAAVZ2V0X2hhcmJlcmdlcl9ob2xkaW5nAAAAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAD6AAAB9AAAAAQSGFyYmVyZ2VySG9sZGluZw==",
+        "AAAAAAAAAD9HZXQgdGhlIHNlY29uZHMgdW50aWwgYSBIYXJiZXJnZXIgaG9sZGVyJ3MgdGF4IGRlcG9zaXQgcnVucyBvdXQAAAAADmdldF90YXhfcnVud2F5AAAAAAABAAAAAAAAAARzbG90AAAAEQAAAAEAAAAG",
+        "AAAAAAAAADxHZXQgdGhlIG9wZW4gbGlzdGluZyBmb3IgYW4gYXVjdGlvbidzIGRpc3BsYXkgcmlnaHRzLCBpZiBhbnkAAAALZ2V0X2xpc3RpbmcAAAAAAQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAEAAAPoAAAH0AAAAAdMaXN0aW5nAA==",
+        "AAAAAAAAAEFHZXQgdGhlIHBsYXRmb3JtIHJveWFsdHkgb24gZGlzcGxheSByaWdodHMgc2FsZXMsIGluIGJhc2lzIHBvaW50cwAAAAAAABNnZXRfbGlzdGluZ19yb3lhbHR5AAAAAAAAAAABAAAABA==",
+        "AAAAAAAAADtHZXQgdGhlIHRva2VucyBhY2NlcHRlZCBmb3IgYmlkcyBiZXNpZGVzIHRoZSBjb250cmFjdCB0b2tlbgAAAAATZ2V0X2FjY2VwdGVkX3Rva2VucwAAAAAAAAAAAQAAA+oAAAAT",
+        "AAAAAAAAAEJHZXQgdGhlIHByaWNlIG9yYWNsZSB1c2VkIHRvIHZhbHVlIGJpZHMgaW4gYWNjZXB0ZWQgdG9rZW5zLCBpZiBhbnkAAAAAABBnZXRfcHJpY2Vfb3JhY2xlAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAD9HZXQgdGhlIG1pbmltdW0gYmlkIGZvciB0aGUgY3VycmVudCBhdWN0aW9uIGluIHVuaXRzIG9mIGB0b2tlbmAAAAAAFWdldF9taW5pbXVtX3Rva2VuX2JpZAAAAAAAAAIAAAAAAAAABHNsb3QAAAARAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAADdHZXQgYSBzbG90J3MgZmlhdC1kZW5vbWluYXRlZCBiaWQgZmxvb3JzLCBpZiBjb25maWd1cmVkAAAAAA9nZXRfZmlhdF9mbG9vcnMAAAAAAQAAAAAAAAAEc2xvdAAAABEAAAABAAAD6AAAB9AAAAAKRmlhdEZsb29ycwAA",
+        "AAAAAAAAAERHZXQgdGhlIG1heGltdW0gYWdlIGluIHNlY29uZHMgb2YgYW4gb3JhY2xlIHByaWNlIGJlZm9yZSBpdCBpcyBzdGFsZQAAABJnZXRfb3JhY2xlX21heF9hZ2UAAAAAAAAAAAABAAAABg==",
+        "AAAAAAAAAENHZXQgdGhlIGRvd250aW1lIGludGVydmFscyByZXBvcnRlZCBmb3IgYW4gYXVjdGlvbidzIGRpc3BsYXkgd2luZG93AAAAAAxnZXRfZG93bnRpbWUAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAA+oAAAfQAAAAEERvd250aW1lSW50ZXJ2YWw=",
+        "AAAAAAAAADlHZXQgdGhlIHRvdGFsIHNlY29uZHMgb2YgcmVwb3J0ZWQgZG93bnRpbWUgZm9yIGFuIGF1Y3Rpb24AAAAAAAASZ2V0X3RvdGFsX2Rvd250aW1lAAAAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAAAY=",
+        "AAAAAAAAADtHZXQgdGhlIHByb2NlZWRzIHN0aWxsIGhlbGQgZm9yIGFuIGF1Y3Rpb24ncyBkaXNwbGF5IHdpbmRvdwAAAAAVZ2V0X2VzY3Jvd2VkX3Byb2NlZWRzAAAAAAAAAQAAAAAAAAAKYXVjdGlvbl9pZAAAAAAABgAAAAEAAAAL",
+        "AAAAAAAAAERHZXQgbG9ja2VkLCBjbGFpbWFibGUsIHJlbGVhc2VkIGFuZCByZWZ1bmRlZCBwcm9jZWVkcyBmb3IgYW4gYXVjdGlvbgAAAAxnZXRfcHJvY2VlZHMAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAGAAAAAQAAB9AAAAAOUHJvY2VlZHNTdGF0dXMAAA==",
+        "AAAAAAAAADlHZXQgdGhlIGFkZHJlc3MgYWxsb3dlZCB0byByZXBvcnQgZGV2aWNlIGRvd250aW1lLCBpZiBhbnkAAAAAAAATZ2V0X2RldmljZV9yZXBvcnRlcgAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAC9HZXQgdGhlIGFkZHJlc3MgYWxsb3dlZCB0byByZXBvcnQgc2NhbnMsIGlmIGFueQAAAAARZ2V0X3NjYW5fcmVwb3J0ZXIAAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAACZTZXQgbWluaW11bSBiaWQgaW5jcmVtZW50IChvbmx5IG93bmVyKQAAAAAAFXNldF9taW5fYmlkX2luY3JlbWVudAAAAAAAAAIAAAAAAAAABHNsb3QAAAARAAAAAAAAAA1uZXdfaW5jcmVtZW50AAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAACVTZXQgbWluaW11bSBzdGFydGluZyBiaWQgKG9ubHkgb3duZXIpAAAAAAAAFHNldF9taW5fc3RhcnRpbmdfYmlkAAAAAgAAAAAAAAAEc2xvdAAAABEAAAAAAAAAEG5ld19zdGFydGluZ19iaWQAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAMZTZXQgcGVyY2VudGFnZS1iYXNlZCBtaW5pbXVtIGJpZCBpbmNyZW1lbnQgaW4gYmFzaXMgcG9pbnRzIChvbmx5IG93bmVyKQoKVGhlIGVmZmVjdGl2ZSBpbmNyZW1lbnQgaXMgdGhlIGxhcmdlciBvZiB0aGUgZmxhdCBpbmNyZW1lbnQgYW5kIHRoaXMKcGVyY2VudGFnZSBvZiB0aGUgY3VycmVudCBoaWdoZXN0IGJpZC4gWmVybyBkaXNhYmxlcyBpdC4AAAAAABlzZXRfbWluX2JpZF9pbmNyZW1lbnRfYnBzAAAAAAAAAgAAAAAAAAAEc2xvdAAAABEAAAAAAAAAB25ld19icHMAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAOFTZXQgdGhlIHRpZXJlZCBtaW5pbXVtIGJpZCBpbmNyZW1lbnQgdGFibGUgKG9ubHkgb3duZXIpCgpUaWVycyBtdXN0IGJlIHNvcnRlZCBieSBzdHJpY3RseSBhc2NlbmRpbmcgYG1pbl9iaWRgLiBXaGlsZSBhIHRpZXIKbWF0Y2hlcyB0aGUgY3VycmVudCBoaWdoZXN0IGJpZCBpdHMgaW5jcmVtZW50IHJlcGxhY2VzIHRoZSBmbGF0IG9uZS4KQW4gZW1wdHkgdGFibGUgZGlzYWJsZXMgdGllcmluZy4AAAAAAAATc2V0X2luY3JlbWVudF90aWVycwAAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAAFdGllcnMAAAAAAAPqAAAH0AAAAA1JbmNyZW1lbnRUaWVyAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAElTZXQgYSBzaW5nbGUgZmFsbGJhY2sgVVJMIHNob3duIHdoZW4gbm8gd2lubmVyIGlzIGRpc3BsYXlpbmcgKG9ubHkgb3duZXIpAAAAAAAAEHNldF9mYWxsYmFja191cmwAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAADdXJsAAAAABAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAANNTZXQgdGhlIHdlaWdodGVkIGhvdXNlIGFkIHJvdGF0aW9uIHVzZWQgYXMgZmFsbGJhY2sgKG9ubHkgb3duZXIpCgpFbnRyaWVzIHJvdGF0ZSBldmVyeSBgSE9VU0VfQURfUk9UQVRJT05fUEVSSU9EYCwgZWFjaCByZWNlaXZpbmcgYQpzaGFyZSBvZiBwZXJpb2RzIHByb3BvcnRpb25hbCB0byBpdHMgd2VpZ2h0LiBBbiBlbXB0eSBsaXN0IGNsZWFycyB0aGUKZmFsbGJhY2suAAAAAA1zZXRfaG91c2VfYWRzAAAAAAAAAgAAAAAAAAAEc2xvdAAAABEAAAAAAAAACWhvdXNlX2FkcwAAAAAAA+oAAAfQAAAAB0hvdXNlQWQAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAKZTZXQgdGhlIHRva2VucyBhY2NlcHRlZCBmb3IgYmlkcyBiZXNpZGVzIHRoZSBjb250cmFjdCB0b2tlbiAob25seSBvd25lcikKCkxlYWRpbmcgYmlkcyBhbHJlYWR5IHBsYWNlZCBpbiBhIHJlbW92ZWQgdG9rZW4gYXJlIHN0aWxsIHJlZnVuZGVkCmFuZCBzZXR0bGVkIGluIHRoYXQgdG9rZW4uAAAAAAATc2V0X2FjY2VwdGVkX3Rva2VucwAAAAABAAAAAAAAAAZ0b2tlbnMAAAAAA+oAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADtTZXQgdGhlIFNFUC00MCBwcmljZSBvcmFjbGUgdXNlZCB0byB2YWx1ZSBiaWRzIChvbmx5IG93bmVyKQAAAAAQc2V0X3ByaWNlX29yYWNsZQAAAAEAAAAAAAAABm9yYWNsZQAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEZTZXQgdGhlIG1heGltdW0gYWdlIG9mIGFuIG9yYWNsZSBwcmljZSBiZWZvcmUgaXQgaXMgc3RhbGUgKG9ubHkgb3duZXIpAAAAAAASc2V0X29yYWNsZV9tYXhfYWdlAAAAAAABAAAAAAAAAAdtYXhfYWdlAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAH9EZW5vbWluYXRlIGEgc2xvdCdzIHN0YXJ0aW5nIGJpZCBhbmQgZmxhdCBpbmNyZW1lbnQgaW4gdGhlIHByaWNlCm9yYWNsZSdzIGJhc2UgYXNzZXQgaW5zdGVhZCBvZiB0aGUgY29udHJhY3QgdG9rZW4gKG9ubHkgb3duZXIpAAAAAA9zZXRfZmlhdF9mbG9vcnMAAAAAAwAAAAAAAAAEc2xvdAAAABEAAAAAAAAAEG1pbl9zdGFydGluZ19iaWQAAAALAAAAAAAAABFtaW5fYmlkX2luY3JlbWVudAAAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEFHbyBiYWNrIHRvIHRoZSBzbG90J3MgZmxvb3JzIGluIGNvbnRyYWN0IHRva2VuIHVuaXRzIChvbmx5IG93bmVyKQAAAAAAABFjbGVhcl9maWF0X2Zsb29ycwAAAAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEVTZXQgdGhlIHJld2FyZCBwYWlkIHRvIGtlZXBlcnMgc2V0dGxpbmcgZXhwaXJlZCBhdWN0aW9ucyAob25seSBvd25lcikAAAAAAAARc2V0X2tlZXBlcl9yZXdhcmQAAAAAAAADAAAAAAAAAAxmaXhlZF9hbW91bnQAAAALAAAAAAAAAANicHMAAAAABAAAAAAAAAAKbWF4X2Ftb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAD1TZXQgdGhlIHRydXN0ZWQgYWRkcmVzcyB0aGF0IGF0dGVzdHMgc2NhbiBjb3VudHMgKG9ubHkgb3duZXIpAAAAAAAAEXNldF9zY2FuX3JlcG9ydGVyAAAAAAAAAQAAAAAAAAAIcmVwb3J0ZXIAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEJTZXQgdGhlIHRydXN0ZWQgYWRkcmVzcyB0aGF0IHJlcG9ydHMgZGlzcGxheSBkb3dudGltZSAob25seSBvd25lcikAAAAAABNzZXRfZGV2aWNlX3JlcG9ydGVyAAAAAAEAAAAAAAAACHJlcG9ydGVyAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAHRWZXN0IHByb2NlZWRzIGxpbmVhcmx5IG92ZXIgZGlzcGxheSB3aW5kb3dzIHNldHRsZWQgZnJvbSBub3cgb24KaW5zdGVhZCBvZiByZWxlYXNpbmcgdGhlbSBhdCB3aW5kb3cgZW5kIChvbmx5IG93bmVyKQAAABJzZXRfbGluZWFyX3Zlc3RpbmcAAAAAAAEAAAAAAAAAB2VuYWJsZWQAAAAAAQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAANtTd2l0Y2ggYSBzbG90IHRvIEhhcmJlcmdlciBvd25lcnNoaXAgaW5zdGVhZCBvZiBkYWlseSBhdWN0aW9ucywgb3IKY2hhbmdlIGl0cyBkYWlseSB0YXggcmF0ZSBpbiBiYXNpcyBwb2ludHMgb2YgdGhlIHByaWNlIChvbmx5IG93bmVyKQoKT25seSBwb3NzaWJsZSBvbmNlIG5vIGF1Y3Rpb24gaXMgcnVubmluZyBhbmQgZXZlcnkgd2lubmVyIGRpc3BsYXkKd2luZG93IGhhcyBlbmRlZC4AAAAAEnNldF9oYXJiZXJnZXJfbW9kZQAAAAAAAgAAAAAAAAAEc2xvdAAAABEAAAAAAAAAB3RheF9icHMAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAGFSZXR1cm4gYSBIYXJiZXJnZXIgc2xvdCB0byBkYWlseSBhdWN0aW9ucywgcmVmdW5kaW5nIHRoZSBob2xkZXIncwp1bnVzZWQgdGF4IGRlcG9zaXQgKG9ubHkgb3duZXIpAAAAAAAAFmRpc2FibGVfaGFyYmVyZ2VyX21vZGUAAAAAAAEAAAAAAAAABHNsb3QAAAARAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAFZTZXQgdGhlIHBsYXRmb3JtIHJveWFsdHkgdGFrZW4gZnJvbSBkaXNwbGF5IHJpZ2h0cyBzYWxlcywgaW4gYmFzaXMKcG9pbnRzIChvbmx5IG93bmVyKQAAAAAAE3NldF9saXN0aW5nX3JveWFsdHkAAAAAAQAAAAAAAAADYnBzAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEZFbmFibGUgb3IgZGlzYWJsZSBzdGFydGluZyB0aGUgbmV4dCBhdWN0aW9uIG9uIHNldHRsZW1lbnQgKG9ubHkgb3duZXIpAAAAAAATc2V0X2NvbnRpbnVvdXNfbW9kZQAAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAC9TZXQgdGhlIGNlaWxpbmcgZm9yIGJpZCBwYXJhbWV0ZXJzIChvbmx5IG93bmVyKQAAAAARc2V0X21heF9iaWRfcGFyYW0AAAAAAAACAAAAAAAAAARzbG90AAAAEQAAAAAAAAALbmV3X2NlaWxpbmcAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
         "AAAAAAAAACNVcGRhdGUgcGxhdGZvcm0gd2FsbGV0IChvbmx5IG93bmVyKQAAAAATc2V0X3BsYXRmb3JtX3dhbGxldAAAAAABAAAAAAAAAApuZXdfd2FsbGV0AAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEpEZWxlZ2F0ZSBjb250cmFjdCB1cGdyYWRlcyB0byBhbm90aGVyIGFkZHJlc3MsIGUuZy4gYSBmYWN0b3J5IChvbmx5IG93bmVyKQAAAAAADHNldF91cGdyYWRlcgAAAAEAAAAAAAAACHVwZ3JhZGVyAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAADxSZXBsYWNlIHRoZSBjb250cmFjdCBjb2RlIChvbmx5IHVwZ3JhZGVyLCBkZWZhdWx0cyB0byBvd25lcikAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
         "AAAAAAAAAB9UcmFuc2ZlciBvd25lcnNoaXAgKG9ubHkgb3duZXIpAAAAABJ0cmFuc2Zlcl9vd25lcnNoaXAAAAAAAAEAAAAAAAAACW5ld19vd25lcgAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==" ]),
       options
     )
   }
   public readonly fromJSON = {
     initialize: this.txFromJSON<Result<void>>,
+        create_slot: this.txFromJSON<Result<void>>,
+        list_slots: this.txFromJSON<Array<string>>,
         place_bid: this.txFromJSON<Result<void>>,
+        place_gift_bid: this.txFromJSON<Result<void>>,
+        place_bid_from: this.txFromJSON<Result<void>>,
+        place_token_bid: this.txFromJSON<Result<void>>,
+        place_proxy_bid: this.txFromJSON<Result<void>>,
+        open_pool: this.txFromJSON<Result<u64>>,
+        contribute: this.txFromJSON<Result<void>>,
+        withdraw_from_pool: this.txFromJSON<Result<i128>>,
+        set_qr_metadata: this.txFromJSON<Result<void>>,
+        update_url: this.txFromJSON<Result<void>>,
+        appoint_manager: this.txFromJSON<Result<void>>,
+        revoke_manager: this.txFromJSON<Result<void>>,
+        transfer_display: this.txFromJSON<Result<void>>,
+        list_display: this.txFromJSON<Result<void>>,
+        cancel_listing: this.txFromJSON<Result<void>>,
+        buy_listing: this.txFromJSON<Result<void>>,
+        claim_slot: this.txFromJSON<Result<void>>,
+        set_harberger_price: this.txFromJSON<Result<void>>,
+        top_up_tax: this.txFromJSON<Result<void>>,
+        collect_tax: this.txFromJSON<Result<void>>,
+        report_scans: this.txFromJSON<Result<void>>,
+        report_downtime: this.txFromJSON<Result<void>>,
+        close_display_window: this.txFromJSON<Result<void>>,
+        claim_proceeds: this.txFromJSON<Result<i128>>,
+        deposit: this.txFromJSON<Result<void>>,
+        withdraw: this.txFromJSON<Result<void>>,
         start_auction: this.txFromJSON<Result<void>>,
         end_auction: this.txFromJSON<Result<void>>,
         get_qr_url: this.txFromJSON<string>,
@@ -631,6 +2869,8 @@ export class Client extends ContractClient {
         has_active_qr_url: this.txFromJSON<boolean>,
         get_qr_url_status: this.txFromJSON<QRStatus>,
         get_qr_url_expiry_time: this.txFromJSON<u64>,
+        get_current_display: this.txFromJSON<Option<DisplayWindow>>,
+        get_display_schedule: this.txFromJSON<Array<DisplayWindow>>,
         get_time_remaining: this.txFromJSON<u64>,
         is_auction_active: this.txFromJSON<boolean>,
         get_current_auction: this.txFromJSON<Auction>,
@@ -638,12 +2878,63 @@ export class Client extends ContractClient {
         get_auction: this.txFromJSON<Option<Auction>>,
         get_auction_counter: this.txFromJSON<u64>,
         get_contract_info: this.txFromJSON<ContractInfo>,
+        get_slot_config: this.txFromJSON<SlotConfig>,
         get_auction_summary: this.txFromJSON<AuctionSummary>,
-        get_minimum_bid: this.txFromJSON<i128>,
+        get_minimum_bid: this.txFromJSON<Result<i128>>,
+        get_increment_tiers: this.txFromJSON<Array<IncrementTier>>,
+        get_bids: this.txFromJSON<Array<BidRecord>>,
+        get_bid_count: this.txFromJSON<u32>,
+        get_first_bid_index: this.txFromJSON<u32>,
+        get_bidder_stats: this.txFromJSON<BidderStats>,
+        get_leaderboard: this.txFromJSON<Array<LeaderboardEntry>>,
+        get_house_ads: this.txFromJSON<Array<HouseAd>>,
         get_auction_history: this.txFromJSON<Array<Auction>>,
+        get_scan_reports: this.txFromJSON<Array<ScanBucket>>,
+        get_total_scans: this.txFromJSON<u64>,
+        get_cost_per_scan: this.txFromJSON<i128>,
+        get_balance: this.txFromJSON<i128>,
+        get_url_manager: this.txFromJSON<Option<string>>,
+        get_display_holder: this.txFromJSON<Result<string>>,
+        get_pool: this.txFromJSON<Option<Pool>>,
+        get_pool_contribution: this.txFromJSON<i128>,
+        get_harberger_holding: this.txFromJSON<Option<HarbergerHolding>>,
+        get_tax_runway: this.txFromJSON<u64>,
+        get_listing: this.txFromJSON<Option<Listing>>,
+        get_listing_royalty: this.txFromJSON<u32>,
+        get_accepted_tokens: this.txFromJSON<Array<string>>,
+        get_price_oracle: this.txFromJSON<Option<string>>,
+        get_minimum_token_bid: this.txFromJSON<Result<i128>>,
+        get_fiat_floors: this.txFromJSON<Option<FiatFloors>>,
+        get_oracle_max_age: this.txFromJSON<u64>,
+        get_downtime: this.txFromJSON<Array<DowntimeInterval>>,
+        get_total_downtime: this.txFromJSON<u64>,
+        get_escrowed_proceeds: this.txFromJSON<i128>,
+        get_proceeds: this.txFromJSON<ProceedsStatus>,
+        get_device_reporter: this.txFromJSON<Option<string>>,
+        get_scan_reporter: this.txFromJSON<Option<string>>,
         set_min_bid_increment: this.txFromJSON<Result<void>>,
         set_min_starting_bid: this.txFromJSON<Result<void>>,
+        set_min_bid_increment_bps: this.txFromJSON<Result<void>>,
+        set_increment_tiers: this.txFromJSON<Result<void>>,
+        set_fallback_url: this.txFromJSON<Result<void>>,
+        set_house_ads: this.txFromJSON<Result<void>>,
+        set_accepted_tokens: this.txFromJSON<Result<void>>,
+        set_price_oracle: this.txFromJSON<Result<void>>,
+        set_oracle_max_age: this.txFromJSON<Result<void>>,
+        set_fiat_floors: this.txFromJSON<Result<void>>,
+        clear_fiat_floors: this.txFromJSON<Result<void>>,
+        set_keeper_reward: this.txFromJSON<Result<void>>,
+        set_scan_reporter: this.txFromJSON<Result<void>>,
+        set_device_reporter: this.txFromJSON<Result<void>>,
+        set_linear_vesting: this.txFromJSON<Result<void>>,
+        set_harberger_mode: this.txFromJSON<Result<void>>,
+        disable_harberger_mode: this.txFromJSON<Result<void>>,
+        set_listing_royalty: this.txFromJSON<Result<void>>,
+        set_continuous_mode: this.txFromJSON<Result<void>>,
+        set_max_bid_param: this.txFromJSON<Result<void>>,
         set_platform_wallet: this.txFromJSON<Result<void>>,
+        set_upgrader: this.txFromJSON<Result<void>>,
+        upgrade: this.txFromJSON<Result<void>>,
         transfer_ownership: this.txFromJSON<Result<void>>
   }
 }
\ No newline at end of file
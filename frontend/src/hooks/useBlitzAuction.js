@@ -76,9 +76,9 @@ export const useBlitzAuction = () => {
     }
   };
 
-  const endAuction = async () => {
+  const endAuction = async (keeper) => {
     try {
-      const result = await auctionClient.endAuction();
+      const result = await auctionClient.endAuction(keeper);
       await fetchAuctionData();
       return result;
     } catch (error) {
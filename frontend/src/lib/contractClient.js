@@ -32,7 +32,11 @@ const convertStringToBigInt = (value) => {
 };
 
 export class AuctionContractClient {
-  constructor(rpcUrl = "https://soroban-testnet.stellar.org") {
+  /**
+   * @param slot QR slot to operate on, like SLOT in scripts/manage.sh
+   */
+  constructor(rpcUrl = "https://soroban-testnet.stellar.org", slot = "main") {
+    this.slot = slot;
     this.client = new Client({
       ...networks.testnet,
       rpcUrl,
@@ -49,7 +53,7 @@ export class AuctionContractClient {
    */
   async getAuctionSummary() {
     try {
-      const tx = await this.client.get_auction_summary();
+      const tx = await this.client.get_auction_summary({ slot: this.slot });
       const result = await tx.simulate();
       return convertBigIntToString(result.result);
     } catch (error) {
@@ -63,7 +67,7 @@ export class AuctionContractClient {
    */
   async isAuctionActive() {
     try {
-      const tx = await this.client.is_auction_active();
+      const tx = await this.client.is_auction_active({ slot: this.slot });
       const result = await tx.simulate();
       return result.result;
     } catch (error) {
@@ -77,7 +81,7 @@ export class AuctionContractClient {
    */
   async getCurrentAuction() {
     try {
-      const tx = await this.client.get_current_auction();
+      const tx = await this.client.get_current_auction({ slot: this.slot });
       const result = await tx.simulate();
       return convertBigIntToString(result.result);
     } catch (error) {
@@ -91,7 +95,7 @@ export class AuctionContractClient {
    */
   async getLastAuction() {
     try {
-      const tx = await this.client.get_last_auction();
+      const tx = await this.client.get_last_auction({ slot: this.slot });
       const result = await tx.simulate();
       return convertBigIntToString(result.result);
     } catch (error) {
@@ -121,9 +125,9 @@ export class AuctionContractClient {
    */
   async getMinimumBid() {
     try {
-      const tx = await this.client.get_minimum_bid();
+      const tx = await this.client.get_minimum_bid({ slot: this.slot });
       const result = await tx.simulate();
-      return convertBigIntToString(result.result);
+      return convertBigIntToString(result.result.unwrap());
     } catch (error) {
       console.error("Error getting minimum bid:", error);
       throw error;
@@ -135,7 +139,7 @@ export class AuctionContractClient {
    */
   async getTimeRemaining() {
     try {
-      const tx = await this.client.get_time_remaining();
+      const tx = await this.client.get_time_remaining({ slot: this.slot });
       const result = await tx.simulate();
       return convertBigIntToString(result.result);
     } catch (error) {
@@ -172,12 +176,26 @@ export class AuctionContractClient {
     }
   }
 
+  /**
+   * Get bid parameters of the selected slot
+   */
+  async getSlotConfig() {
+    try {
+      const tx = await this.client.get_slot_config({ slot: this.slot });
+      const result = await tx.simulate();
+      return convertBigIntToString(result.result);
+    } catch (error) {
+      console.error("Error getting slot config:", error);
+      throw error;
+    }
+  }
+
   /**
    * Get auction history (last 5 auctions)
    */
   async getAuctionHistory() {
     try {
-      const tx = await this.client.get_auction_history();
+      const tx = await this.client.get_auction_history({ slot: this.slot });
       const result = await tx.simulate();
       return convertBigIntToString(result.result);
     } catch (error) {
@@ -195,7 +213,7 @@ export class AuctionContractClient {
    */
   async getQRUrl() {
     try {
-      const tx = await this.client.get_qr_url();
+      const tx = await this.client.get_qr_url({ slot: this.slot });
       const result = await tx.simulate();
       return result.result;
     } catch (error) {
@@ -209,7 +227,7 @@ export class AuctionContractClient {
    */
   async getCurrentAuctionUrl() {
     try {
-      const tx = await this.client.get_current_auction_url();
+      const tx = await this.client.get_current_auction_url({ slot: this.slot });
       const result = await tx.simulate();
       return result.result;
     } catch (error) {
@@ -223,7 +241,7 @@ export class AuctionContractClient {
    */
   async hasActiveQRUrl() {
     try {
-      const tx = await this.client.has_active_qr_url();
+      const tx = await this.client.has_active_qr_url({ slot: this.slot });
       const result = await tx.simulate();
       return result.result;
     } catch (error) {
@@ -237,7 +255,7 @@ export class AuctionContractClient {
    */
   async getQRUrlStatus() {
     try {
-      const tx = await this.client.get_qr_url_status();
+      const tx = await this.client.get_qr_url_status({ slot: this.slot });
       const result = await tx.simulate();
       return result.result;
     } catch (error) {
@@ -251,7 +269,7 @@ export class AuctionContractClient {
    */
   async getQRUrlExpiryTime() {
     try {
-      const tx = await this.client.get_qr_url_expiry_time();
+      const tx = await this.client.get_qr_url_expiry_time({ slot: this.slot });
       const result = await tx.simulate();
       return convertBigIntToString(result.result);
     } catch (error) {
@@ -299,6 +317,7 @@ export class AuctionContractClient {
     try {
       const tx = await this.client.place_bid(
         {
+          slot: this.slot,
           bidder,
           amount: convertStringToBigInt(amount),
           preferred_url: preferredUrl,
@@ -326,9 +345,14 @@ export class AuctionContractClient {
    */
   async startAuction(publicKey) {
     try {
-      const tx = await this.client.start_auction({
-        simulate: false,
-      });
+      const tx = await this.client.start_auction(
+        {
+          slot: this.slot,
+        },
+        {
+          simulate: false,
+        }
+      );
 
       // 🔑 Manually simulate before using toXDR
       await tx.simulate();
@@ -344,13 +368,19 @@ export class AuctionContractClient {
   }
 
   /**
-   * End the current auction
+   * End the current auction; the keeper signs and collects the keeper reward
    */
-  async endAuction(publicKey) {
+  async endAuction(keeper, publicKey) {
     try {
-      const tx = await this.client.end_auction({
-        simulate: false,
-      });
+      const tx = await this.client.end_auction(
+        {
+          slot: this.slot,
+          keeper,
+        },
+        {
+          simulate: false,
+        }
+      );
 
       // Manually simulate before using toXDR
       await tx.simulate();
@@ -376,6 +406,7 @@ export class AuctionContractClient {
     try {
       const tx = await this.client.set_min_bid_increment(
         {
+          slot: this.slot,
           new_increment: convertStringToBigInt(newIncrement),
         },
         {
@@ -402,6 +433,7 @@ export class AuctionContractClient {
     try {
       const tx = await this.client.set_min_starting_bid(
         {
+          slot: this.slot,
           new_starting_bid: convertStringToBigInt(newStartingBid),
         },
         {
@@ -485,20 +517,29 @@ export class AuctionContractClient {
    */
   async getAllAuctionData() {
     try {
-      const [summary, isActive, contractInfo, history, hasQR, qrStatus] =
-        await Promise.all([
-          this.getAuctionSummary(),
-          this.isAuctionActive(),
-          this.getContractInfo(),
-          this.getAuctionHistory(),
-          this.hasActiveQRUrl(),
-          this.getQRUrlStatus().catch(() => null), // QR status might fail if no active QR
-        ]);
+      const [
+        summary,
+        isActive,
+        contractInfo,
+        slotConfig,
+        history,
+        hasQR,
+        qrStatus,
+      ] = await Promise.all([
+        this.getAuctionSummary(),
+        this.isAuctionActive(),
+        this.getContractInfo(),
+        this.getSlotConfig(),
+        this.getAuctionHistory(),
+        this.hasActiveQRUrl(),
+        this.getQRUrlStatus().catch(() => null), // QR status might fail if no active QR
+      ]);
 
       return {
         summary,
         isActive,
         contractInfo,
+        slotConfig,
         history,
         hasQR,
         qrStatus,